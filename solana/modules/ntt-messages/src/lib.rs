@@ -3,6 +3,7 @@ pub mod errors;
 pub mod mode;
 pub mod ntt;
 pub mod ntt_manager;
+pub mod pause;
 pub mod transceiver;
 pub mod transceivers;
 pub mod trimmed_amount;
@@ -14,7 +14,8 @@ use crate::{chain_id::ChainId, trimmed_amount::TrimmedAmount, utils::maybe_space
 )]
 pub struct NativeTokenTransfer<A: MaybeSpace> {
     pub amount: TrimmedAmount,
-    // TODO: is this needed?
+    /// The token on the sending chain. Receivers check this against the token
+    /// address they have configured for the sending peer.
     pub source_token: [u8; 32],
     // TODO: shouldn't we put this in the outer message?
     pub to_chain: ChainId,
@@ -26,11 +27,32 @@ impl<A: MaybeSpace> NativeTokenTransfer<A> {
     const PREFIX: [u8; 4] = [0x99, 0x4E, 0x54, 0x54];
 }
 
-impl<A: TypePrefixedPayload + MaybeSpace> TypePrefixedPayload for NativeTokenTransfer<A> {
+/// The additional payload of a [`NativeTokenTransfer`].
+///
+/// Unless the payload type has an explicit size of zero (see
+/// [`EmptyPayload`]), it's written with a `u16` length prefix. The prefix is
+/// optional on the wire though: EVM NTT managers omit it (along with the
+/// payload) for transfers without an additional payload. Payload types that
+/// can represent that implement [`AdditionalPayload::omitted`].
+pub trait AdditionalPayload: Sized {
+    /// Whether the payload is omitted, length prefix included, when written.
+    fn is_omitted(&self) -> bool {
+        false
+    }
+
+    /// The payload of a transfer that omits it, if the type allows that.
+    fn omitted() -> Option<Self> {
+        None
+    }
+}
+
+impl<A: TypePrefixedPayload + MaybeSpace + AdditionalPayload> TypePrefixedPayload
+    for NativeTokenTransfer<A>
+{
     const TYPE: Option<u8> = None;
 }
 
-impl<A: TypePrefixedPayload + MaybeSpace> Readable for NativeTokenTransfer<A> {
+impl<A: TypePrefixedPayload + MaybeSpace + AdditionalPayload> Readable for NativeTokenTransfer<A> {
     const SIZE: Option<usize> = None;
 
     fn read<R>(reader: &mut R) -> io::Result<Self>
@@ -51,13 +73,30 @@ impl<A: TypePrefixedPayload + MaybeSpace> Readable for NativeTokenTransfer<A> {
         let to = Readable::read(reader)?;
         let to_chain = Readable::read(reader)?;
 
-        if A::SIZE != Some(0) {
-            // if the size is explicitly zero, this is an empty payload message
-            // and the size field should be skipped
-            // TODO: ditto todo in transceiver.rs
-            let _additional_payload_len: u16 = Readable::read(reader)?;
-        }
-        let additional_payload = A::read_payload(reader)?;
+        // if the size is explicitly zero, this is an empty payload message
+        // and the size field should be skipped
+        let additional_payload = if A::SIZE == Some(0) {
+            A::read_payload(reader)?
+        } else {
+            match read_optional_len(reader)? {
+                Some(len) => {
+                    let mut data = vec![0; len.into()];
+                    reader.read_exact(&mut data)?;
+                    let mut data = &data[..];
+                    let additional_payload = A::read_payload(&mut data)?;
+                    if !data.is_empty() {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            "Invalid additional payload length",
+                        ));
+                    }
+                    additional_payload
+                }
+                None => A::omitted().ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::UnexpectedEof, "Missing additional payload")
+                })?,
+            }
+        };
 
         Ok(Self {
             amount,
@@ -69,14 +108,14 @@ impl<A: TypePrefixedPayload + MaybeSpace> Readable for NativeTokenTransfer<A> {
     }
 }
 
-impl<A: TypePrefixedPayload + MaybeSpace> Writeable for NativeTokenTransfer<A> {
+impl<A: TypePrefixedPayload + MaybeSpace + AdditionalPayload> Writeable for NativeTokenTransfer<A> {
     fn written_size(&self) -> usize {
         Self::PREFIX.len()
             + TrimmedAmount::SIZE.unwrap()
             + self.source_token.len()
             + self.to.len()
             + ChainId::SIZE.unwrap()
-            + if A::SIZE != Some(0) {
+            + if A::SIZE != Some(0) && !self.additional_payload.is_omitted() {
                 u16::SIZE.unwrap() + self.additional_payload.written_size()
             } else {
                 0
@@ -101,7 +140,7 @@ impl<A: TypePrefixedPayload + MaybeSpace> Writeable for NativeTokenTransfer<A> {
         to.write(writer)?;
         to_chain.write(writer)?;
 
-        if A::SIZE != Some(0) {
+        if A::SIZE != Some(0) && !additional_payload.is_omitted() {
             let len: u16 = u16::try_from(additional_payload.written_size()).expect("u16 overflow");
             len.write(writer)?;
             // TODO: ditto todo in transceiver.rs
@@ -127,6 +166,8 @@ impl TypePrefixedPayload for EmptyPayload {
     const TYPE: Option<u8> = None;
 }
 
+impl AdditionalPayload for EmptyPayload {}
+
 impl Readable for EmptyPayload {
     const SIZE: Option<usize> = Some(0);
 
@@ -151,3 +192,83 @@ impl Writeable for EmptyPayload {
         Ok(())
     }
 }
+
+/// An additional payload of arbitrary bytes, which is passed on as is.
+///
+/// `data` is `None` when the transfer doesn't carry an additional payload (in
+/// which case it's omitted on the wire, see [`AdditionalPayload`]), as opposed
+/// to an empty payload, which is written with a zero length.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct RawPayload {
+    pub data: Option<Vec<u8>>,
+}
+
+impl RawPayload {
+    /// The bytes of the payload. Empty when it's omitted.
+    pub fn bytes(&self) -> &[u8] {
+        self.data.as_deref().unwrap_or_default()
+    }
+}
+
+/// NOTE: this doesn't include the bytes of the payload, as they're not
+/// bounded. Accounts that store a [`RawPayload`] are allocated with room for
+/// the bytes on top of this.
+#[cfg(feature = "anchor")]
+impl Space for RawPayload {
+    const INIT_SPACE: usize = 1 + 4;
+}
+
+impl TypePrefixedPayload for RawPayload {
+    const TYPE: Option<u8> = None;
+}
+
+impl AdditionalPayload for RawPayload {
+    fn is_omitted(&self) -> bool {
+        self.data.is_none()
+    }
+
+    fn omitted() -> Option<Self> {
+        Some(Self { data: None })
+    }
+}
+
+impl Readable for RawPayload {
+    const SIZE: Option<usize> = None;
+
+    /// Reads the rest of `reader`, which [`NativeTokenTransfer`] limits to the
+    /// length of the payload.
+    fn read<R>(reader: &mut R) -> io::Result<Self>
+    where
+        Self: Sized,
+        R: io::Read,
+    {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        Ok(Self { data: Some(data) })
+    }
+}
+
+impl Writeable for RawPayload {
+    fn written_size(&self) -> usize {
+        self.bytes().len()
+    }
+
+    fn write<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        writer.write_all(self.bytes())
+    }
+}
+
+/// Reads a `u16` length prefix, or `None` if `reader` is exhausted.
+fn read_optional_len<R: io::Read>(reader: &mut R) -> io::Result<Option<u16>> {
+    let mut len = [0u8; 2];
+    match reader.read(&mut len)? {
+        0 => return Ok(None),
+        1 => reader.read_exact(&mut len[1..])?,
+        _ => {}
+    }
+    Ok(Some(u16::from_be_bytes(len)))
+}
@@ -45,9 +45,20 @@ impl<A: TypePrefixedPayload + MaybeSpace> Readable for NttManagerMessage<A> {
     {
         let id = Readable::read(reader)?;
         let sender = Readable::read(reader)?;
-        // TODO: ditto todo in transceiver.rs
-        let _payload_len: u16 = Readable::read(reader)?;
-        let payload = A::read_payload(reader)?;
+        // the payload is read from exactly the bytes it's prefixed with, so
+        // that it can tell where it ends (see
+        // [`crate::ntt::AdditionalPayload`]), and trailing bytes are rejected
+        let payload_len: u16 = Readable::read(reader)?;
+        let mut data = vec![0; payload_len.into()];
+        reader.read_exact(&mut data)?;
+        let mut data = &data[..];
+        let payload = A::read_payload(&mut data)?;
+        if !data.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Invalid payload length",
+            ));
+        }
 
         Ok(Self {
             id,
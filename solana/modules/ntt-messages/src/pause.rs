@@ -0,0 +1,105 @@
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::*;
+
+use std::io;
+
+use wormhole_io::{Readable, TypePrefixedPayload, Writeable};
+
+use crate::chain_id::ChainId;
+
+/// Control message sent by a manager's owner to its peer on `to_chain`,
+/// asking it to pause (or resume) outbound transfers to the sending chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(AnchorSerialize, AnchorDeserialize, InitSpace)
+)]
+pub struct PauseSignal {
+    pub paused: bool,
+    pub to_chain: ChainId,
+}
+
+impl PauseSignal {
+    const PREFIX: [u8; 4] = [0x99, 0x50, 0x41, 0x55];
+}
+
+impl TypePrefixedPayload for PauseSignal {
+    const TYPE: Option<u8> = None;
+}
+
+impl Readable for PauseSignal {
+    const SIZE: Option<usize> = Some(4 + 1 + 2);
+
+    fn read<R>(reader: &mut R) -> io::Result<Self>
+    where
+        Self: Sized,
+        R: io::Read,
+    {
+        let prefix: [u8; 4] = Readable::read(reader)?;
+        if prefix != Self::PREFIX {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Invalid prefix for PauseSignal",
+            ));
+        }
+
+        let paused = match u8::read(reader)? {
+            0 => false,
+            1 => true,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Invalid paused flag",
+                ))
+            }
+        };
+        let to_chain = Readable::read(reader)?;
+
+        Ok(Self { paused, to_chain })
+    }
+}
+
+impl Writeable for PauseSignal {
+    fn written_size(&self) -> usize {
+        Self::SIZE.unwrap()
+    }
+
+    fn write<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let PauseSignal { paused, to_chain } = self;
+
+        Self::PREFIX.write(writer)?;
+        u8::from(*paused).write(writer)?;
+        to_chain.write(writer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pause_signal_roundtrip() {
+        let signal = PauseSignal {
+            paused: true,
+            to_chain: ChainId { id: 2 },
+        };
+
+        let encoded = TypePrefixedPayload::to_vec_payload(&signal);
+        assert_eq!(encoded, vec![0x99, 0x50, 0x41, 0x55, 0x01, 0x00, 0x02]);
+
+        let mut vec = &encoded[..];
+        let decoded: PauseSignal = TypePrefixedPayload::read_payload(&mut vec).unwrap();
+        assert_eq!(decoded, signal);
+        assert_eq!(vec.len(), 0);
+    }
+
+    #[test]
+    fn test_pause_signal_invalid_flag() {
+        let data = [0x99, 0x50, 0x41, 0x55, 0x02, 0x00, 0x02];
+        let mut vec = &data[..];
+        assert!(<PauseSignal as TypePrefixedPayload>::read_payload(&mut vec).is_err());
+    }
+}
@@ -216,8 +216,10 @@ where
 #[cfg(test)]
 mod test {
     use crate::{
-        chain_id::ChainId, ntt::EmptyPayload, ntt::NativeTokenTransfer,
-        transceivers::wormhole::WormholeTransceiver, trimmed_amount::TrimmedAmount,
+        chain_id::ChainId,
+        ntt::{AdditionalPayload, EmptyPayload, NativeTokenTransfer, RawPayload},
+        transceivers::wormhole::WormholeTransceiver,
+        trimmed_amount::TrimmedAmount,
     };
 
     use super::*;
@@ -294,6 +296,8 @@ mod test {
         const TYPE: Option<u8> = None;
     }
 
+    impl AdditionalPayload for EmptyMockPayload {}
+
     impl Readable for EmptyMockPayload {
         // This will cause the size to be written, since it is not explicitly 0
         const SIZE: Option<usize> = None;
@@ -399,6 +403,8 @@ mod test {
         const TYPE: Option<u8> = None;
     }
 
+    impl AdditionalPayload for MockPayload {}
+
     impl Readable for MockPayload {
         const SIZE: Option<usize> = None;
 
@@ -493,4 +499,42 @@ mod test {
         let encoded = TypePrefixedPayload::to_vec_payload(&expected);
         assert_eq!(encoded, data);
     }
+
+    #[test]
+    fn test_deserialize_transceiver_message_with_raw_payload() {
+        let cases = [
+            ("transceiver_message_1.txt", None),
+            ("transceiver_message_with_empty_payload.txt", Some(vec![])),
+            (
+                "transceiver_message_with_32byte_payload.txt",
+                Some(
+                    [
+                        [0xDE, 0xAD, 0xBE, 0xEF].as_slice(),
+                        &[0; 24],
+                        &[0xDE, 0xAD, 0xBE, 0xEF],
+                    ]
+                    .concat(),
+                ),
+            ),
+        ];
+        for (file, expected) in cases {
+            let path = format!(
+                "{}/../../../evm/test/payloads/{file}",
+                env!("CARGO_MANIFEST_DIR")
+            );
+            let data = hex::decode(std::fs::read_to_string(path).unwrap().trim_end()).unwrap();
+            let mut vec = &data[..];
+            let message: TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<RawPayload>> =
+                TypePrefixedPayload::read_payload(&mut vec).unwrap();
+
+            assert_eq!(
+                message.ntt_manager_payload.payload.additional_payload,
+                RawPayload { data: expected }
+            );
+            assert_eq!(vec.len(), 0);
+
+            let encoded = TypePrefixedPayload::to_vec_payload(&message);
+            assert_eq!(encoded, data);
+        }
+    }
 }
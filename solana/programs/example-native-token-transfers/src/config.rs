@@ -3,7 +3,10 @@ use std::ops::{Deref, DerefMut};
 use anchor_lang::prelude::*;
 use ntt_messages::{chain_id::ChainId, mode::Mode};
 
-use crate::bitmap::Bitmap;
+use crate::{
+    bitmap::Bitmap, error::NTTError, mint_extensions::MintExtensions,
+    transceivers::wormhole::accounts::WormholeFinality,
+};
 
 /// This is a hack to re-export some modules that anchor generates as
 /// pub(crate), as it's not possible to directly re-export a module with a
@@ -55,10 +58,197 @@ pub struct Config {
     pub paused: bool,
     /// The custody account that holds tokens in locking mode.
     pub custody: Pubkey,
+    /// Minimum number of seconds between proposing an admin action and
+    /// executing it. Zero disables the timelock.
+    /// See [`crate::timelock`].
+    pub timelock_delay: i64,
+    /// Optional m-of-n owner set. When empty, [`Config::owner`] alone
+    /// authorizes owner instructions.
+    pub owner_set: OwnerSet,
+    /// Number of seconds after its release timestamp after which an
+    /// unreleased outbound transfer can be reclaimed by the sender.
+    /// Zero means outbound transfers never expire.
+    pub max_queue_age: i64,
+    /// When enabled, releasing a queued transfer (in either direction) also
+    /// consumes rate limit capacity, and the transfer is delayed again if there
+    /// isn't enough. Like an immediate transfer, it then refills the rate limit
+    /// of the opposite direction (backflow). This bounds the total throughput
+    /// in any 24 hour window, at the cost of transfers larger than the limit
+    /// never being released.
+    pub capacity_aware_release: bool,
+    /// Optional operator that, in addition to the owner, can expedite or
+    /// freeze queued inbound transfers.
+    /// See [`crate::instructions::expedite_inbox_item`].
+    pub operator: Option<Pubkey>,
+    /// The token-2022 extensions of the mint, detected at initialize.
+    /// See [`crate::mint_extensions`].
+    pub mint_extensions: MintExtensions,
+    /// The version of this account's layout. Set to [`Config::LAYOUT_VERSION`]
+    /// at initialize (or by [`crate::instructions::migrate_config`]), and
+    /// checked when unpausing after an upgrade.
+    /// See [`crate::instructions::upgrade`].
+    pub layout_version: u8,
+    /// Whether this config is one of many managed by this program. The
+    /// accounts of a multi-token config (including the config itself) are
+    /// keyed by its mint. See [`Config::mint_seed`].
+    pub multi_token: bool,
+    /// The finality of Wormhole messages sent to peers that don't override it
+    /// (see [`crate::transceivers::accounts::peer::TransceiverPeer::finality`]),
+    /// and of messages that aren't sent to a specific chain (e.g. broadcasts).
+    /// Initialized to the network's
+    /// [`crate::transceivers::wormhole::accounts::DEFAULT_FINALITY`].
+    pub wormhole_finality: WormholeFinality,
+    /// Whether the `upgrade_lock` only holds the program's upgrade authority
+    /// for the duration of an ownership transfer, after which it's handed to
+    /// the new owner (or back to the owner). Otherwise, an authority held by
+    /// the `upgrade_lock` stays there, see [`crate::instructions::upgrade`].
+    pub upgrade_authority_escrowed: bool,
 }
 
 impl Config {
     pub const SEED_PREFIX: &'static [u8] = b"config";
+
+    /// The layout version expected by this version of the program. Bumped
+    /// whenever a migration of existing config accounts is required.
+    pub const LAYOUT_VERSION: u8 = 2;
+
+    /// The seed that keys the accounts of this config by its mint.
+    /// See [`mint_seed`].
+    pub fn mint_seed(&self) -> &[u8] {
+        mint_seed(&self.mint, self.multi_token)
+    }
+
+    pub fn timelock_enabled(&self) -> bool {
+        self.timelock_delay > 0
+    }
+
+    /// Checks that an owner instruction signed by `owner` is authorized.
+    ///
+    /// Without an owner set, `owner` must be [`Config::owner`]. Otherwise
+    /// `owner` must be a member of the set, and at least
+    /// [`OwnerSet::threshold`] distinct members must have signed the
+    /// transaction. The additional signers are passed in `remaining_accounts`.
+    pub fn authorize_owner(
+        &self,
+        owner: &Pubkey,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        if self.owner_set.keys.is_empty() {
+            if *owner != self.owner {
+                return Err(NTTError::InvalidOwner.into());
+            }
+            return Ok(());
+        }
+
+        if !self.owner_set.keys.contains(owner) {
+            return Err(NTTError::InvalidOwner.into());
+        }
+
+        // NOTE: the keys in the set are distinct (see [`OwnerSet::new`]), so
+        // counting the keys that have a matching signer counts distinct signers.
+        let signatures = self
+            .owner_set
+            .keys
+            .iter()
+            .filter(|key| {
+                *key == owner
+                    || remaining_accounts
+                        .iter()
+                        .any(|account| account.is_signer && account.key == *key)
+            })
+            .count();
+
+        if signatures < usize::from(self.owner_set.threshold) {
+            return Err(NTTError::InsufficientOwnerSignatures.into());
+        }
+
+        Ok(())
+    }
+
+    /// Like [`Config::authorize_owner`], but the [`Config::operator`] (if
+    /// any) is also authorized on its own.
+    pub fn authorize_owner_or_operator(
+        &self,
+        authority: &Pubkey,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        if self.operator.as_ref() == Some(authority) {
+            return Ok(());
+        }
+
+        self.authorize_owner(authority, remaining_accounts)
+    }
+}
+
+/// The seed that keys per-token accounts (config, peers, rate limits, token
+/// authority, etc.) by `mint` in multi-token mode.
+///
+/// In single-token mode the seed is empty, which derives the same addresses as
+/// omitting it, so the accounts of existing deployments are unchanged.
+pub fn mint_seed(mint: &Pubkey, multi_token: bool) -> &[u8] {
+    if multi_token {
+        mint.as_ref()
+    } else {
+        &[]
+    }
+}
+
+/// Whether the program manages a single token or multiple tokens (see
+/// [`mint_seed`]). Set by the first [`crate::instructions::initialize`] and
+/// checked by later ones, so that the two modes can't be mixed in one program.
+#[account]
+#[derive(InitSpace)]
+pub struct TokenMode {
+    pub bump: u8,
+    /// Whether [`TokenMode::multi_token`] has been set. False only while the
+    /// account is being created by the first initialize.
+    pub initialized: bool,
+    pub multi_token: bool,
+}
+
+impl TokenMode {
+    pub const SEED_PREFIX: &'static [u8] = b"token_mode";
+}
+
+/// The maximum number of keys in an [`OwnerSet`].
+pub const MAX_OWNERS: usize = 10;
+
+/// A set of owner keys, any `threshold` of which can authorize owner
+/// instructions together.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct OwnerSet {
+    #[max_len(MAX_OWNERS)]
+    pub keys: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+impl OwnerSet {
+    /// Creates a validated owner set. An empty set (with a zero threshold)
+    /// disables m-of-n authorization.
+    pub fn new(keys: Vec<Pubkey>, threshold: u8) -> Result<Self> {
+        if keys.len() > MAX_OWNERS {
+            return Err(NTTError::InvalidOwnerSet.into());
+        }
+
+        if keys
+            .iter()
+            .enumerate()
+            .any(|(i, key)| keys[..i].contains(key))
+        {
+            return Err(NTTError::InvalidOwnerSet.into());
+        }
+
+        let valid_threshold = if keys.is_empty() {
+            threshold == 0
+        } else {
+            threshold > 0 && usize::from(threshold) <= keys.len()
+        };
+        if !valid_threshold {
+            return Err(NTTError::InvalidOwnerSet.into());
+        }
+
+        Ok(Self { keys, threshold })
+    }
 }
 
 #[derive(Accounts)]
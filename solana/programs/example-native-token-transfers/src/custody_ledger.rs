@@ -0,0 +1,66 @@
+//! Custody accounting for locking mode.
+//!
+//! Every token that enters the custody account through an outbound transfer
+//! and every token that leaves it (released inbound transfers and reclaimed
+//! outbound transfers) is recorded in the [`CustodyLedger`]. The difference
+//! between the two totals is what the custody account is expected to hold,
+//! which [`crate::instructions::check_custody_invariant`] compares with its
+//! actual balance.
+//!
+//! In burning mode, tokens only pass through custody, so nothing is recorded.
+//!
+//! Deployments created before the ledger existed don't have one until the
+//! owner initializes it (see [`crate::instructions::initialize_custody_ledger`]),
+//! and only record from then on. So the invariant only holds from
+//! initialization onwards: it takes the custody balance at that point as
+//! correct, and doesn't reveal any earlier discrepancy.
+
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct CustodyLedger {
+    pub bump: u8,
+    /// Total amount locked into custody by outbound transfers.
+    pub total_outbound: u128,
+    /// Total amount unlocked from custody.
+    pub total_inbound: u128,
+}
+
+impl CustodyLedger {
+    pub const SEED_PREFIX: &'static [u8] = b"custody_ledger";
+
+    /// Applies `record` to the ledger at `custody_ledger`, if it has been
+    /// initialized. Instructions that move tokens in or out of custody take the
+    /// ledger this way, so that they keep working for deployments that haven't
+    /// initialized it yet.
+    /// SECURITY: the caller must check the PDA. Once the ledger is initialized,
+    /// the PDA check ensures it can't be skipped.
+    pub fn record_if_initialized(
+        custody_ledger: &AccountInfo,
+        record: impl FnOnce(&mut Self),
+    ) -> Result<()> {
+        if custody_ledger.owner != &crate::ID {
+            return Ok(());
+        }
+
+        let mut data = custody_ledger.try_borrow_mut_data()?;
+        let mut ledger = Self::try_deserialize(&mut &data[..])?;
+        record(&mut ledger);
+        ledger.try_serialize(&mut &mut data[..])
+    }
+
+    pub fn record_outbound(&mut self, amount: u64) {
+        self.total_outbound += u128::from(amount);
+    }
+
+    pub fn record_inbound(&mut self, amount: u64) {
+        self.total_inbound += u128::from(amount);
+    }
+
+    /// The amount the custody account is expected to hold. Negative if more
+    /// has been unlocked than was ever locked.
+    pub fn recorded_balance(&self) -> i128 {
+        self.total_outbound as i128 - self.total_inbound as i128
+    }
+}
@@ -53,6 +53,92 @@ pub enum NTTError {
     BitmapIndexOutOfBounds,
     #[msg("NoRegisteredTransceivers")]
     NoRegisteredTransceivers,
+    #[msg("TimelockDisabled")]
+    TimelockDisabled,
+    #[msg("InvalidTimelockDelay")]
+    InvalidTimelockDelay,
+    #[msg("AdminActionNotProposed")]
+    AdminActionNotProposed,
+    #[msg("AdminActionMismatch")]
+    AdminActionMismatch,
+    #[msg("TimelockNotExpired")]
+    TimelockNotExpired,
+    #[msg("AccountAlreadyMigrated")]
+    AccountAlreadyMigrated,
+    #[msg("InvalidOwner")]
+    InvalidOwner,
+    #[msg("InsufficientOwnerSignatures")]
+    InsufficientOwnerSignatures,
+    #[msg("InvalidOwnerSet")]
+    InvalidOwnerSet,
+    #[msg("OutboxItemNotExpired")]
+    OutboxItemNotExpired,
+    #[msg("InvalidMaxQueueAge")]
+    InvalidMaxQueueAge,
+    #[msg("InboxItemFrozen")]
+    InboxItemFrozen,
+    #[msg("InvalidSettlementDelays")]
+    InvalidSettlementDelays,
+    #[msg("InvalidVaaAccount")]
+    InvalidVaaAccount,
+    #[msg("PendingPeerIncomplete")]
+    PendingPeerIncomplete,
+    #[msg("PendingPeerMismatch")]
+    PendingPeerMismatch,
+    #[msg("InvalidSourceToken")]
+    InvalidSourceToken,
+    #[msg("InvalidFromAuthority")]
+    InvalidFromAuthority,
+    #[msg("ReleaseRequiresCall")]
+    ReleaseRequiresCall,
+    #[msg("ReceiverNotRegistered")]
+    ReceiverNotRegistered,
+    #[msg("InvalidReceiverProgram")]
+    InvalidReceiverProgram,
+    #[msg("NonTransferableMint")]
+    NonTransferableMint,
+    #[msg("ConfidentialTransferMint")]
+    ConfidentialTransferMint,
+    #[msg("PermanentDelegateMint")]
+    PermanentDelegateMint,
+    #[msg("FrozenByDefaultMint")]
+    FrozenByDefaultMint,
+    #[msg("UnsupportedMintExtension")]
+    UnsupportedMintExtension,
+    #[msg("InvalidTransferHookAccounts")]
+    InvalidTransferHookAccounts,
+    #[msg("CurrentLUT")]
+    CurrentLUT,
+    #[msg("NotPaused")]
+    NotPaused,
+    #[msg("InvalidUpgradeAuthority")]
+    InvalidUpgradeAuthority,
+    #[msg("ConfigLayoutMismatch")]
+    ConfigLayoutMismatch,
+    #[msg("NoPendingOwner")]
+    NoPendingOwner,
+    #[msg("InvalidRenounceConfirmation")]
+    InvalidRenounceConfirmation,
+    #[msg("InvalidMint")]
+    InvalidMint,
+    #[msg("MultiTokenConfig")]
+    MultiTokenConfig,
+    #[msg("PeerOutboundPaused")]
+    PeerOutboundPaused,
+    #[msg("StalePauseSignal")]
+    StalePauseSignal,
+    #[msg("DuplicateInboxItem")]
+    DuplicateInboxItem,
+    #[msg("QueuedAmountExceedsCap")]
+    QueuedAmountExceedsCap,
+    #[msg("InvalidRampDuration")]
+    InvalidRampDuration,
+    #[msg("UnapprovedRelayer")]
+    UnapprovedRelayer,
+    #[msg("MixedTokenModes")]
+    MixedTokenModes,
+    #[msg("TransferFeeMint")]
+    TransferFeeMint,
 }
 
 impl From<ScalingError> for NTTError {
@@ -1,16 +1,25 @@
 use anchor_lang::prelude::*;
-use ntt_messages::chain_id::ChainId;
+use anchor_spl::token_interface;
+use ntt_messages::{chain_id::ChainId, mode::Mode};
 use wormhole_solana_utils::cpi::bpf_loader_upgradeable::{self, BpfLoaderUpgradeable};
 
 #[cfg(feature = "idl-build")]
 use crate::messages::Hack;
 
 use crate::{
-    config::Config,
+    clock::current_timestamp,
+    config::{Config, OwnerSet},
+    custody_ledger::CustodyLedger,
     error::NTTError,
-    peer::NttManagerPeer,
-    queue::{inbox::InboxRateLimit, outbox::OutboxRateLimit, rate_limit::RateLimitState},
+    peer::{NttManagerPeer, SettlementDelayTier},
+    queue::{
+        inbox::{InboxItem, InboxRateLimit},
+        outbox::OutboxRateLimit,
+        rate_limit::RateLimitState,
+    },
+    registered_receiver::{receiver_authority, RegisteredReceiver},
     registered_transceiver::RegisteredTransceiver,
+    timelock::{enforce_timelock, AdminAction, PendingAdminAction},
 };
 
 // * Transfer ownership
@@ -20,24 +29,32 @@ use crate::{
 /// This is to prevent a situation where the ownership is transferred to an
 /// address that is not able to claim the ownership (by mistake).
 ///
-/// The transfer can be cancelled by the existing owner invoking the [`claim_ownership`]
-/// instruction.
+/// The transfer can be cancelled by the existing owner invoking the
+/// [`cancel_ownership_transfer`] (or, without an owner set, [`claim_ownership`])
+/// instruction. Once claimed, the new owner is the sole owner: any owner set is
+/// cleared.
 ///
 /// Alternatively, the ownership can be transferred in a single step by calling the
 /// [`transfer_ownership_one_step_unchecked`] instruction. This can be dangerous because if the new owner
 /// cannot actually sign transactions (due to setting the wrong address), the program will be
 /// permanently locked. If the intention is to transfer ownership to a program using this instruction,
 /// take extra care to ensure that the owner is a PDA, not the program address itself.
+///
+/// The upgrade authority of the program moves along with the ownership, except
+/// in multi-token mode, where it stays with the deployer. If the authority has
+/// been handed to the `upgrade_lock` for good (see [`upgrade`]), it stays there.
 #[derive(Accounts)]
 pub struct TransferOwnership<'info> {
-    #[account(
-        mut,
-        has_one = owner,
-    )]
+    #[account(mut)]
     pub config: Account<'info, Config>,
 
+    #[account(mut)]
     pub owner: Signer<'info>,
 
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
     /// CHECK: This account will be the signer in the [claim_ownership] instruction.
     new_owner: UncheckedAccount<'info>,
 
@@ -60,9 +77,36 @@ pub struct TransferOwnership<'info> {
 }
 
 pub fn transfer_ownership(ctx: Context<TransferOwnership>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::TransferOwnership {
+            new_owner: ctx.accounts.new_owner.key(),
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
     ctx.accounts.config.pending_owner = Some(ctx.accounts.new_owner.key());
 
-    // TODO: only transfer authority when the authority is not already the upgrade lock
+    // In multi-token mode, the upgrade authority belongs to the program's
+    // deployer, not to the owner of any one token.
+    if ctx.accounts.config.multi_token {
+        return Ok(());
+    }
+
+    // With an owner set, the authority may be held by a member other than the
+    // signer, or it may already be with the upgrade lock (for the `upgrade`
+    // instruction, or from an earlier transfer), in which case it stays put.
+    if ctx.accounts.program_data.upgrade_authority_address != Some(ctx.accounts.owner.key()) {
+        return Ok(());
+    }
+
+    ctx.accounts.config.upgrade_authority_escrowed = true;
+
     bpf_loader_upgradeable::set_upgrade_authority_checked(
         CpiContext::new_with_signer(
             ctx.accounts
@@ -80,9 +124,28 @@ pub fn transfer_ownership(ctx: Context<TransferOwnership>) -> Result<()> {
 }
 
 pub fn transfer_ownership_one_step_unchecked(ctx: Context<TransferOwnership>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::TransferOwnershipOneStepUnchecked {
+            new_owner: ctx.accounts.new_owner.key(),
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
     ctx.accounts.config.pending_owner = None;
     ctx.accounts.config.owner = ctx.accounts.new_owner.key();
 
+    if ctx.accounts.config.multi_token
+        || ctx.accounts.program_data.upgrade_authority_address != Some(ctx.accounts.owner.key())
+    {
+        return Ok(());
+    }
+
     // NOTE: unlike in `transfer_ownership`, we use the unchecked version of the
     // `set_upgrade_authority` instruction here. The checked version requires
     // the new owner to be a signer, which is what we want to avoid here.
@@ -135,8 +198,30 @@ pub struct ClaimOwnership<'info> {
 }
 
 pub fn claim_ownership(ctx: Context<ClaimOwnership>) -> Result<()> {
-    ctx.accounts.config.pending_owner = None;
-    ctx.accounts.config.owner = ctx.accounts.new_owner.key();
+    let config = &mut ctx.accounts.config;
+
+    if config.pending_owner == Some(ctx.accounts.new_owner.key()) {
+        // The new owner takes over alone. The owner set of the previous
+        // owner(s) would otherwise keep authorizing owner instructions.
+        config.owner_set = OwnerSet::default();
+    } else if !config.owner_set.keys.is_empty() {
+        // The current owner can cancel the transfer by claiming it, but with
+        // an owner set, [`cancel_ownership_transfer`] has to be used instead.
+        return Err(NTTError::InvalidPendingOwner.into());
+    }
+
+    config.pending_owner = None;
+    config.owner = ctx.accounts.new_owner.key();
+
+    // The upgrade lock only hands on the authority it holds for the transfer.
+    // Otherwise it keeps it, so that the new owner can `upgrade` the program.
+    if !std::mem::take(&mut config.upgrade_authority_escrowed)
+        || config.multi_token
+        || ctx.accounts.program_data.upgrade_authority_address
+            != Some(ctx.accounts.upgrade_lock.key())
+    {
+        return Ok(());
+    }
 
     bpf_loader_upgradeable::set_upgrade_authority_checked(
         CpiContext::new_with_signer(
@@ -154,6 +239,177 @@ pub fn claim_ownership(ctx: Context<ClaimOwnership>) -> Result<()> {
     )
 }
 
+// * Cancel ownership transfer
+// NOTE: like pausing, cancelling is not subject to the timelock, so that a
+// transfer proposed with a compromised key can be cancelled before it matures.
+
+#[derive(Accounts)]
+pub struct CancelOwnershipTransfer<'info> {
+    #[account(
+        mut,
+        constraint = config.pending_owner.is_some() @ NTTError::NoPendingOwner,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"upgrade_lock"],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct address
+    upgrade_lock: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable_program,
+    )]
+    program_data: Account<'info, ProgramData>,
+
+    bpf_loader_upgradeable_program: Program<'info, BpfLoaderUpgradeable>,
+}
+
+/// Cancels a pending [`transfer_ownership`], and returns the upgrade authority
+/// from the upgrade lock to the owner, if the transfer put it there.
+pub fn cancel_ownership_transfer(ctx: Context<CancelOwnershipTransfer>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    let config = &mut ctx.accounts.config;
+    config.pending_owner = None;
+
+    if !std::mem::take(&mut config.upgrade_authority_escrowed)
+        || config.multi_token
+        || ctx.accounts.program_data.upgrade_authority_address
+            != Some(ctx.accounts.upgrade_lock.key())
+    {
+        return Ok(());
+    }
+
+    bpf_loader_upgradeable::set_upgrade_authority_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts
+                .bpf_loader_upgradeable_program
+                .to_account_info(),
+            bpf_loader_upgradeable::SetUpgradeAuthorityChecked {
+                program_data: ctx.accounts.program_data.to_account_info(),
+                current_authority: ctx.accounts.upgrade_lock.to_account_info(),
+                new_authority: ctx.accounts.owner.to_account_info(),
+            },
+            &[&[b"upgrade_lock", &[ctx.bumps.upgrade_lock]]],
+        ),
+        &crate::ID,
+    )
+}
+
+// * Renounce ownership
+
+/// The confirmation that has to be passed to [`renounce_ownership`].
+pub const RENOUNCE_OWNERSHIP_CONFIRMATION: &str = "renounce ownership";
+
+/// Permanently gives up ownership of the program. The config is left without
+/// an owner, owner set or operator, so no admin instruction can be executed
+/// anymore, and the program is made immutable.
+///
+/// NOTE: if the program is paused, it stays paused forever.
+/// In multi-token mode, only the token of the config is affected, and the
+/// program is not made immutable.
+#[derive(Accounts)]
+pub struct RenounceOwnership<'info> {
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
+    #[account(
+        seeds = [b"upgrade_lock"],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct address
+    upgrade_lock: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable_program,
+    )]
+    program_data: Account<'info, ProgramData>,
+
+    bpf_loader_upgradeable_program: Program<'info, BpfLoaderUpgradeable>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RenounceOwnershipArgs {
+    /// Must be [`RENOUNCE_OWNERSHIP_CONFIRMATION`].
+    pub confirmation: String,
+}
+
+pub fn renounce_ownership(
+    ctx: Context<RenounceOwnership>,
+    args: RenounceOwnershipArgs,
+) -> Result<()> {
+    if args.confirmation != RENOUNCE_OWNERSHIP_CONFIRMATION {
+        return Err(NTTError::InvalidRenounceConfirmation.into());
+    }
+
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::RenounceOwnership,
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    let config = &mut ctx.accounts.config;
+    config.owner = Pubkey::default();
+    config.pending_owner = None;
+    config.owner_set = OwnerSet::default();
+    config.operator = None;
+    config.upgrade_authority_escrowed = false;
+
+    // In multi-token mode, the program stays upgradeable by its deployer.
+    if config.multi_token {
+        return Ok(());
+    }
+
+    // The upgrade authority is either held by the owner, or by the upgrade
+    // lock (during an ownership transfer, or for the `upgrade` instruction).
+    let current_authority = match ctx.accounts.program_data.upgrade_authority_address {
+        None => return Ok(()),
+        Some(authority) if authority == ctx.accounts.upgrade_lock.key() => {
+            ctx.accounts.upgrade_lock.to_account_info()
+        }
+        Some(_) => ctx.accounts.owner.to_account_info(),
+    };
+
+    bpf_loader_upgradeable::set_upgrade_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts
+                .bpf_loader_upgradeable_program
+                .to_account_info(),
+            bpf_loader_upgradeable::SetUpgradeAuthority {
+                program_data: ctx.accounts.program_data.to_account_info(),
+                current_authority,
+                new_authority: None,
+            },
+            &[&[b"upgrade_lock", &[ctx.bumps.upgrade_lock]]],
+        ),
+        &crate::ID,
+    )
+}
+
 // * Set peers
 
 #[derive(Accounts)]
@@ -162,18 +418,24 @@ pub struct SetPeer<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    #[account(mut)]
     pub owner: Signer<'info>,
 
-    #[account(
-        has_one = owner,
-    )]
     pub config: Account<'info, Config>,
 
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
     #[account(
         init_if_needed,
         space = 8 + NttManagerPeer::INIT_SPACE,
         payer = payer,
-        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        seeds = [
+            NttManagerPeer::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
         bump
     )]
     pub peer: Account<'info, NttManagerPeer>,
@@ -184,7 +446,8 @@ pub struct SetPeer<'info> {
         payer = payer,
         seeds = [
             InboxRateLimit::SEED_PREFIX,
-            args.chain_id.id.to_be_bytes().as_ref()
+            args.chain_id.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
         ],
         bump,
     )]
@@ -200,14 +463,35 @@ pub struct SetPeerArgs {
     pub limit: u64,
     /// The token decimals on the peer chain.
     pub token_decimals: u8,
+    /// The token address on the peer chain.
+    pub token_address: [u8; 32],
 }
 
 pub fn set_peer(ctx: Context<SetPeer>, args: SetPeerArgs) -> Result<()> {
-    ctx.accounts.peer.set_inner(NttManagerPeer {
-        bump: ctx.bumps.peer,
-        address: args.address,
-        token_decimals: args.token_decimals,
-    });
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetPeer {
+            chain_id: args.chain_id,
+            address: args.address,
+            limit: args.limit,
+            token_decimals: args.token_decimals,
+            token_address: args.token_address,
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    // NOTE: the fields are updated in place so that re-registering a peer
+    // doesn't reset its settlement delays.
+    let peer = &mut ctx.accounts.peer;
+    peer.bump = ctx.bumps.peer;
+    peer.address = args.address;
+    peer.token_decimals = args.token_decimals;
+    peer.token_address = args.token_address;
 
     ctx.accounts.inbox_rate_limit.set_inner(InboxRateLimit {
         bump: ctx.bumps.inbox_rate_limit,
@@ -216,21 +500,78 @@ pub fn set_peer(ctx: Context<SetPeer>, args: SetPeerArgs) -> Result<()> {
     Ok(())
 }
 
-// * Register transceivers
+// * Settlement delays
 
 #[derive(Accounts)]
-pub struct RegisterTransceiver<'info> {
+#[instruction(args: SetSettlementDelaysArgs)]
+pub struct SetSettlementDelays<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub config: Account<'info, Config>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
     #[account(
         mut,
-        has_one = owner,
+        seeds = [
+            NttManagerPeer::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = peer.bump,
     )]
+    pub peer: Account<'info, NttManagerPeer>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetSettlementDelaysArgs {
+    pub chain_id: ChainId,
+    pub tiers: Vec<SettlementDelayTier>,
+}
+
+/// Replace the settlement delay tiers of a peer. An empty list disables the
+/// settlement delay.
+pub fn set_settlement_delays(
+    ctx: Context<SetSettlementDelays>,
+    args: SetSettlementDelaysArgs,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetSettlementDelays {
+            chain_id: args.chain_id,
+            tiers: args.tiers.clone(),
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    ctx.accounts.peer.set_settlement_delays(args.tiers)
+}
+
+// * Register transceivers
+
+#[derive(Accounts)]
+pub struct RegisterTransceiver<'info> {
+    #[account(mut)]
     pub config: Account<'info, Config>,
 
+    #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
     #[account(executable)]
     /// CHECK: transceiver is meant to be a transceiver program. Arguably a `Program` constraint could be
     /// used here that wraps the Transceiver account type.
@@ -240,7 +581,11 @@ pub struct RegisterTransceiver<'info> {
         init,
         space = 8 + RegisteredTransceiver::INIT_SPACE,
         payer = payer,
-        seeds = [RegisteredTransceiver::SEED_PREFIX, transceiver.key().as_ref()],
+        seeds = [
+            RegisteredTransceiver::SEED_PREFIX,
+            transceiver.key().as_ref(),
+            config.mint_seed(),
+        ],
         bump
     )]
     pub registered_transceiver: Account<'info, RegisteredTransceiver>,
@@ -249,6 +594,19 @@ pub struct RegisterTransceiver<'info> {
 }
 
 pub fn register_transceiver(ctx: Context<RegisterTransceiver>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::RegisterTransceiver {
+            transceiver: ctx.accounts.transceiver.key(),
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
     let id = ctx.accounts.config.next_transceiver_id;
     ctx.accounts.config.next_transceiver_id += 1;
     ctx.accounts
@@ -263,17 +621,125 @@ pub fn register_transceiver(ctx: Context<RegisterTransceiver>) -> Result<()> {
     Ok(())
 }
 
-// * Limit rate adjustment
+// * Register receivers
+
 #[derive(Accounts)]
-pub struct SetOutboundLimit<'info> {
+pub struct RegisterReceiver<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
+    #[account(executable)]
+    /// CHECK: the receiver is meant to be a program implementing the
+    /// `receive_ntt_transfer` handler. See [`crate::registered_receiver`].
+    pub receiver: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        space = 8 + RegisteredReceiver::INIT_SPACE,
+        payer = payer,
+        seeds = [
+            RegisteredReceiver::SEED_PREFIX,
+            receiver_authority(&receiver.key()).as_ref(),
+            config.mint_seed(),
+        ],
+        bump
+    )]
+    pub registered_receiver: Account<'info, RegisteredReceiver>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_receiver(ctx: Context<RegisterReceiver>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::RegisterReceiver {
+            receiver: ctx.accounts.receiver.key(),
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    ctx.accounts
+        .registered_receiver
+        .set_inner(RegisteredReceiver {
+            bump: ctx.bumps.registered_receiver,
+            program: ctx.accounts.receiver.key(),
+        });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeregisterReceiver<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
     #[account(
-        constraint = config.owner == owner.key()
+        mut,
+        close = owner,
+        seeds = [
+            RegisteredReceiver::SEED_PREFIX,
+            receiver_authority(&registered_receiver.program).as_ref(),
+            config.mint_seed(),
+        ],
+        bump = registered_receiver.bump,
     )]
+    pub registered_receiver: Account<'info, RegisteredReceiver>,
+}
+
+/// After deregistering, transfers to the receiver's authority are released
+/// into its token account without invoking the receiver.
+pub fn deregister_receiver(ctx: Context<DeregisterReceiver>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::DeregisterReceiver {
+            receiver: ctx.accounts.registered_receiver.program,
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )
+}
+
+// * Limit rate adjustment
+#[derive(Accounts)]
+pub struct SetOutboundLimit<'info> {
     pub config: Account<'info, Config>,
 
+    #[account(mut)]
     pub owner: Signer<'info>,
 
+    /// Required when the timelock is enabled. See [`crate::timelock`].
     #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
+    #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
     pub rate_limit: Account<'info, OutboxRateLimit>,
 }
 
@@ -286,55 +752,824 @@ pub fn set_outbound_limit(
     ctx: Context<SetOutboundLimit>,
     args: SetOutboundLimitArgs,
 ) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetOutboundLimit { limit: args.limit },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
     ctx.accounts.rate_limit.set_limit(args.limit);
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(args: SetInboundLimitArgs)]
-pub struct SetInboundLimit<'info> {
-    #[account(
-        constraint = config.owner == owner.key()
-    )]
+pub struct SetMaxQueuedOutbound<'info> {
     pub config: Account<'info, Config>,
 
+    #[account(mut)]
     pub owner: Signer<'info>,
 
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
     #[account(
         mut,
-        seeds = [
-            InboxRateLimit::SEED_PREFIX,
-            args.chain_id.id.to_be_bytes().as_ref()
-        ],
-        bump = rate_limit.bump
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
     )]
-    pub rate_limit: Account<'info, InboxRateLimit>,
+    pub rate_limit: Account<'info, OutboxRateLimit>,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
-pub struct SetInboundLimitArgs {
-    pub limit: u64,
-    pub chain_id: ChainId,
+pub struct SetMaxQueuedOutboundArgs {
+    /// In the mint's native (untrimmed) units, like the rate limits.
+    /// `None` removes the cap.
+    pub max_queued_amount: Option<u64>,
 }
 
-pub fn set_inbound_limit(ctx: Context<SetInboundLimit>, args: SetInboundLimitArgs) -> Result<()> {
-    ctx.accounts.rate_limit.set_limit(args.limit);
-    Ok(())
+/// Caps the total amount of queued outbound transfers. Lowering the cap below
+/// the currently queued amount doesn't affect the transfers already queued,
+/// but no new ones can be queued until enough of them are released.
+pub fn set_max_queued_outbound(
+    ctx: Context<SetMaxQueuedOutbound>,
+    args: SetMaxQueuedOutboundArgs,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetMaxQueuedOutbound {
+            max_queued_amount: args.max_queued_amount,
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    ctx.accounts.rate_limit.max_queued_amount = args.max_queued_amount;
+    Ok(())
 }
 
-// * Pausing
 #[derive(Accounts)]
-pub struct SetPaused<'info> {
+#[instruction(args: SetInboundLimitArgs)]
+pub struct SetInboundLimit<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
     pub owner: Signer<'info>,
 
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
     #[account(
         mut,
-        has_one = owner,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = rate_limit.bump
     )]
+    pub rate_limit: Account<'info, InboxRateLimit>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetInboundLimitArgs {
+    pub limit: u64,
+    pub chain_id: ChainId,
+}
+
+pub fn set_inbound_limit(ctx: Context<SetInboundLimit>, args: SetInboundLimitArgs) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetInboundLimit {
+            chain_id: args.chain_id,
+            limit: args.limit,
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    ctx.accounts.rate_limit.set_limit(args.limit);
+    Ok(())
+}
+
+// * Limit ramps
+// Instead of changing a limit at once, the owner can schedule it to change
+// linearly over a period (see [`crate::queue::rate_limit::LimitRamp`]).
+// NOTE: cancelling a ramp is not subject to the timelock, as it can only leave
+// the limit somewhere between the two values the timelocked ramp allowed.
+
+#[derive(Accounts)]
+pub struct SetOutboundLimitRamp<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
+    #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
+    pub rate_limit: Account<'info, OutboxRateLimit>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetOutboundLimitRampArgs {
+    pub limit: u64,
+    /// The duration of the ramp, in seconds.
+    pub duration: i64,
+}
+
+pub fn set_outbound_limit_ramp(
+    ctx: Context<SetOutboundLimitRamp>,
+    args: SetOutboundLimitRampArgs,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetOutboundLimitRamp {
+            limit: args.limit,
+            duration: args.duration,
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    if args.duration <= 0 {
+        return Err(NTTError::InvalidRampDuration.into());
+    }
+
+    ctx.accounts
+        .rate_limit
+        .ramp_limit(args.limit, args.duration);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetInboundLimitRampArgs)]
+pub struct SetInboundLimitRamp<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Account<'info, InboxRateLimit>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetInboundLimitRampArgs {
+    pub chain_id: ChainId,
+    pub limit: u64,
+    /// The duration of the ramp, in seconds.
+    pub duration: i64,
+}
+
+pub fn set_inbound_limit_ramp(
+    ctx: Context<SetInboundLimitRamp>,
+    args: SetInboundLimitRampArgs,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetInboundLimitRamp {
+            chain_id: args.chain_id,
+            limit: args.limit,
+            duration: args.duration,
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    if args.duration <= 0 {
+        return Err(NTTError::InvalidRampDuration.into());
+    }
+
+    ctx.accounts
+        .rate_limit
+        .ramp_limit(args.limit, args.duration);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelOutboundLimitRamp<'info> {
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
+    pub rate_limit: Account<'info, OutboxRateLimit>,
+}
+
+pub fn cancel_outbound_limit_ramp(ctx: Context<CancelOutboundLimitRamp>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    ctx.accounts.rate_limit.cancel_ramp();
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: CancelInboundLimitRampArgs)]
+pub struct CancelInboundLimitRamp<'info> {
+    pub config: Account<'info, Config>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Account<'info, InboxRateLimit>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CancelInboundLimitRampArgs {
+    pub chain_id: ChainId,
+}
+
+pub fn cancel_inbound_limit_ramp(
+    ctx: Context<CancelInboundLimitRamp>,
+    _args: CancelInboundLimitRampArgs,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    ctx.accounts.rate_limit.cancel_ramp();
+    Ok(())
+}
+
+// * Owner set
+
+/// Replaces the m-of-n owner set (see [`Config::authorize_owner`]). Setting an
+/// empty set with a zero threshold goes back to single-owner authorization by
+/// [`Config::owner`].
+#[derive(Accounts)]
+pub struct SetOwnerSet<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetOwnerSetArgs {
+    pub keys: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+pub fn set_owner_set(ctx: Context<SetOwnerSet>, args: SetOwnerSetArgs) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    let owner_set = OwnerSet::new(args.keys, args.threshold)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetOwnerSet {
+            owner_set: owner_set.clone(),
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    ctx.accounts.config.owner_set = owner_set;
+    Ok(())
+}
+
+// * Queue expiry
+
+#[derive(Accounts)]
+pub struct SetMaxQueueAge<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetMaxQueueAgeArgs {
+    /// Seconds after the release timestamp after which unreleased outbound
+    /// transfers can be reclaimed. Zero disables expiry.
+    pub max_queue_age: i64,
+}
+
+pub fn set_max_queue_age(ctx: Context<SetMaxQueueAge>, args: SetMaxQueueAgeArgs) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    if args.max_queue_age < 0 {
+        return Err(NTTError::InvalidMaxQueueAge.into());
+    }
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetMaxQueueAge {
+            max_queue_age: args.max_queue_age,
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    ctx.accounts.config.max_queue_age = args.max_queue_age;
+    Ok(())
+}
+
+// * Capacity-aware release
+
+#[derive(Accounts)]
+pub struct SetCapacityAwareRelease<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+}
+
+pub fn set_capacity_aware_release(
+    ctx: Context<SetCapacityAwareRelease>,
+    enabled: bool,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetCapacityAwareRelease { enabled },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    ctx.accounts.config.capacity_aware_release = enabled;
+    Ok(())
+}
+
+// * Operator
+
+#[derive(Accounts)]
+pub struct SetOperator<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+}
+
+pub fn set_operator(ctx: Context<SetOperator>, operator: Option<Pubkey>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetOperator { operator },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    ctx.accounts.config.operator = operator;
+    Ok(())
+}
+
+// * Pausing
+// NOTE: pausing is deliberately not subject to the timelock, so that it can be
+// used to react to a compromised owner key during the timelock window.
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
     pub config: Account<'info, Config>,
 }
 
 pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    // The program may have been upgraded while paused. Don't resume until the
+    // config account has been migrated to the layout the program expects
+    // (see [`crate::instructions::migrate_config`]).
+    if !paused && ctx.accounts.config.layout_version != Config::LAYOUT_VERSION {
+        return Err(NTTError::ConfigLayoutMismatch.into());
+    }
+
     ctx.accounts.config.paused = paused;
     Ok(())
 }
+
+// * Upgrade
+
+/// Upgrades the program to the code in `buffer`.
+///
+/// The program has to be paused, and the upgrade authority of both the
+/// program and the buffer has to be the `upgrade_lock` PDA, so that the owner
+/// never needs to hold the upgrade authority directly. The authority can be
+/// handed to the `upgrade_lock` with the loader's (unchecked)
+/// `set_upgrade_authority` instruction.
+///
+/// The new code only takes effect in subsequent transactions, so its
+/// [`Config::LAYOUT_VERSION`] is checked when the program is unpaused (see
+/// [`set_paused`]).
+///
+/// In multi-token mode, the program is upgraded by its deployer with the
+/// loader directly, as the owner of a single token can't upgrade the program
+/// for all of them.
+#[derive(Accounts)]
+pub struct Upgrade<'info> {
+    #[account(
+        constraint = config.paused @ NTTError::NotPaused,
+        constraint = !config.multi_token @ NTTError::MultiTokenConfig,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
+    #[account(
+        seeds = [b"upgrade_lock"],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct address
+    upgrade_lock: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable_program,
+        constraint = program_data.upgrade_authority_address == Some(upgrade_lock.key())
+            @ NTTError::InvalidUpgradeAuthority,
+    )]
+    program_data: Account<'info, ProgramData>,
+
+    #[account(
+        mut,
+        address = crate::ID,
+    )]
+    /// CHECK: The address constraint enforces that this is the program itself
+    program: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: The loader checks that this is a buffer whose authority is the
+    /// `upgrade_lock`.
+    buffer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: Receives the buffer's lamports.
+    spill: UncheckedAccount<'info>,
+
+    rent: Sysvar<'info, Rent>,
+
+    clock: Sysvar<'info, Clock>,
+
+    bpf_loader_upgradeable_program: Program<'info, BpfLoaderUpgradeable>,
+}
+
+pub fn upgrade(ctx: Context<Upgrade>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::Upgrade {
+            buffer: ctx.accounts.buffer.key(),
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    solana_program::program::invoke_signed(
+        &solana_program::bpf_loader_upgradeable::upgrade(
+            &crate::ID,
+            &ctx.accounts.buffer.key(),
+            &ctx.accounts.upgrade_lock.key(),
+            &ctx.accounts.spill.key(),
+        ),
+        &[
+            ctx.accounts.program_data.to_account_info(),
+            ctx.accounts.program.to_account_info(),
+            ctx.accounts.buffer.to_account_info(),
+            ctx.accounts.spill.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            ctx.accounts.clock.to_account_info(),
+            ctx.accounts.upgrade_lock.to_account_info(),
+        ],
+        &[&[b"upgrade_lock", &[ctx.bumps.upgrade_lock]]],
+    )?;
+
+    Ok(())
+}
+
+// * Inbox item overrides
+// NOTE: like pausing, these are not subject to the timelock, as they are only
+// useful if they take effect before the inbound rate limit delay elapses.
+
+#[derive(Accounts)]
+pub struct OverrideInboxItem<'info> {
+    /// The owner or the operator.
+    pub authority: Signer<'info>,
+
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = inbox_item.mint == config.mint @ NTTError::InvalidMint,
+    )]
+    pub inbox_item: Account<'info, InboxItem>,
+}
+
+/// Make a queued inbound transfer releasable immediately, bypassing the
+/// remainder of the inbound rate limit delay.
+pub fn expedite_inbox_item(ctx: Context<OverrideInboxItem>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner_or_operator(&ctx.accounts.authority.key(), ctx.remaining_accounts)?;
+
+    ctx.accounts.inbox_item.expedite()
+}
+
+/// Freeze (or unfreeze) an inbound transfer that hasn't been released yet.
+/// A frozen transfer can't be released, but keeps its release status, so
+/// unfreezing it resumes where it left off.
+pub fn set_inbox_item_frozen(ctx: Context<OverrideInboxItem>, frozen: bool) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner_or_operator(&ctx.accounts.authority.key(), ctx.remaining_accounts)?;
+
+    ctx.accounts.inbox_item.set_frozen(frozen)
+}
+
+// * Custody ledger
+
+/// Creates the [`CustodyLedger`] of a deployment initialized before the ledger
+/// was introduced (newer deployments create it in
+/// [`crate::instructions::initialize`]). In locking mode, the ledger starts
+/// out with the current custody balance as its outbound total, so discrepancies
+/// are only detected from this point on, and any existing shortfall goes
+/// unnoticed.
+#[derive(Accounts)]
+pub struct InitializeCustodyLedger<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub config: Account<'info, Config>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
+    #[account(address = config.custody)]
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CustodyLedger::INIT_SPACE,
+        seeds = [CustodyLedger::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
+    pub custody_ledger: Account<'info, CustodyLedger>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_custody_ledger(ctx: Context<InitializeCustodyLedger>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::InitializeCustodyLedger,
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    let total_outbound = match ctx.accounts.config.mode {
+        Mode::Locking => u128::from(ctx.accounts.custody.amount),
+        Mode::Burning => 0,
+    };
+
+    ctx.accounts.custody_ledger.set_inner(CustodyLedger {
+        bump: ctx.bumps.custody_ledger,
+        total_outbound,
+        total_inbound: 0,
+    });
+
+    Ok(())
+}
+
+// * Timelock
+
+#[derive(Accounts)]
+#[instruction(action: AdminAction)]
+pub struct ProposeAdminAction<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        constraint = config.timelock_enabled() @ NTTError::TimelockDisabled,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        init,
+        space = 8 + PendingAdminAction::INIT_SPACE,
+        payer = payer,
+        seeds = [
+            PendingAdminAction::SEED_PREFIX,
+            action.keccak256().as_ref(),
+            config.mint_seed(),
+        ],
+        bump,
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_admin_action(ctx: Context<ProposeAdminAction>, action: AdminAction) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    let earliest_execution = current_timestamp().saturating_add(ctx.accounts.config.timelock_delay);
+
+    ctx.accounts.pending_action.set_inner(PendingAdminAction {
+        bump: ctx.bumps.pending_action,
+        action,
+        earliest_execution,
+        mint: ctx.accounts.config.mint,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelAdminAction<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = owner,
+        constraint = pending_action.mint == config.mint @ NTTError::InvalidMint,
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+}
+
+pub fn cancel_admin_action(ctx: Context<CancelAdminAction>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)
+}
+
+/// Enforces the timelock for an admin action that is carried out by another
+/// program, such as a standalone transceiver, which can't close the proposal
+/// itself. That program invokes this instruction before applying `action`.
+#[derive(Accounts)]
+pub struct ExecuteAdminAction<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub config: Account<'info, Config>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+}
+
+pub fn execute_admin_action(ctx: Context<ExecuteAdminAction>, action: AdminAction) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &action,
+        &ctx.accounts.owner.to_account_info(),
+    )
+}
+
+#[derive(Accounts)]
+pub struct SetTimelockDelay<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetTimelockDelayArgs {
+    /// The new delay in seconds. Zero disables the timelock.
+    pub delay: i64,
+}
+
+pub fn set_timelock_delay(
+    ctx: Context<SetTimelockDelay>,
+    args: SetTimelockDelayArgs,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    if args.delay < 0 {
+        return Err(NTTError::InvalidTimelockDelay.into());
+    }
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetTimelockDelay { delay: args.delay },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    ctx.accounts.config.timelock_delay = args.delay;
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use ntt_messages::mode::Mode;
+
+use crate::{
+    config::Config,
+    custody_ledger::CustodyLedger,
+    error::NTTError,
+    queue::inbox::{InboxItem, ReleaseStatus},
+};
+
+/// Checks that the custody account holds what the [`CustodyLedger`] says it
+/// should, and that it covers the approved but unreleased inbound transfers
+/// passed as remaining accounts (which are owed out of custody).
+///
+/// This instruction is permissionless. When `auto_pause` is set and the
+/// invariant is broken, the program is paused (without reverting), so the
+/// discrepancy can be investigated before any more tokens leave custody.
+/// As only approved inbox items are counted, passing additional items can't
+/// trigger a pause unless custody is in fact unable to cover them.
+#[derive(Accounts)]
+pub struct CheckCustodyInvariant<'info> {
+    #[account(
+        mut,
+        constraint = config.mode == Mode::Locking @ NTTError::InvalidMode,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(address = config.custody)]
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        seeds = [CustodyLedger::SEED_PREFIX, config.mint_seed()],
+        bump = custody_ledger.bump,
+    )]
+    pub custody_ledger: Account<'info, CustodyLedger>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CheckCustodyInvariantArgs {
+    pub auto_pause: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CustodyInvariant {
+    /// The balance of the custody account.
+    pub custody: u64,
+    /// The balance recorded by the custody ledger.
+    pub recorded: i128,
+    /// The total amount of the inbox items that were passed in.
+    pub pending_inbound: u128,
+    /// `custody` minus the larger of `recorded` and `pending_inbound`.
+    /// Negative when the invariant is broken.
+    pub discrepancy: i128,
+}
+
+pub fn check_custody_invariant<'info>(
+    ctx: Context<'_, '_, '_, 'info, CheckCustodyInvariant<'info>>,
+    args: CheckCustodyInvariantArgs,
+) -> Result<CustodyInvariant> {
+    let accs = ctx.accounts;
+
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+    let mut pending_inbound: u128 = 0;
+    for info in ctx.remaining_accounts {
+        if seen.contains(info.key) {
+            return Err(NTTError::DuplicateInboxItem.into());
+        }
+        seen.push(info.key());
+
+        let inbox_item = Account::<InboxItem>::try_from(info)?;
+        if inbox_item.mint != accs.config.mint {
+            return Err(NTTError::InvalidMint.into());
+        }
+        match inbox_item.release_status {
+            ReleaseStatus::NotApproved => return Err(NTTError::TransferNotApproved.into()),
+            ReleaseStatus::ReleaseAfter(_) => {}
+            ReleaseStatus::Released => return Err(NTTError::TransferAlreadyRedeemed.into()),
+        }
+        pending_inbound += u128::from(inbox_item.amount);
+    }
+
+    let custody = accs.custody.amount;
+    let recorded = accs.custody_ledger.recorded_balance();
+    let required = recorded.max(pending_inbound as i128);
+    let discrepancy = i128::from(custody) - required;
+
+    if discrepancy < 0 && args.auto_pause && !accs.config.paused {
+        msg!("Custody invariant broken, pausing: {}", discrepancy);
+        accs.config.paused = true;
+    }
+
+    Ok(CustodyInvariant {
+        custody,
+        recorded,
+        pending_inbound,
+        discrepancy,
+    })
+}
@@ -8,7 +8,10 @@ use crate::messages::Hack;
 
 use crate::{
     bitmap::Bitmap,
+    config::{mint_seed, OwnerSet, TokenMode},
+    custody_ledger::CustodyLedger,
     error::NTTError,
+    mint_extensions::MintExtensions,
     queue::{outbox::OutboxRateLimit, rate_limit::RateLimitState},
 };
 
@@ -28,34 +31,65 @@ pub struct Initialize<'info> {
     )]
     program_data: Account<'info, ProgramData>,
 
+    // NOTE: declared before the accounts whose seeds refer to it.
+    #[account(
+        constraint =
+            args.mode == Mode::Locking
+            || mint.mint_authority.unwrap() == token_authority.key()
+            @ NTTError::InvalidMintAuthority,
+    )]
+    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
     #[account(
         init,
         space = 8 + crate::config::Config::INIT_SPACE,
         payer = payer,
-        seeds = [crate::config::Config::SEED_PREFIX],
+        seeds = [
+            crate::config::Config::SEED_PREFIX,
+            mint_seed(&mint.key(), args.multi_token),
+        ],
         bump
     )]
     pub config: Box<Account<'info, crate::config::Config>>,
 
     #[account(
-        constraint =
-            args.mode == Mode::Locking
-            || mint.mint_authority.unwrap() == token_authority.key()
-            @ NTTError::InvalidMintAuthority,
+        init_if_needed,
+        payer = payer,
+        space = 8 + TokenMode::INIT_SPACE,
+        seeds = [TokenMode::SEED_PREFIX],
+        bump,
     )]
-    pub mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+    pub token_mode: Box<Account<'info, TokenMode>>,
+
+    #[account(
+        seeds = [crate::config::Config::SEED_PREFIX],
+        bump,
+    )]
+    /// CHECK: the single-token config, which must not exist in multi-token
+    /// mode. Deployments predating [`TokenMode`] only have this to go by.
+    /// Required in multi-token mode.
+    pub single_token_config: Option<UncheckedAccount<'info>>,
 
     #[account(
         init,
         payer = payer,
         space = 8 + OutboxRateLimit::INIT_SPACE,
-        seeds = [OutboxRateLimit::SEED_PREFIX],
+        seeds = [OutboxRateLimit::SEED_PREFIX, mint_seed(&mint.key(), args.multi_token)],
         bump,
     )]
     pub rate_limit: Account<'info, OutboxRateLimit>,
 
     #[account(
-        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        init,
+        payer = payer,
+        space = 8 + CustodyLedger::INIT_SPACE,
+        seeds = [CustodyLedger::SEED_PREFIX, mint_seed(&mint.key(), args.multi_token)],
+        bump,
+    )]
+    pub custody_ledger: Box<Account<'info, CustodyLedger>>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED, mint_seed(&mint.key(), args.multi_token)],
         bump,
     )]
     /// CHECK: [`token_authority`] is checked against the custody account and the [`mint`]'s mint_authority
@@ -93,9 +127,32 @@ pub struct InitializeArgs {
     pub chain_id: u16,
     pub limit: u64,
     pub mode: ntt_messages::mode::Mode,
+    /// Initialize one of many tokens managed by this program, keying all its
+    /// accounts by the mint. See [`crate::config::mint_seed`].
+    /// Single-token deployments can't be converted later, and a program can't
+    /// have configs of both kinds (see [`TokenMode`]).
+    pub multi_token: bool,
 }
 
 pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()> {
+    let mint_extensions = MintExtensions::check(&ctx.accounts.mint.to_account_info(), args.mode)?;
+
+    let token_mode = &mut ctx.accounts.token_mode;
+    if token_mode.initialized && token_mode.multi_token != args.multi_token {
+        return Err(NTTError::MixedTokenModes.into());
+    }
+    if args.multi_token {
+        match &ctx.accounts.single_token_config {
+            Some(single_token_config) if single_token_config.data_is_empty() => {}
+            _ => return Err(NTTError::MixedTokenModes.into()),
+        }
+    }
+    token_mode.set_inner(TokenMode {
+        bump: ctx.bumps.token_mode,
+        initialized: true,
+        multi_token: args.multi_token,
+    });
+
     ctx.accounts.config.set_inner(crate::config::Config {
         bump: ctx.bumps.config,
         mint: ctx.accounts.mint.key(),
@@ -110,10 +167,28 @@ pub fn initialize(ctx: Context<Initialize>, args: InitializeArgs) -> Result<()>
         threshold: 1,
         enabled_transceivers: Bitmap::new(),
         custody: ctx.accounts.custody.key(),
+        timelock_delay: 0,
+        owner_set: OwnerSet::default(),
+        max_queue_age: 0,
+        capacity_aware_release: false,
+        operator: None,
+        mint_extensions,
+        layout_version: crate::config::Config::LAYOUT_VERSION,
+        multi_token: args.multi_token,
+        wormhole_finality: crate::transceivers::wormhole::accounts::DEFAULT_FINALITY,
+        upgrade_authority_escrowed: false,
     });
 
     ctx.accounts.rate_limit.set_inner(OutboxRateLimit {
         rate_limit: RateLimitState::new(args.limit),
+        queued_amount: 0,
+        max_queued_amount: None,
+    });
+
+    ctx.accounts.custody_ledger.set_inner(CustodyLedger {
+        bump: ctx.bumps.custody_ledger,
+        total_outbound: 0,
+        total_inbound: 0,
     });
 
     Ok(())
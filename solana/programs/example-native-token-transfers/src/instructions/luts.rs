@@ -21,12 +21,40 @@
 //! often, so the extra allocation is justifiable.
 //!
 //! Because of all the above, this instruction can be called permissionlessly.
+//!
+//! The [`Entries`] only cover the accounts that are the same for every
+//! transfer. The per-chain accounts (peer, inbox rate limit and transceiver
+//! peer), the registered transceivers and the transfer hook accounts are added
+//! to the current LUT with the `extend_lut_*` instructions. These are
+//! permissionless too, as the accounts are all validated, and each account is
+//! only added once. When peers or transceivers change, the LUT can be
+//! refreshed by calling [`initialize_lut`] again and re-extending the new one.
+//!
+//! Old LUTs are kept, so that clients holding references to them keep working,
+//! until the owner deactivates (and eventually closes) them with
+//! [`deactivate_lut`] and [`close_lut`].
+//!
+//! In multi-token mode, each token has its own LUT (and LUT authority), keyed
+//! by the mint. See [`crate::config::mint_seed`].
 
 use anchor_lang::prelude::*;
 use solana_address_lookup_table_program;
-use solana_program::program::{invoke, invoke_signed};
+use solana_program::{
+    address_lookup_table::state::AddressLookupTable,
+    program::{invoke, invoke_signed},
+};
 
-use crate::{config::Config, queue::outbox::OutboxRateLimit, transceivers::wormhole::accounts::*};
+use crate::{
+    config::Config,
+    error::NTTError,
+    peer::NttManagerPeer,
+    queue::{inbox::InboxRateLimit, outbox::OutboxRateLimit},
+    registered_transceiver::RegisteredTransceiver,
+    transceivers::{accounts::peer::TransceiverPeer, wormhole::accounts::*},
+    transfer_hook::transfer_hook_program,
+};
+
+const LUT_AUTHORITY_SEED: &[u8] = b"lut_authority";
 
 #[account]
 #[derive(InitSpace)]
@@ -41,8 +69,12 @@ pub struct InitializeLUT<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// These are the entries that will populate the LUT.
+    /// NOTE: declared before the accounts whose seeds refer to the config.
+    pub entries: Entries<'info>,
+
     #[account(
-        seeds = [b"lut_authority"],
+        seeds = [LUT_AUTHORITY_SEED, entries.config.mint_seed()],
         bump
     )]
     /// CHECK: The seeds constraint enforces that this is the correct account.
@@ -61,7 +93,7 @@ pub struct InitializeLUT<'info> {
         init_if_needed,
         payer = payer,
         space = 8 + LUT::INIT_SPACE,
-        seeds = [b"lut"],
+        seeds = [b"lut", entries.config.mint_seed()],
         bump
     )]
     pub lut: Account<'info, LUT>,
@@ -71,9 +103,6 @@ pub struct InitializeLUT<'info> {
     pub lut_program: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
-
-    /// These are the entries that will populate the LUT.
-    pub entries: Entries<'info>,
 }
 
 #[derive(Accounts)]
@@ -99,12 +128,16 @@ pub struct Entries<'info> {
     pub mint: UncheckedAccount<'info>,
 
     #[account(
-        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        seeds = [crate::TOKEN_AUTHORITY_SEED, config.mint_seed()],
         bump,
     )]
     /// CHECK: The seeds constraint enforces that this is the correct account.
     pub token_authority: UncheckedAccount<'info>,
 
+    #[account(
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
     pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
 
     // NOTE: this includes the system program so we don't need to add it in the outer context
@@ -156,11 +189,243 @@ pub fn initialize_lut(ctx: Context<InitializeLUT>, recent_slot: u64) -> Result<(
     entries.push(crate::ID);
     entries.extend(entries_infos.into_iter().map(|x| x.key));
 
+    extend_lookup_table(
+        ctx.accounts.lut_address.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.entries.config.mint_seed(),
+        ctx.bumps.authority,
+        entries,
+    )
+}
+
+fn extend_lookup_table<'info>(
+    lut_address: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    mint_seed: &[u8],
+    authority_bump: u8,
+    entries: Vec<Pubkey>,
+) -> Result<()> {
     let ix = solana_address_lookup_table_program::instruction::extend_lookup_table(
+        lut_address.key(),
+        authority.key(),
+        Some(payer.key()),
+        entries,
+    );
+
+    invoke_signed(
+        &ix,
+        &[lut_address, authority, payer, system_program],
+        &[&[LUT_AUTHORITY_SEED, mint_seed, &[authority_bump]]],
+    )?;
+
+    Ok(())
+}
+
+// * Extending the LUT
+
+#[derive(Accounts)]
+pub struct ExtendLUT<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [LUT_AUTHORITY_SEED, config.mint_seed()],
+        bump
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct account.
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"lut", config.mint_seed()],
+        bump = lut.bump,
+    )]
+    pub lut: Account<'info, LUT>,
+
+    #[account(
+        mut,
+        address = lut.address,
+    )]
+    /// CHECK: The address constraint enforces that this is the current LUT.
+    pub lut_address: UncheckedAccount<'info>,
+
+    /// CHECK: address lookup table program (checked by instruction)
+    #[account(executable)]
+    pub lut_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExtendLUT<'info> {
+    /// Appends the `entries` that are not yet in the LUT. Does nothing if all
+    /// of them are, so that the LUT can't be filled up with duplicates.
+    fn extend(&self, authority_bump: u8, entries: Vec<Pubkey>) -> Result<()> {
+        let new_entries = {
+            let data = self.lut_address.try_borrow_data()?;
+            let lut = AddressLookupTable::deserialize(&data)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            let mut new_entries: Vec<Pubkey> = vec![];
+            for entry in entries {
+                if !lut.addresses.contains(&entry) && !new_entries.contains(&entry) {
+                    new_entries.push(entry);
+                }
+            }
+            new_entries
+        };
+
+        if new_entries.is_empty() {
+            return Ok(());
+        }
+
+        extend_lookup_table(
+            self.lut_address.to_account_info(),
+            self.authority.to_account_info(),
+            self.payer.to_account_info(),
+            self.system_program.to_account_info(),
+            self.config.mint_seed(),
+            authority_bump,
+            new_entries,
+        )
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct ExtendLUTWithPeer<'info> {
+    pub common: ExtendLUT<'info>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref(), common.config.mint_seed()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        seeds = [InboxRateLimit::SEED_PREFIX, chain_id.to_be_bytes().as_ref(), common.config.mint_seed()],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [TransceiverPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref(), common.config.mint_seed()],
+        bump = transceiver_peer.bump,
+    )]
+    pub transceiver_peer: Account<'info, TransceiverPeer>,
+}
+
+/// Adds the accounts of the peer on `chain_id` to the current LUT.
+pub fn extend_lut_with_peer(ctx: Context<ExtendLUTWithPeer>, _chain_id: u16) -> Result<()> {
+    ctx.accounts.common.extend(
+        ctx.bumps.common.authority,
+        vec![
+            ctx.accounts.peer.key(),
+            ctx.accounts.inbox_rate_limit.key(),
+            ctx.accounts.transceiver_peer.key(),
+        ],
+    )
+}
+
+#[derive(Accounts)]
+pub struct ExtendLUTWithTransceiver<'info> {
+    pub common: ExtendLUT<'info>,
+
+    #[account(
+        seeds = [RegisteredTransceiver::SEED_PREFIX, registered_transceiver.transceiver_address.as_ref(), common.config.mint_seed()],
+        bump = registered_transceiver.bump,
+    )]
+    pub registered_transceiver: Account<'info, RegisteredTransceiver>,
+}
+
+/// Adds a registered transceiver (and its registration account) to the
+/// current LUT.
+pub fn extend_lut_with_transceiver(ctx: Context<ExtendLUTWithTransceiver>) -> Result<()> {
+    ctx.accounts.common.extend(
+        ctx.bumps.common.authority,
+        vec![
+            ctx.accounts.registered_transceiver.transceiver_address,
+            ctx.accounts.registered_transceiver.key(),
+        ],
+    )
+}
+
+#[derive(Accounts)]
+pub struct ExtendLUTWithTransferHook<'info> {
+    pub common: ExtendLUT<'info>,
+
+    #[account(
+        address = common.config.mint,
+    )]
+    /// CHECK: The address constraint enforces that this is the correct account.
+    pub mint: UncheckedAccount<'info>,
+}
+
+/// Adds the transfer hook program of the mint and its validation account to
+/// the current LUT. The hook's extra accounts generally depend on the
+/// transfer, so they are not included. See [`crate::transfer_hook`].
+pub fn extend_lut_with_transfer_hook(ctx: Context<ExtendLUTWithTransferHook>) -> Result<()> {
+    let mint = &ctx.accounts.mint;
+    let Some(program_id) = transfer_hook_program(mint)? else {
+        return Ok(());
+    };
+
+    ctx.accounts.common.extend(
+        ctx.bumps.common.authority,
+        vec![
+            program_id,
+            spl_transfer_hook_interface::get_extra_account_metas_address(&mint.key(), &program_id),
+        ],
+    )
+}
+
+// * Retiring old LUTs
+
+#[derive(Accounts)]
+pub struct RetireLUT<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [LUT_AUTHORITY_SEED, config.mint_seed()],
+        bump
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct account.
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"lut", config.mint_seed()],
+        bump = lut.bump,
+    )]
+    pub lut: Account<'info, LUT>,
+
+    #[account(
+        mut,
+        constraint = lut_address.key() != lut.address @ NTTError::CurrentLUT,
+    )]
+    /// CHECK: the lookup table program checks that the LUT belongs to
+    /// `authority`.
+    pub lut_address: UncheckedAccount<'info>,
+
+    /// CHECK: address lookup table program (checked by instruction)
+    #[account(executable)]
+    pub lut_program: AccountInfo<'info>,
+}
+
+/// Deactivates an old LUT. The current LUT can't be deactivated.
+pub fn deactivate_lut(ctx: Context<RetireLUT>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    let ix = solana_address_lookup_table_program::instruction::deactivate_lookup_table(
         ctx.accounts.lut_address.key(),
         ctx.accounts.authority.key(),
-        Some(ctx.accounts.payer.key()),
-        entries,
     );
 
     invoke_signed(
@@ -168,10 +433,42 @@ pub fn initialize_lut(ctx: Context<InitializeLUT>, recent_slot: u64) -> Result<(
         &[
             ctx.accounts.lut_address.to_account_info(),
             ctx.accounts.authority.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
         ],
-        &[&[b"lut_authority", &[ctx.bumps.authority]]],
+        &[&[
+            LUT_AUTHORITY_SEED,
+            ctx.accounts.config.mint_seed(),
+            &[ctx.bumps.authority],
+        ]],
+    )?;
+
+    Ok(())
+}
+
+/// Closes a deactivated LUT once it has cooled down, and sends its rent to the
+/// owner.
+pub fn close_lut(ctx: Context<RetireLUT>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    let ix = solana_address_lookup_table_program::instruction::close_lookup_table(
+        ctx.accounts.lut_address.key(),
+        ctx.accounts.authority.key(),
+        ctx.accounts.owner.key(),
+    );
+
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.lut_address.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+        ],
+        &[&[
+            LUT_AUTHORITY_SEED,
+            ctx.accounts.config.mint_seed(),
+            &[ctx.bumps.authority],
+        ]],
     )?;
 
     Ok(())
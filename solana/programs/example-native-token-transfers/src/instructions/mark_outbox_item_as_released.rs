@@ -1,5 +1,10 @@
 use crate::{
-    config::*, error::NTTError, queue::outbox::OutboxItem,
+    config::*,
+    error::NTTError,
+    queue::{
+        inbox::InboxRateLimit,
+        outbox::{OutboxItem, OutboxRateLimit},
+    },
     registered_transceiver::RegisteredTransceiver,
 };
 use anchor_lang::prelude::*;
@@ -20,10 +25,37 @@ pub struct MarkOutboxItemAsReleased<'info> {
     #[account(
         mut,
         constraint = !outbox_item.released.get(transceiver.id)? @ NTTError::MessageAlreadySent,
+        constraint = outbox_item.mint == config.mint @ NTTError::InvalidMint,
     )]
     pub outbox_item: Account<'info, OutboxItem>,
 
     #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            outbox_item.recipient_chain.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = inbox_rate_limit.bump,
+    )]
+    /// Refilled (backflow) when a queued transfer consumes its deferred
+    /// capacity. See [`OutboxItem::try_release`].
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [
+            RegisteredTransceiver::SEED_PREFIX,
+            transceiver.transceiver_address.as_ref(),
+            config.mint_seed(),
+        ],
+        bump = transceiver.bump,
         constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
     )]
     pub transceiver: Account<'info, RegisteredTransceiver>,
@@ -31,6 +63,16 @@ pub struct MarkOutboxItemAsReleased<'info> {
 
 pub fn mark_outbox_item_as_released(ctx: Context<MarkOutboxItemAsReleased>) -> Result<bool> {
     let accs = ctx.accounts;
-    let released = accs.outbox_item.try_release(accs.transceiver.id)?;
+    let queued = accs.outbox_item.deferred_capacity;
+    let rate_limits = accs.config.capacity_aware_release.then_some((
+        &mut accs.outbox_rate_limit.rate_limit,
+        &mut accs.inbox_rate_limit.rate_limit,
+    ));
+    let released = accs
+        .outbox_item
+        .try_release(accs.transceiver.id, rate_limits)?;
+    if released {
+        accs.outbox_rate_limit.dequeue(queued);
+    }
     Ok(released)
 }
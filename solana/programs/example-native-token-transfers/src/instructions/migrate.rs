@@ -0,0 +1,411 @@
+//! Migrations of accounts created by earlier versions of the program to their
+//! current layout. See [`crate::legacy`].
+//!
+//! The migrations are permissionless, as they don't change what the accounts
+//! say. New fields are set to the values that newly created accounts start out
+//! with, unless noted otherwise. The payer tops up the rent of the accounts
+//! that grow.
+
+use anchor_lang::prelude::*;
+use ntt_messages::{
+    chain_id::ChainId, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage,
+    trimmed_amount::TrimmedAmount,
+};
+
+use crate::{
+    config::{Config, OwnerSet},
+    legacy::{
+        self, LegacyConfig, LegacyInboxItem, LegacyInboxRateLimit, LegacyNttManagerPeer,
+        LegacyOutboxItem, LegacyOutboxRateLimit, LegacyTransceiverPeer,
+    },
+    mint_extensions::MintExtensions,
+    peer::NttManagerPeer,
+    queue::{
+        inbox::{InboxItem, InboxRateLimit},
+        outbox::{OutboxItem, OutboxRateLimit},
+    },
+    transceivers::{accounts::peer::TransceiverPeer, wormhole::accounts::DEFAULT_FINALITY},
+    transfer::Payload,
+};
+
+// * Config
+
+/// Migrates a config created by an earlier version of the program.
+/// The layout version is set to [`Config::LAYOUT_VERSION`], so that the
+/// program can be unpaused after the upgrade (see
+/// [`crate::instructions::set_paused`]).
+///
+/// The config has to be migrated before the other accounts.
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: a config in the legacy layout, which can't be deserialized as a
+    /// [`Config`]. Checked by [`legacy::read`].
+    pub config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let legacy = legacy::read::<Config, LegacyConfig>(&ctx.accounts.config.try_borrow_data()?)?;
+
+    let config = Config {
+        bump: legacy.bump,
+        owner: legacy.owner,
+        pending_owner: legacy.pending_owner,
+        mint: legacy.mint,
+        token_program: legacy.token_program,
+        mode: legacy.mode,
+        chain_id: legacy.chain_id,
+        next_transceiver_id: legacy.next_transceiver_id,
+        threshold: legacy.threshold,
+        enabled_transceivers: legacy.enabled_transceivers,
+        paused: legacy.paused,
+        custody: legacy.custody,
+        timelock_delay: 0,
+        owner_set: OwnerSet::default(),
+        max_queue_age: 0,
+        capacity_aware_release: false,
+        operator: None,
+        // NOTE: the mint isn't inspected, as rejecting the mint of an existing
+        // deployment would lock it up.
+        mint_extensions: MintExtensions::default(),
+        layout_version: Config::LAYOUT_VERSION,
+        multi_token: false,
+        wormhole_finality: DEFAULT_FINALITY,
+        // Earlier versions always escrowed the upgrade authority in the upgrade
+        // lock while an ownership transfer was pending.
+        upgrade_authority_escrowed: legacy.pending_owner.is_some(),
+    };
+
+    legacy::rewrite(
+        &ctx.accounts.config,
+        &config,
+        8 + Config::INIT_SPACE,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )
+}
+
+// * Rate limits
+
+#[derive(Accounts)]
+pub struct MigrateOutboxRateLimit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: checked by [`legacy::read`].
+    pub outbox_rate_limit: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// NOTE: the queued amount starts out at zero, as the transfers queued before
+/// the migration weren't tracked. See [`OutboxRateLimit::dequeue`].
+pub fn migrate_outbox_rate_limit(ctx: Context<MigrateOutboxRateLimit>) -> Result<()> {
+    let legacy = legacy::read::<OutboxRateLimit, LegacyOutboxRateLimit>(
+        &ctx.accounts.outbox_rate_limit.try_borrow_data()?,
+    )?;
+
+    let outbox_rate_limit = OutboxRateLimit {
+        rate_limit: legacy.rate_limit.into(),
+        queued_amount: 0,
+        max_queued_amount: None,
+    };
+
+    legacy::rewrite(
+        &ctx.accounts.outbox_rate_limit,
+        &outbox_rate_limit,
+        8 + OutboxRateLimit::INIT_SPACE,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct MigrateInboxRateLimit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [InboxRateLimit::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: checked by [`legacy::read`].
+    pub inbox_rate_limit: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_inbox_rate_limit(ctx: Context<MigrateInboxRateLimit>, _chain_id: u16) -> Result<()> {
+    let legacy = legacy::read::<InboxRateLimit, LegacyInboxRateLimit>(
+        &ctx.accounts.inbox_rate_limit.try_borrow_data()?,
+    )?;
+
+    let inbox_rate_limit = InboxRateLimit {
+        bump: legacy.bump,
+        rate_limit: legacy.rate_limit.into(),
+    };
+
+    legacy::rewrite(
+        &ctx.accounts.inbox_rate_limit,
+        &inbox_rate_limit,
+        8 + InboxRateLimit::INIT_SPACE,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )
+}
+
+// * Peers
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct MigratePeer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: checked by [`legacy::read`].
+    pub peer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// NOTE: earlier versions didn't record the peer's token address, so it's left
+/// zeroed, and inbound transfers from the peer are rejected until the owner
+/// sets it with [`crate::instructions::set_peer`].
+pub fn migrate_peer(ctx: Context<MigratePeer>, _chain_id: u16) -> Result<()> {
+    let legacy = legacy::read::<NttManagerPeer, LegacyNttManagerPeer>(
+        &ctx.accounts.peer.try_borrow_data()?,
+    )?;
+
+    let peer = NttManagerPeer {
+        bump: legacy.bump,
+        address: legacy.address,
+        token_decimals: legacy.token_decimals,
+        token_address: [0; 32],
+        settlement_delays: Vec::new(),
+        outbound_paused: false,
+    };
+
+    legacy::rewrite(
+        &ctx.accounts.peer,
+        &peer,
+        8 + NttManagerPeer::INIT_SPACE,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct MigrateTransceiverPeer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TransceiverPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: checked by [`legacy::read`].
+    pub peer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_transceiver_peer(
+    ctx: Context<MigrateTransceiverPeer>,
+    _chain_id: u16,
+) -> Result<()> {
+    let legacy = legacy::read::<TransceiverPeer, LegacyTransceiverPeer>(
+        &ctx.accounts.peer.try_borrow_data()?,
+    )?;
+
+    let peer = TransceiverPeer {
+        bump: legacy.bump,
+        address: legacy.address,
+        finality: None,
+        pause_signal_sequence: None,
+    };
+
+    legacy::rewrite(
+        &ctx.accounts.peer,
+        &peer,
+        8 + TransceiverPeer::INIT_SPACE,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )
+}
+
+// * Queued transfers
+
+/// Migrates an inbound transfer that was redeemed (but not released) before
+/// the upgrade.
+#[derive(Accounts)]
+#[instruction(args: MigrateInboxItemArgs)]
+pub struct MigrateInboxItem<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxItem::SEED_PREFIX,
+            args.message().keccak256(args.from_chain).as_ref(),
+        ],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: checked by [`legacy::read`].
+    pub inbox_item: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The message of the transfer, which the inbox item is keyed by. Provides the
+/// fields that the legacy layout doesn't have. The message is passed as its
+/// fields, as clients can't encode the generic message type.
+///
+/// NOTE: earlier versions didn't support additional payloads, so the
+/// message's additional payload is omitted (see
+/// [`ntt_messages::ntt::RawPayload`]).
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct MigrateInboxItemArgs {
+    /// The chain the transfer was sent from.
+    pub from_chain: ChainId,
+    pub id: [u8; 32],
+    pub sender: [u8; 32],
+    pub amount: TrimmedAmount,
+    pub source_token: [u8; 32],
+    pub to_chain: ChainId,
+    pub to: [u8; 32],
+}
+
+impl MigrateInboxItemArgs {
+    /// The legacy message, which has no additional payload.
+    pub fn message(&self) -> NttManagerMessage<NativeTokenTransfer<Payload>> {
+        NttManagerMessage {
+            id: self.id,
+            sender: self.sender,
+            payload: NativeTokenTransfer {
+                amount: self.amount,
+                source_token: self.source_token,
+                to_chain: self.to_chain,
+                to: self.to,
+                additional_payload: Payload::default(),
+            },
+        }
+    }
+}
+
+pub fn migrate_inbox_item(
+    ctx: Context<MigrateInboxItem>,
+    args: MigrateInboxItemArgs,
+) -> Result<()> {
+    let legacy =
+        legacy::read::<InboxItem, LegacyInboxItem>(&ctx.accounts.inbox_item.try_borrow_data()?)?;
+
+    // legacy transfers don't carry an additional payload
+    let space = InboxItem::space(0);
+
+    let inbox_item = InboxItem {
+        init: legacy.init,
+        bump: legacy.bump,
+        amount: legacy.amount,
+        recipient_address: legacy.recipient_address,
+        votes: legacy.votes,
+        release_status: legacy.release_status,
+        from_chain: args.from_chain,
+        deferred_capacity: 0,
+        frozen: false,
+        sender: args.sender,
+        mint: ctx.accounts.config.mint,
+        additional_payload: Payload::default(),
+    };
+
+    legacy::rewrite(
+        &ctx.accounts.inbox_item,
+        &inbox_item,
+        space,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )
+}
+
+/// Migrates an outbound transfer that was queued (or not released by all
+/// transceivers) before the upgrade.
+#[derive(Accounts)]
+pub struct MigrateOutboxItem<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    /// CHECK: checked by [`legacy::read`].
+    pub outbox_item: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_outbox_item(ctx: Context<MigrateOutboxItem>) -> Result<()> {
+    let legacy =
+        legacy::read::<OutboxItem, LegacyOutboxItem>(&ctx.accounts.outbox_item.try_borrow_data()?)?;
+
+    let outbox_item = OutboxItem {
+        amount: legacy.amount,
+        sender: legacy.sender,
+        recipient_chain: legacy.recipient_chain,
+        recipient_ntt_manager: legacy.recipient_ntt_manager,
+        recipient_address: legacy.recipient_address,
+        release_timestamp: legacy.release_timestamp,
+        released: legacy.released,
+        deferred_capacity: 0,
+        mint: ctx.accounts.config.mint,
+    };
+
+    legacy::rewrite(
+        &ctx.accounts.outbox_item,
+        &outbox_item,
+        8 + OutboxItem::INIT_SPACE,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )
+}
@@ -1,15 +1,23 @@
 pub mod admin;
+pub mod check_custody;
 pub mod initialize;
 pub mod luts;
 pub mod mark_outbox_item_as_released;
+pub mod migrate;
+pub mod reclaim_outbox_item;
 pub mod redeem;
 pub mod release_inbound;
 pub mod transfer;
+pub mod views;
 
 pub use admin::*;
+pub use check_custody::*;
 pub use initialize::*;
 pub use luts::*;
 pub use mark_outbox_item_as_released::*;
+pub use migrate::*;
+pub use reclaim_outbox_item::*;
 pub use redeem::*;
 pub use release_inbound::*;
 pub use transfer::*;
+pub use views::*;
@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use ntt_messages::mode::Mode;
+use spl_token_2022::onchain;
+
+use crate::{
+    config::*,
+    custody_ledger::CustodyLedger,
+    error::NTTError,
+    queue::outbox::{OutboxItem, OutboxRateLimit},
+};
+
+#[derive(Accounts)]
+pub struct ReclaimOutboxItem<'info> {
+    pub config: NotPausedConfig<'info>,
+
+    #[account(
+        mut,
+        close = sender,
+        constraint = outbox_item.released.is_empty() @ NTTError::MessageAlreadySent,
+        constraint = outbox_item.is_expired(config.max_queue_age) @ NTTError::OutboxItemNotExpired,
+        constraint = outbox_item.mint == config.mint @ NTTError::InvalidMint,
+    )]
+    pub outbox_item: Account<'info, OutboxItem>,
+
+    #[account(
+        mut,
+        address = outbox_item.sender,
+    )]
+    /// CHECK: the address is checked against the outbox item. The rent of the
+    /// outbox item is returned to this account.
+    pub sender: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::authority = outbox_item.sender,
+        associated_token::mint = mint,
+        associated_token::token_program = token_program,
+    )]
+    /// The tokens are always refunded to the sender's associated token account,
+    /// so that anyone can crank the reclaim on behalf of the sender.
+    pub sender_token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED, config.mint_seed()],
+        bump,
+    )]
+    /// CHECK The seeds constraint ensures that this is the correct address
+    pub token_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = config.mint,
+    )]
+    /// CHECK: the mint address matches the config
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    #[account(
+        mut,
+        address = config.custody
+    )]
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+}
+
+impl<'info> ReclaimOutboxItem<'info> {
+    /// A reclaimed item that was still queued no longer counts towards the
+    /// queued total (see [`OutboxRateLimit::max_queued_amount`]).
+    fn dequeue(&mut self) {
+        let queued = self.outbox_item.deferred_capacity;
+        self.outbox_rate_limit.dequeue(queued);
+    }
+}
+
+// NOTE: reclaiming does not give back the outbound rate limit capacity (or
+// take back the inbound backflow) that the transfer may have consumed when it
+// was sent. Items that were queued never consumed any capacity in the first
+// place.
+
+// Burn/mint
+
+#[derive(Accounts)]
+pub struct ReclaimOutboxItemMint<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Burning @ NTTError::InvalidMode,
+    )]
+    common: ReclaimOutboxItem<'info>,
+}
+
+/// Refund an expired outbox item that no transceiver has released by minting
+/// the tokens back to the sender, and close the outbox item.
+/// This instruction is permissionless.
+pub fn reclaim_outbox_item_mint<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReclaimOutboxItemMint<'info>>,
+) -> Result<()> {
+    ctx.accounts.common.dequeue();
+    let accs = &ctx.accounts.common;
+
+    let amount = accs
+        .outbox_item
+        .amount
+        .untrim(accs.mint.decimals)
+        .map_err(NTTError::from)?;
+
+    // NOTE: like in [`crate::instructions::release_inbound_mint`], we mint to
+    // the custody account first, and then transfer to the sender, so that the
+    // transfer hook (if any) is invoked.
+
+    // Step 1: mint tokens to the custody account
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            accs.token_program.to_account_info(),
+            token_interface::MintTo {
+                mint: accs.mint.to_account_info(),
+                to: accs.custody.to_account_info(),
+                authority: accs.token_authority.to_account_info(),
+            },
+            &[&[
+                crate::TOKEN_AUTHORITY_SEED,
+                accs.config.mint_seed(),
+                &[ctx.bumps.common.token_authority],
+            ]],
+        ),
+        amount,
+    )?;
+
+    // Step 2: transfer the tokens from the custody account to the sender
+    onchain::invoke_transfer_checked(
+        &accs.token_program.key(),
+        accs.custody.to_account_info(),
+        accs.mint.to_account_info(),
+        accs.sender_token_account.to_account_info(),
+        accs.token_authority.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        accs.mint.decimals,
+        &[&[
+            crate::TOKEN_AUTHORITY_SEED,
+            accs.config.mint_seed(),
+            &[ctx.bumps.common.token_authority],
+        ]],
+    )?;
+    Ok(())
+}
+
+// Lock/unlock
+
+#[derive(Accounts)]
+pub struct ReclaimOutboxItemUnlock<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Locking @ NTTError::InvalidMode,
+    )]
+    common: ReclaimOutboxItem<'info>,
+
+    #[account(
+        mut,
+        seeds = [CustodyLedger::SEED_PREFIX, common.config.mint_seed()],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures that this is the custody ledger,
+    /// which may not be initialized. See [`CustodyLedger::record_if_initialized`].
+    custody_ledger: UncheckedAccount<'info>,
+}
+
+/// Refund an expired outbox item that no transceiver has released by unlocking
+/// the tokens back to the sender, and close the outbox item.
+/// This instruction is permissionless.
+pub fn reclaim_outbox_item_unlock<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReclaimOutboxItemUnlock<'info>>,
+) -> Result<()> {
+    ctx.accounts.common.dequeue();
+    let accs = &ctx.accounts.common;
+
+    let amount = accs
+        .outbox_item
+        .amount
+        .untrim(accs.mint.decimals)
+        .map_err(NTTError::from)?;
+
+    onchain::invoke_transfer_checked(
+        &accs.token_program.key(),
+        accs.custody.to_account_info(),
+        accs.mint.to_account_info(),
+        accs.sender_token_account.to_account_info(),
+        accs.token_authority.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        accs.mint.decimals,
+        &[&[
+            crate::TOKEN_AUTHORITY_SEED,
+            accs.config.mint_seed(),
+            &[ctx.bumps.common.token_authority],
+        ]],
+    )?;
+
+    // the tokens never reached the peer chain, so they leave custody like a
+    // released inbound transfer would
+    CustodyLedger::record_if_initialized(&ctx.accounts.custody_ledger, |ledger| {
+        ledger.record_inbound(amount)
+    })?;
+    Ok(())
+}
@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface;
-use ntt_messages::{ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage};
+use ntt_messages::{chain_id::ChainId, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage};
 
 use crate::{
     bitmap::Bitmap,
+    clock::current_timestamp,
     config::*,
     error::NTTError,
     messages::ValidatedTransceiverMessage,
@@ -29,7 +30,7 @@ pub struct Redeem<'info> {
     pub config: Account<'info, Config>,
 
     #[account(
-        seeds = [NttManagerPeer::SEED_PREFIX, ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::from_chain(&transceiver_message)?.id.to_be_bytes().as_ref()],
+        seeds = [NttManagerPeer::SEED_PREFIX, ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::from_chain(&transceiver_message)?.id.to_be_bytes().as_ref(), config.mint_seed()],
         constraint = peer.address == ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.source_ntt_manager() @ NTTError::InvalidNttManagerPeer,
         bump = peer.bump,
     )]
@@ -49,6 +50,8 @@ pub struct Redeem<'info> {
     pub transceiver_message: UncheckedAccount<'info>,
 
     #[account(
+        seeds = [RegisteredTransceiver::SEED_PREFIX, transceiver.transceiver_address.as_ref(), config.mint_seed()],
+        bump = transceiver.bump,
         constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
     )]
     pub transceiver: Account<'info, RegisteredTransceiver>,
@@ -61,12 +64,15 @@ pub struct Redeem<'info> {
     #[account(
         init_if_needed,
         payer = payer,
-        space = 8 + InboxItem::INIT_SPACE,
+        space = InboxItem::space(
+            ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.ntt_manager_payload().payload.additional_payload.bytes().len()
+        ),
         seeds = [
             InboxItem::SEED_PREFIX,
             ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::message(&transceiver_message.try_borrow_data()?[..])?.ntt_manager_payload().keccak256(
                 ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::from_chain(&transceiver_message)?
             ).as_ref(),
+            config.mint_seed(),
         ],
         bump,
     )]
@@ -89,12 +95,17 @@ pub struct Redeem<'info> {
         seeds = [
             InboxRateLimit::SEED_PREFIX,
             ValidatedTransceiverMessage::<NativeTokenTransfer<Payload>>::from_chain(&transceiver_message)?.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
         ],
         bump,
     )]
     pub inbox_rate_limit: Account<'info, InboxRateLimit>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
     pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
 
     pub system_program: Program<'info, System>,
@@ -111,8 +122,42 @@ pub fn redeem(ctx: Context<Redeem>, _args: RedeemArgs) -> Result<()> {
             &accs.transceiver_message,
             &accs.transceiver.transceiver_address,
         )?;
-    let message: NttManagerMessage<NativeTokenTransfer<Payload>> =
-        transceiver_message.message.ntt_manager_payload.clone();
+
+    record_vote(
+        &accs.config,
+        &accs.peer,
+        &accs.mint,
+        &accs.transceiver,
+        &mut accs.inbox_item,
+        ctx.bumps.inbox_item,
+        &mut accs.inbox_rate_limit,
+        &mut accs.outbox_rate_limit,
+        transceiver_message.from_chain,
+        transceiver_message.message.ntt_manager_payload,
+    )
+}
+
+/// Records the vote of `transceiver` for `message` in `inbox_item` (which is
+/// initialised on the first vote), and once the threshold is reached,
+/// schedules the transfer for release.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn record_vote(
+    config: &Config,
+    peer: &NttManagerPeer,
+    mint: &InterfaceAccount<token_interface::Mint>,
+    transceiver: &RegisteredTransceiver,
+    inbox_item: &mut Account<InboxItem>,
+    inbox_item_bump: u8,
+    inbox_rate_limit: &mut InboxRateLimit,
+    outbox_rate_limit: &mut OutboxRateLimit,
+    from_chain: ChainId,
+    message: NttManagerMessage<NativeTokenTransfer<Payload>>,
+) -> Result<()> {
+    // check that the peer is bridging our token, and not some other token
+    // that it has been misconfigured with
+    if message.payload.source_token != peer.token_address {
+        return Err(NTTError::InvalidSourceToken.into());
+    }
 
     // Calculate the scaled amount based on the appropriate decimal encoding for the token.
     // Return an error if the resulting amount overflows.
@@ -121,46 +166,60 @@ pub fn redeem(ctx: Context<Redeem>, _args: RedeemArgs) -> Result<()> {
     let amount = message
         .payload
         .amount
-        .untrim(accs.mint.decimals)
+        .untrim(mint.decimals)
         .map_err(NTTError::from)?;
 
-    if !accs.inbox_item.init {
+    if !inbox_item.init {
         let recipient_address =
             Pubkey::try_from(message.payload.to).map_err(|_| NTTError::InvalidRecipientAddress)?;
 
-        accs.inbox_item.set_inner(InboxItem {
+        inbox_item.set_inner(InboxItem {
             init: true,
-            bump: ctx.bumps.inbox_item,
+            bump: inbox_item_bump,
             amount,
             recipient_address,
             release_status: ReleaseStatus::NotApproved,
             votes: Bitmap::new(),
+            from_chain,
+            deferred_capacity: 0,
+            frozen: false,
+            sender: message.sender,
+            mint: mint.key(),
+            additional_payload: message.payload.additional_payload,
         });
     }
 
     // idempotent
-    accs.inbox_item.votes.set(accs.transceiver.id, true)?;
+    inbox_item.votes.set(transceiver.id, true)?;
 
-    if accs
-        .inbox_item
+    if inbox_item
         .votes
-        .count_enabled_votes(accs.config.enabled_transceivers)
-        < accs.config.threshold
+        .count_enabled_votes(config.enabled_transceivers)
+        < config.threshold
     {
         return Ok(());
     }
 
-    let release_timestamp = match accs.inbox_rate_limit.rate_limit.consume_or_delay(amount) {
+    let release_timestamp = match inbox_rate_limit.rate_limit.consume_or_delay(amount) {
         RateLimitResult::Consumed(now) => {
             // When receiving a transfer, we refill the outbound rate limit with
             // the same amount (we call this "backflow")
-            accs.outbox_rate_limit.rate_limit.refill(now, amount);
+            outbox_rate_limit.rate_limit.refill(now, amount);
             now
         }
-        RateLimitResult::Delayed(release_timestamp) => release_timestamp,
+        RateLimitResult::Delayed(release_timestamp) => {
+            inbox_item.deferred_capacity = amount;
+            release_timestamp
+        }
     };
 
-    accs.inbox_item.release_after(release_timestamp)?;
+    // Even if there was enough capacity, the transfer has to wait out the
+    // settlement delay of the peer, which gives us a window to pause (or
+    // freeze the inbox item) if the message turns out to be bad.
+    let settlement_timestamp = current_timestamp().saturating_add(peer.settlement_delay(amount));
+    let release_timestamp = release_timestamp.max(settlement_timestamp);
+
+    inbox_item.release_after(release_timestamp)?;
 
     Ok(())
 }
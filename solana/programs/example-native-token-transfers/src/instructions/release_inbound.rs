@@ -5,8 +5,14 @@ use spl_token_2022::onchain;
 
 use crate::{
     config::*,
+    custody_ledger::CustodyLedger,
     error::NTTError,
-    queue::inbox::{InboxItem, ReleaseStatus},
+    queue::{
+        inbox::{InboxItem, InboxRateLimit, ReleaseStatus},
+        outbox::OutboxRateLimit,
+    },
+    registered_receiver::{ReceiveNttTransferArgs, RegisteredReceiver, RECEIVER_CALLER_SEED},
+    transfer_hook::check_transfer_hook_accounts,
 };
 
 #[derive(Accounts)]
@@ -16,9 +22,32 @@ pub struct ReleaseInbound<'info> {
 
     pub config: NotPausedConfig<'info>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = inbox_item.mint == config.mint @ NTTError::InvalidMint,
+    )]
     pub inbox_item: Account<'info, InboxItem>,
 
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            inbox_item.from_chain.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
+    /// Refilled (backflow) when a delayed transfer consumes its deferred
+    /// capacity. See [`InboxItem::try_release`].
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
     #[account(
         mut,
         associated_token::authority = inbox_item.recipient_address,
@@ -28,7 +57,21 @@ pub struct ReleaseInbound<'info> {
     pub recipient: InterfaceAccount<'info, token_interface::TokenAccount>,
 
     #[account(
-        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        seeds = [
+            RegisteredReceiver::SEED_PREFIX,
+            inbox_item.recipient_address.as_ref(),
+            config.mint_seed(),
+        ],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct address.
+    /// This account only exists if the recipient is a registered receiver, in
+    /// which case the transfer has to be released with one of the `_and_call`
+    /// instructions. See [`crate::registered_receiver`].
+    pub registered_receiver: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED, config.mint_seed()],
         bump,
     )]
     /// CHECK The seeds constraint ensures that this is the correct address
@@ -51,6 +94,153 @@ pub struct ReleaseInbound<'info> {
     pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
 }
 
+impl<'info> ReleaseInbound<'info> {
+    /// Marks the inbox item as released. Returns false if it can't be
+    /// released yet (and `revert_on_delay` is not set).
+    fn try_release(&mut self, revert_on_delay: bool) -> Result<bool> {
+        let rate_limits = self.config.capacity_aware_release.then_some((
+            &mut self.inbox_rate_limit.rate_limit,
+            &mut self.outbox_rate_limit.rate_limit,
+        ));
+
+        let released = self.inbox_item.try_release(rate_limits)?;
+
+        if !released {
+            if revert_on_delay {
+                return Err(NTTError::CantReleaseYet.into());
+            } else {
+                return Ok(false);
+            }
+        }
+
+        assert!(self.inbox_item.release_status == ReleaseStatus::Released);
+        Ok(true)
+    }
+
+    fn check_not_registered_receiver(&self) -> Result<()> {
+        if !self.registered_receiver.data_is_empty() {
+            return Err(NTTError::ReleaseRequiresCall.into());
+        }
+        Ok(())
+    }
+
+    fn token_release(&self, token_authority_bump: u8) -> TokenRelease<'_, 'info> {
+        TokenRelease {
+            config: &self.config,
+            mint: &self.mint,
+            custody: &self.custody,
+            recipient: &self.recipient,
+            token_authority: &self.token_authority,
+            token_authority_bump,
+            token_program: &self.token_program,
+        }
+    }
+
+    fn mint_to_recipient(
+        &self,
+        token_authority_bump: u8,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        self.token_release(token_authority_bump)
+            .mint_to_recipient(self.inbox_item.amount, remaining_accounts)
+    }
+
+    fn unlock_to_recipient(
+        &self,
+        token_authority_bump: u8,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        self.token_release(token_authority_bump)
+            .unlock_to_recipient(self.inbox_item.amount, remaining_accounts)
+    }
+}
+
+/// The accounts needed to release tokens from custody (or mint them) to the
+/// recipient of an inbound transfer.
+pub(crate) struct TokenRelease<'a, 'info> {
+    pub config: &'a Config,
+    pub mint: &'a InterfaceAccount<'info, token_interface::Mint>,
+    pub custody: &'a InterfaceAccount<'info, token_interface::TokenAccount>,
+    pub recipient: &'a InterfaceAccount<'info, token_interface::TokenAccount>,
+    pub token_authority: &'a UncheckedAccount<'info>,
+    pub token_authority_bump: u8,
+    pub token_program: &'a Interface<'info, token_interface::TokenInterface>,
+}
+
+impl<'info> TokenRelease<'_, 'info> {
+    pub fn mint_to_recipient(
+        &self,
+        amount: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        // NOTE: minting tokens is a two-step process:
+        // 1. Mint tokens to the custody account
+        // 2. Transfer the tokens from the custody account to the recipient
+        //
+        // This is done to ensure that if the token has a transfer hook defined, it
+        // will be called after the tokens are minted.
+        // Unfortunately the Token2022 program doesn't trigger transfer hooks when
+        // minting tokens, so we have to do it "manually" via a transfer.
+        //
+        // If we didn't do this, transfer hooks could be bypassed by transferring
+        // the tokens out through NTT first, then back in to the intended recipient.
+        //
+        // The [`transfer_burn`] function operates in a similar way
+        // (transfer to custody from sender, *then* burn).
+
+        // Step 1: mint tokens to the custody account
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token_interface::MintTo {
+                    mint: self.mint.to_account_info(),
+                    to: self.custody.to_account_info(),
+                    authority: self.token_authority.to_account_info(),
+                },
+                &[&[
+                    crate::TOKEN_AUTHORITY_SEED,
+                    self.config.mint_seed(),
+                    &[self.token_authority_bump],
+                ]],
+            ),
+            amount,
+        )?;
+
+        // Step 2: transfer the tokens from the custody account to the recipient
+        self.unlock_to_recipient(amount, remaining_accounts)
+    }
+
+    pub fn unlock_to_recipient(
+        &self,
+        amount: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        check_transfer_hook_accounts(
+            &self.mint.to_account_info(),
+            &self.custody.to_account_info(),
+            &self.recipient.to_account_info(),
+            &self.token_authority.to_account_info(),
+            amount,
+            remaining_accounts,
+        )?;
+        onchain::invoke_transfer_checked(
+            &self.token_program.key(),
+            self.custody.to_account_info(),
+            self.mint.to_account_info(),
+            self.recipient.to_account_info(),
+            self.token_authority.to_account_info(),
+            remaining_accounts,
+            amount,
+            self.mint.decimals,
+            &[&[
+                crate::TOKEN_AUTHORITY_SEED,
+                self.config.mint_seed(),
+                &[self.token_authority_bump],
+            ]],
+        )
+    }
+}
+
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct ReleaseInboundArgs {
     pub revert_on_delay: bool,
@@ -77,68 +267,14 @@ pub fn release_inbound_mint<'info>(
     ctx: Context<'_, '_, '_, 'info, ReleaseInboundMint<'info>>,
     args: ReleaseInboundArgs,
 ) -> Result<()> {
-    let inbox_item = &mut ctx.accounts.common.inbox_item;
-
-    let released = inbox_item.try_release()?;
+    let common = &mut ctx.accounts.common;
+    common.check_not_registered_receiver()?;
 
-    if !released {
-        if args.revert_on_delay {
-            return Err(NTTError::CantReleaseYet.into());
-        } else {
-            return Ok(());
-        }
+    if !common.try_release(args.revert_on_delay)? {
+        return Ok(());
     }
 
-    assert!(inbox_item.release_status == ReleaseStatus::Released);
-
-    // NOTE: minting tokens is a two-step process:
-    // 1. Mint tokens to the custody account
-    // 2. Transfer the tokens from the custody account to the recipient
-    //
-    // This is done to ensure that if the token has a transfer hook defined, it
-    // will be called after the tokens are minted.
-    // Unfortunately the Token2022 program doesn't trigger transfer hooks when
-    // minting tokens, so we have to do it "manually" via a transfer.
-    //
-    // If we didn't do this, transfer hooks could be bypassed by transferring
-    // the tokens out through NTT first, then back in to the intended recipient.
-    //
-    // The [`transfer_burn`] function operates in a similar way
-    // (transfer to custody from sender, *then* burn).
-
-    // Step 1: mint tokens to the custody account
-    token_interface::mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.common.token_program.to_account_info(),
-            token_interface::MintTo {
-                mint: ctx.accounts.common.mint.to_account_info(),
-                to: ctx.accounts.common.custody.to_account_info(),
-                authority: ctx.accounts.common.token_authority.to_account_info(),
-            },
-            &[&[
-                crate::TOKEN_AUTHORITY_SEED,
-                &[ctx.bumps.common.token_authority],
-            ]],
-        ),
-        inbox_item.amount,
-    )?;
-
-    // Step 2: transfer the tokens from the custody account to the recipient
-    onchain::invoke_transfer_checked(
-        &ctx.accounts.common.token_program.key(),
-        ctx.accounts.common.custody.to_account_info(),
-        ctx.accounts.common.mint.to_account_info(),
-        ctx.accounts.common.recipient.to_account_info(),
-        ctx.accounts.common.token_authority.to_account_info(),
-        ctx.remaining_accounts,
-        inbox_item.amount,
-        ctx.accounts.common.mint.decimals,
-        &[&[
-            crate::TOKEN_AUTHORITY_SEED,
-            &[ctx.bumps.common.token_authority],
-        ]],
-    )?;
-    Ok(())
+    common.mint_to_recipient(ctx.bumps.common.token_authority, ctx.remaining_accounts)
 }
 
 // Lock/unlock
@@ -149,6 +285,15 @@ pub struct ReleaseInboundUnlock<'info> {
         constraint = common.config.mode == Mode::Locking @ NTTError::InvalidMode,
     )]
     common: ReleaseInbound<'info>,
+
+    #[account(
+        mut,
+        seeds = [CustodyLedger::SEED_PREFIX, common.config.mint_seed()],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures that this is the custody ledger,
+    /// which may not be initialized. See [`CustodyLedger::record_if_initialized`].
+    custody_ledger: UncheckedAccount<'info>,
 }
 
 /// Release an inbound transfer and unlock the tokens to the recipient.
@@ -162,31 +307,162 @@ pub fn release_inbound_unlock<'info>(
     ctx: Context<'_, '_, '_, 'info, ReleaseInboundUnlock<'info>>,
     args: ReleaseInboundArgs,
 ) -> Result<()> {
-    let inbox_item = &mut ctx.accounts.common.inbox_item;
+    let common = &mut ctx.accounts.common;
+    common.check_not_registered_receiver()?;
+
+    if !common.try_release(args.revert_on_delay)? {
+        return Ok(());
+    }
+
+    common.unlock_to_recipient(ctx.bumps.common.token_authority, ctx.remaining_accounts)?;
+    CustodyLedger::record_if_initialized(&ctx.accounts.custody_ledger, |ledger| {
+        ledger.record_inbound(common.inbox_item.amount)
+    })?;
+    Ok(())
+}
+
+// Transfer-and-call
+
+#[derive(Accounts)]
+pub struct ReleaseInboundAndCall<'info> {
+    common: ReleaseInbound<'info>,
 
-    let released = inbox_item.try_release()?;
+    #[account(executable)]
+    /// CHECK: checked against the registered receiver in [`Self::call_receiver`]
+    pub receiver_program: UncheckedAccount<'info>,
 
-    if !released {
-        if args.revert_on_delay {
-            return Err(NTTError::CantReleaseYet.into());
-        } else {
-            return Ok(());
+    #[account(
+        seeds = [RECEIVER_CALLER_SEED],
+        bump,
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub receiver_caller: UncheckedAccount<'info>,
+}
+
+impl<'info> ReleaseInboundAndCall<'info> {
+    /// Invokes the receiver's `receive_ntt_transfer` handler. See
+    /// [`crate::registered_receiver`] for the interface.
+    fn call_receiver(
+        &self,
+        receiver_caller_bump: u8,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let registered_receiver =
+            Account::<RegisteredReceiver>::try_from(&self.common.registered_receiver)
+                .map_err(|_| NTTError::ReceiverNotRegistered)?;
+        if registered_receiver.program != self.receiver_program.key() {
+            return Err(NTTError::InvalidReceiverProgram.into());
+        }
+
+        let inbox_item = &self.common.inbox_item;
+        let args = ReceiveNttTransferArgs {
+            from_chain: inbox_item.from_chain,
+            sender: inbox_item.sender,
+            amount: inbox_item.amount,
+            additional_payload: inbox_item.additional_payload.bytes().to_vec(),
+        };
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(self.receiver_caller.key(), true),
+            AccountMeta::new(self.common.recipient.key(), false),
+            AccountMeta::new_readonly(self.common.mint.key(), false),
+            AccountMeta::new_readonly(inbox_item.key(), false),
+        ];
+        let mut account_infos = vec![
+            self.receiver_caller.to_account_info(),
+            self.common.recipient.to_account_info(),
+            self.common.mint.to_account_info(),
+            inbox_item.to_account_info(),
+        ];
+        for account in remaining_accounts {
+            accounts.push(if account.is_writable {
+                AccountMeta::new(account.key(), account.is_signer)
+            } else {
+                AccountMeta::new_readonly(account.key(), account.is_signer)
+            });
+            account_infos.push(account.clone());
         }
+
+        solana_program::program::invoke_signed(
+            &solana_program::instruction::Instruction {
+                program_id: self.receiver_program.key(),
+                accounts,
+                data: args.data(),
+            },
+            &account_infos,
+            &[&[RECEIVER_CALLER_SEED, &[receiver_caller_bump]]],
+        )?;
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ReleaseInboundAndCallMint<'info> {
+    #[account(
+        constraint = call.common.config.mode == Mode::Burning @ NTTError::InvalidMode,
+    )]
+    call: ReleaseInboundAndCall<'info>,
+}
+
+/// Same as [`release_inbound_mint`], but for transfers to a registered
+/// receiver. After minting the tokens to the receiver's token account, the
+/// receiver is invoked. The remaining accounts are passed to both the token
+/// program (for transfer hooks) and the receiver.
+pub fn release_inbound_and_call_mint<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReleaseInboundAndCallMint<'info>>,
+    args: ReleaseInboundArgs,
+) -> Result<()> {
+    let call = &mut ctx.accounts.call;
+
+    if !call.common.try_release(args.revert_on_delay)? {
+        return Ok(());
     }
 
-    onchain::invoke_transfer_checked(
-        &ctx.accounts.common.token_program.key(),
-        ctx.accounts.common.custody.to_account_info(),
-        ctx.accounts.common.mint.to_account_info(),
-        ctx.accounts.common.recipient.to_account_info(),
-        ctx.accounts.common.token_authority.to_account_info(),
+    call.common.mint_to_recipient(
+        ctx.bumps.call.common.token_authority,
         ctx.remaining_accounts,
-        inbox_item.amount,
-        ctx.accounts.common.mint.decimals,
-        &[&[
-            crate::TOKEN_AUTHORITY_SEED,
-            &[ctx.bumps.common.token_authority],
-        ]],
     )?;
-    Ok(())
+    call.call_receiver(ctx.bumps.call.receiver_caller, ctx.remaining_accounts)
+}
+
+#[derive(Accounts)]
+pub struct ReleaseInboundAndCallUnlock<'info> {
+    #[account(
+        constraint = call.common.config.mode == Mode::Locking @ NTTError::InvalidMode,
+    )]
+    call: ReleaseInboundAndCall<'info>,
+
+    #[account(
+        mut,
+        seeds = [CustodyLedger::SEED_PREFIX, call.common.config.mint_seed()],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures that this is the custody ledger,
+    /// which may not be initialized. See [`CustodyLedger::record_if_initialized`].
+    custody_ledger: UncheckedAccount<'info>,
+}
+
+/// Same as [`release_inbound_unlock`], but for transfers to a registered
+/// receiver. After unlocking the tokens to the receiver's token account, the
+/// receiver is invoked. The remaining accounts are passed to both the token
+/// program (for transfer hooks) and the receiver.
+pub fn release_inbound_and_call_unlock<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReleaseInboundAndCallUnlock<'info>>,
+    args: ReleaseInboundArgs,
+) -> Result<()> {
+    let call = &mut ctx.accounts.call;
+
+    if !call.common.try_release(args.revert_on_delay)? {
+        return Ok(());
+    }
+
+    call.common.unlock_to_recipient(
+        ctx.bumps.call.common.token_authority,
+        ctx.remaining_accounts,
+    )?;
+    CustodyLedger::record_if_initialized(&ctx.accounts.custody_ledger, |ledger| {
+        ledger.record_inbound(call.common.inbox_item.amount)
+    })?;
+    call.call_receiver(ctx.bumps.call.receiver_caller, ctx.remaining_accounts)
 }
@@ -10,7 +10,8 @@
 //! accounts whose constraints refer to the instruction data).
 //!
 //! See the documentation of [`crate::SESSION_AUTHORITY_SEED`] for an
-//! explanation of the approval flow.
+//! explanation of the approval flow. The `_direct` variants skip the approval,
+//! and have the owner of the sender's token account sign instead.
 
 #![allow(clippy::too_many_arguments)]
 use anchor_lang::prelude::*;
@@ -21,6 +22,7 @@ use spl_token_2022::onchain;
 use crate::{
     bitmap::Bitmap,
     config::*,
+    custody_ledger::CustodyLedger,
     error::NTTError,
     peer::NttManagerPeer,
     queue::{
@@ -28,6 +30,7 @@ use crate::{
         outbox::{OutboxItem, OutboxRateLimit},
         rate_limit::RateLimitResult,
     },
+    transfer_hook::check_transfer_hook_accounts,
 };
 
 // this will burn the funds and create an account that either allows sending the
@@ -67,7 +70,11 @@ pub struct Transfer<'info> {
     )]
     pub outbox_item: Account<'info, OutboxItem>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
     pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
 
     #[account(
@@ -119,7 +126,11 @@ pub struct TransferBurn<'info> {
 
     #[account(
         mut,
-        seeds = [InboxRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.recipient_chain.id.to_be_bytes().as_ref(),
+            common.config.mint_seed(),
+        ],
         bump = inbox_rate_limit.bump,
     )]
     // NOTE: it would be nice to put these into `common`, but that way we don't
@@ -127,8 +138,13 @@ pub struct TransferBurn<'info> {
     pub inbox_rate_limit: Account<'info, InboxRateLimit>,
 
     #[account(
-        seeds = [NttManagerPeer::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        seeds = [
+            NttManagerPeer::SEED_PREFIX,
+            args.recipient_chain.id.to_be_bytes().as_ref(),
+            common.config.mint_seed(),
+        ],
         bump = peer.bump,
+        constraint = !peer.outbound_paused @ NTTError::PeerOutboundPaused,
     )]
     pub peer: Account<'info, NttManagerPeer>,
 
@@ -145,7 +161,7 @@ pub struct TransferBurn<'info> {
     pub session_authority: UncheckedAccount<'info>,
 
     #[account(
-        seeds = [crate::TOKEN_AUTHORITY_SEED],
+        seeds = [crate::TOKEN_AUTHORITY_SEED, common.config.mint_seed()],
         bump,
     )]
     /// CHECK: The seeds constraint enforces that this is the correct account.
@@ -157,7 +173,104 @@ pub fn transfer_burn<'info>(
     args: TransferArgs,
 ) -> Result<()> {
     let accs = ctx.accounts;
+    let from = accs.common.from.owner;
+    let args_hash = args.keccak256();
+
+    burn_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &accs.peer,
+        accs.token_authority.to_account_info(),
+        ctx.bumps.token_authority,
+        accs.session_authority.to_account_info(),
+        &[&[
+            crate::SESSION_AUTHORITY_SEED,
+            from.as_ref(),
+            args_hash.as_ref(),
+            &[ctx.bumps.session_authority],
+        ]],
+        ctx.remaining_accounts,
+        args,
+    )
+}
+
+/// Same as [`TransferBurn`], except that the owner of the `from` account
+/// signs directly, instead of approving a session authority. This makes it
+/// possible for programs to transfer out of token accounts owned by their
+/// PDAs in a single CPI.
+#[derive(Accounts)]
+#[instruction(args: TransferArgs)]
+pub struct TransferBurnDirect<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Burning @ NTTError::InvalidMode,
+    )]
+    pub common: Transfer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.recipient_chain.id.to_be_bytes().as_ref(),
+            common.config.mint_seed(),
+        ],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [
+            NttManagerPeer::SEED_PREFIX,
+            args.recipient_chain.id.to_be_bytes().as_ref(),
+            common.config.mint_seed(),
+        ],
+        bump = peer.bump,
+        constraint = !peer.outbound_paused @ NTTError::PeerOutboundPaused,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        constraint = common.from.owner == from_authority.key() @ NTTError::InvalidFromAuthority,
+    )]
+    pub from_authority: Signer<'info>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED, common.config.mint_seed()],
+        bump,
+    )]
+    /// CHECK: The seeds constraint enforces that this is the correct account.
+    pub token_authority: UncheckedAccount<'info>,
+}
+
+pub fn transfer_burn_direct<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferBurnDirect<'info>>,
+    args: TransferArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
 
+    burn_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &accs.peer,
+        accs.token_authority.to_account_info(),
+        ctx.bumps.token_authority,
+        accs.from_authority.to_account_info(),
+        &[],
+        ctx.remaining_accounts,
+        args,
+    )
+}
+
+fn burn_and_insert_into_outbox<'info>(
+    common: &mut Transfer<'info>,
+    inbox_rate_limit: &mut InboxRateLimit,
+    peer: &NttManagerPeer,
+    token_authority: AccountInfo<'info>,
+    token_authority_bump: u8,
+    from_authority: AccountInfo<'info>,
+    from_authority_seeds: &[&[&[u8]]],
+    remaining_accounts: &[AccountInfo<'info>],
+    args: TransferArgs,
+) -> Result<()> {
     let TransferArgs {
         mut amount,
         recipient_chain,
@@ -166,14 +279,11 @@ pub fn transfer_burn<'info>(
     } = args;
 
     // TODO: should we revert if we have dust?
-    let trimmed_amount = TrimmedAmount::remove_dust(
-        &mut amount,
-        accs.common.mint.decimals,
-        accs.peer.token_decimals,
-    )
-    .map_err(NTTError::from)?;
+    let trimmed_amount =
+        TrimmedAmount::remove_dust(&mut amount, common.mint.decimals, peer.token_decimals)
+            .map_err(NTTError::from)?;
 
-    let before = accs.common.custody.amount;
+    let before = common.custody.amount;
 
     // NOTE: burning tokens is a two-step process:
     // 1. Transfer the tokens to the custody account
@@ -191,39 +301,46 @@ pub fn transfer_burn<'info>(
     // (mint to custody, *then* transfer to recipient).
 
     // Step 1: transfer to custody account
+    check_transfer_hook_accounts(
+        &common.mint.to_account_info(),
+        &common.from.to_account_info(),
+        &common.custody.to_account_info(),
+        &from_authority,
+        amount,
+        remaining_accounts,
+    )?;
     onchain::invoke_transfer_checked(
-        &accs.common.token_program.key(),
-        accs.common.from.to_account_info(),
-        accs.common.mint.to_account_info(),
-        accs.common.custody.to_account_info(),
-        accs.session_authority.to_account_info(),
-        ctx.remaining_accounts,
+        &common.token_program.key(),
+        common.from.to_account_info(),
+        common.mint.to_account_info(),
+        common.custody.to_account_info(),
+        from_authority,
+        remaining_accounts,
         amount,
-        accs.common.mint.decimals,
-        &[&[
-            crate::SESSION_AUTHORITY_SEED,
-            accs.common.from.owner.as_ref(),
-            args.keccak256().as_ref(),
-            &[ctx.bumps.session_authority],
-        ]],
+        common.mint.decimals,
+        from_authority_seeds,
     )?;
 
     // Step 2: burn the tokens from the custody account
     token_interface::burn(
         CpiContext::new_with_signer(
-            accs.common.token_program.to_account_info(),
+            common.token_program.to_account_info(),
             token_interface::Burn {
-                mint: accs.common.mint.to_account_info(),
-                from: accs.common.custody.to_account_info(),
-                authority: accs.token_authority.to_account_info(),
+                mint: common.mint.to_account_info(),
+                from: common.custody.to_account_info(),
+                authority: token_authority,
             },
-            &[&[crate::TOKEN_AUTHORITY_SEED, &[ctx.bumps.token_authority]]],
+            &[&[
+                crate::TOKEN_AUTHORITY_SEED,
+                common.config.mint_seed(),
+                &[token_authority_bump],
+            ]],
         ),
         amount,
     )?;
 
-    accs.common.custody.reload()?;
-    let after = accs.common.custody.amount;
+    common.custody.reload()?;
+    let after = common.custody.amount;
 
     // NOTE: we currently do not support tokens with fees. Support could be
     // added, but it would require the client to calculate the amount _before_
@@ -239,20 +356,18 @@ pub fn transfer_burn<'info>(
         return Err(NTTError::BadAmountAfterBurn.into());
     }
 
-    let recipient_ntt_manager = accs.peer.address;
+    let recipient_ntt_manager = peer.address;
 
     insert_into_outbox(
-        &mut accs.common,
-        &mut accs.inbox_rate_limit,
+        common,
+        inbox_rate_limit,
         amount,
         trimmed_amount,
         recipient_chain,
         recipient_ntt_manager,
         recipient_address,
         should_queue,
-    )?;
-
-    Ok(())
+    )
 }
 
 // Lock/unlock
@@ -267,7 +382,11 @@ pub struct TransferLock<'info> {
 
     #[account(
         mut,
-        seeds = [InboxRateLimit::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.recipient_chain.id.to_be_bytes().as_ref(),
+            common.config.mint_seed(),
+        ],
         bump = inbox_rate_limit.bump,
     )]
     // NOTE: it would be nice to put these into `common`, but that way we don't
@@ -275,8 +394,13 @@ pub struct TransferLock<'info> {
     pub inbox_rate_limit: Account<'info, InboxRateLimit>,
 
     #[account(
-        seeds = [NttManagerPeer::SEED_PREFIX, args.recipient_chain.id.to_be_bytes().as_ref()],
+        seeds = [
+            NttManagerPeer::SEED_PREFIX,
+            args.recipient_chain.id.to_be_bytes().as_ref(),
+            common.config.mint_seed(),
+        ],
         bump = peer.bump,
+        constraint = !peer.outbound_paused @ NTTError::PeerOutboundPaused,
     )]
     pub peer: Account<'info, NttManagerPeer>,
 
@@ -291,6 +415,15 @@ pub struct TransferLock<'info> {
     /// CHECK: The seeds constraint enforces that this is the correct account
     /// See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow.
     pub session_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [CustodyLedger::SEED_PREFIX, common.config.mint_seed()],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures that this is the custody ledger,
+    /// which may not be initialized. See [`CustodyLedger::record_if_initialized`].
+    pub custody_ledger: UncheckedAccount<'info>,
 }
 
 pub fn transfer_lock<'info>(
@@ -298,7 +431,103 @@ pub fn transfer_lock<'info>(
     args: TransferArgs,
 ) -> Result<()> {
     let accs = ctx.accounts;
+    let from = accs.common.from.owner;
+    let args_hash = args.keccak256();
+
+    lock_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &accs.peer,
+        &accs.custody_ledger,
+        accs.session_authority.to_account_info(),
+        &[&[
+            crate::SESSION_AUTHORITY_SEED,
+            from.as_ref(),
+            args_hash.as_ref(),
+            &[ctx.bumps.session_authority],
+        ]],
+        ctx.remaining_accounts,
+        args,
+    )
+}
+
+/// Same as [`TransferLock`], except that the owner of the `from` account
+/// signs directly, instead of approving a session authority. This makes it
+/// possible for programs to transfer out of token accounts owned by their
+/// PDAs in a single CPI.
+#[derive(Accounts)]
+#[instruction(args: TransferArgs)]
+pub struct TransferLockDirect<'info> {
+    #[account(
+        constraint = common.config.mode == Mode::Locking @ NTTError::InvalidMode,
+    )]
+    pub common: Transfer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.recipient_chain.id.to_be_bytes().as_ref(),
+            common.config.mint_seed(),
+        ],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [
+            NttManagerPeer::SEED_PREFIX,
+            args.recipient_chain.id.to_be_bytes().as_ref(),
+            common.config.mint_seed(),
+        ],
+        bump = peer.bump,
+        constraint = !peer.outbound_paused @ NTTError::PeerOutboundPaused,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        constraint = common.from.owner == from_authority.key() @ NTTError::InvalidFromAuthority,
+    )]
+    pub from_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CustodyLedger::SEED_PREFIX, common.config.mint_seed()],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures that this is the custody ledger,
+    /// which may not be initialized. See [`CustodyLedger::record_if_initialized`].
+    pub custody_ledger: UncheckedAccount<'info>,
+}
+
+pub fn transfer_lock_direct<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferLockDirect<'info>>,
+    args: TransferArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
 
+    lock_and_insert_into_outbox(
+        &mut accs.common,
+        &mut accs.inbox_rate_limit,
+        &accs.peer,
+        &accs.custody_ledger,
+        accs.from_authority.to_account_info(),
+        &[],
+        ctx.remaining_accounts,
+        args,
+    )
+}
+
+fn lock_and_insert_into_outbox<'info>(
+    common: &mut Transfer<'info>,
+    inbox_rate_limit: &mut InboxRateLimit,
+    peer: &NttManagerPeer,
+    custody_ledger: &AccountInfo<'info>,
+    from_authority: AccountInfo<'info>,
+    from_authority_seeds: &[&[&[u8]]],
+    remaining_accounts: &[AccountInfo<'info>],
+    args: TransferArgs,
+) -> Result<()> {
     let TransferArgs {
         mut amount,
         recipient_chain,
@@ -307,34 +536,34 @@ pub fn transfer_lock<'info>(
     } = args;
 
     // TODO: should we revert if we have dust?
-    let trimmed_amount = TrimmedAmount::remove_dust(
-        &mut amount,
-        accs.common.mint.decimals,
-        accs.peer.token_decimals,
-    )
-    .map_err(NTTError::from)?;
+    let trimmed_amount =
+        TrimmedAmount::remove_dust(&mut amount, common.mint.decimals, peer.token_decimals)
+            .map_err(NTTError::from)?;
 
-    let before = accs.common.custody.amount;
+    let before = common.custody.amount;
 
+    check_transfer_hook_accounts(
+        &common.mint.to_account_info(),
+        &common.from.to_account_info(),
+        &common.custody.to_account_info(),
+        &from_authority,
+        amount,
+        remaining_accounts,
+    )?;
     onchain::invoke_transfer_checked(
-        &accs.common.token_program.key(),
-        accs.common.from.to_account_info(),
-        accs.common.mint.to_account_info(),
-        accs.common.custody.to_account_info(),
-        accs.session_authority.to_account_info(),
-        ctx.remaining_accounts,
+        &common.token_program.key(),
+        common.from.to_account_info(),
+        common.mint.to_account_info(),
+        common.custody.to_account_info(),
+        from_authority,
+        remaining_accounts,
         amount,
-        accs.common.mint.decimals,
-        &[&[
-            crate::SESSION_AUTHORITY_SEED,
-            accs.common.from.owner.as_ref(),
-            args.keccak256().as_ref(),
-            &[ctx.bumps.session_authority],
-        ]],
+        common.mint.decimals,
+        from_authority_seeds,
     )?;
 
-    accs.common.custody.reload()?;
-    let after = accs.common.custody.amount;
+    common.custody.reload()?;
+    let after = common.custody.amount;
 
     // NOTE: we currently do not support tokens with fees. Support could be
     // added, but it would require the client to calculate the amount _before_
@@ -347,12 +576,13 @@ pub fn transfer_lock<'info>(
     if after != before + amount {
         return Err(NTTError::BadAmountAfterTransfer.into());
     }
+    CustodyLedger::record_if_initialized(custody_ledger, |ledger| ledger.record_outbound(amount))?;
 
-    let recipient_ntt_manager = accs.peer.address;
+    let recipient_ntt_manager = peer.address;
 
     insert_into_outbox(
-        &mut accs.common,
-        &mut accs.inbox_rate_limit,
+        common,
+        inbox_rate_limit,
         amount,
         trimmed_amount,
         recipient_chain,
@@ -373,20 +603,24 @@ fn insert_into_outbox(
     should_queue: bool,
 ) -> Result<()> {
     // consume the rate limit, or delay the transfer if it's outside the limit
-    let release_timestamp = match common.outbox_rate_limit.rate_limit.consume_or_delay(amount) {
-        RateLimitResult::Consumed(now) => {
-            // When sending a transfer, we refill the inbound rate limit for
-            // that chain the same amount (we call this "backflow")
-            inbox_rate_limit.rate_limit.refill(now, amount);
-            now
-        }
-        RateLimitResult::Delayed(release_timestamp) => {
-            if !should_queue {
-                return Err(NTTError::TransferExceedsRateLimit.into());
+    let (release_timestamp, deferred_capacity) =
+        match common.outbox_rate_limit.rate_limit.consume_or_delay(amount) {
+            RateLimitResult::Consumed(now) => {
+                // When sending a transfer, we refill the inbound rate limit for
+                // that chain the same amount (we call this "backflow")
+                inbox_rate_limit.rate_limit.refill(now, amount);
+                (now, 0)
+            }
+            RateLimitResult::Delayed(release_timestamp) => {
+                if !should_queue {
+                    return Err(NTTError::TransferExceedsRateLimit.into());
+                }
+                // NOTE: untrimmed, like the rate limit, so that it's in the
+                // same units for every recipient chain.
+                common.outbox_rate_limit.enqueue(amount)?;
+                (release_timestamp, amount)
             }
-            release_timestamp
-        }
-    };
+        };
 
     common.outbox_item.set_inner(OutboxItem {
         amount: trimmed_amount,
@@ -396,6 +630,8 @@ fn insert_into_outbox(
         recipient_address,
         release_timestamp,
         released: Bitmap::new(),
+        deferred_capacity,
+        mint: common.mint.key(),
     });
 
     Ok(())
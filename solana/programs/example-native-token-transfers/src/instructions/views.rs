@@ -0,0 +1,229 @@
+//! Read-only instructions that expose the current state of the rate limiters
+//! and the inbound/outbound queues through Anchor return data.
+//!
+//! These mirror the view functions of the EVM `NttManager` (e.g.
+//! `getCurrentOutboundCapacity`, `isMessageApproved`). None of them modify
+//! any account, so they are meant to be simulated rather than submitted. The
+//! values are computed against the cluster clock, so clients don't have to
+//! reimplement [`crate::queue::rate_limit::RateLimitState::capacity_at`].
+//! Likewise, [`get_transfer_hook_accounts`] saves clients from resolving the
+//! mint's transfer hook accounts themselves.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface;
+use ntt_messages::chain_id::ChainId;
+
+use crate::{
+    bitmap::Bitmap,
+    clock::current_timestamp,
+    config::Config,
+    error::NTTError,
+    queue::{
+        inbox::{InboxItem, InboxRateLimit, ReleaseStatus},
+        outbox::{OutboxItem, OutboxRateLimit},
+    },
+    transfer_hook::{resolve_transfer_hook_accounts, TransferHookAccount},
+};
+
+// * Rate limit capacity
+
+#[derive(Accounts)]
+pub struct GetOutboundCapacity<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
+    pub rate_limit: Account<'info, OutboxRateLimit>,
+}
+
+pub fn get_outbound_capacity(ctx: Context<GetOutboundCapacity>) -> Result<u64> {
+    Ok(ctx.accounts.rate_limit.capacity())
+}
+
+#[derive(Accounts)]
+#[instruction(args: GetInboundCapacityArgs)]
+pub struct GetInboundCapacity<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = rate_limit.bump,
+    )]
+    pub rate_limit: Account<'info, InboxRateLimit>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct GetInboundCapacityArgs {
+    pub chain_id: ChainId,
+}
+
+pub fn get_inbound_capacity(
+    ctx: Context<GetInboundCapacity>,
+    _args: GetInboundCapacityArgs,
+) -> Result<u64> {
+    Ok(ctx.accounts.rate_limit.capacity())
+}
+
+// * Outbox items
+
+#[derive(Accounts)]
+pub struct GetOutboxItemStatus<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        constraint = outbox_item.mint == config.mint @ NTTError::InvalidMint,
+    )]
+    pub outbox_item: Account<'info, OutboxItem>,
+
+    #[account(
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct OutboxItemStatus {
+    /// The timestamp after which the transfer may be released.
+    pub release_timestamp: i64,
+    /// Whether the transfer can be released by the transceivers right now:
+    /// the program is not paused, `release_timestamp` has been reached and,
+    /// under [`Config::capacity_aware_release`], there is enough outbound
+    /// capacity for `deferred_capacity`.
+    pub releasable: bool,
+    /// Bitmap of the transceivers that have already released the transfer.
+    pub released: Bitmap,
+    /// The outbound capacity that releasing the transfer consumes under
+    /// [`Config::capacity_aware_release`]. See [`OutboxItem::deferred_capacity`].
+    pub deferred_capacity: u64,
+}
+
+pub fn get_outbox_item_status(ctx: Context<GetOutboxItemStatus>) -> Result<OutboxItemStatus> {
+    let config = &ctx.accounts.config;
+    let outbox_item = &ctx.accounts.outbox_item;
+
+    let has_capacity = outbox_item.deferred_capacity == 0
+        || !config.capacity_aware_release
+        || ctx.accounts.outbox_rate_limit.capacity() >= outbox_item.deferred_capacity;
+    let releasable =
+        !config.paused && outbox_item.release_timestamp <= current_timestamp() && has_capacity;
+
+    Ok(OutboxItemStatus {
+        release_timestamp: outbox_item.release_timestamp,
+        releasable,
+        released: outbox_item.released,
+        deferred_capacity: outbox_item.deferred_capacity,
+    })
+}
+
+// * Inbox items
+
+#[derive(Accounts)]
+pub struct GetInboxItemStatus<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        constraint = inbox_item.mint == config.mint @ NTTError::InvalidMint,
+    )]
+    pub inbox_item: Account<'info, InboxItem>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct InboxItemStatus {
+    pub release_status: ReleaseStatus,
+    /// Bitmap of the transceivers that have attested to the transfer.
+    pub votes: Bitmap,
+    /// Whether enough enabled transceivers have attested to the transfer to
+    /// meet the threshold (`isMessageApproved` on EVM).
+    pub approved: bool,
+    /// Whether the transfer has been released to the recipient
+    /// (`isMessageExecuted` on EVM).
+    pub executed: bool,
+    /// Whether the release timestamp has been reached, i.e. the transfer can
+    /// be released right now.
+    pub releasable: bool,
+    /// Whether the transfer has been frozen by the owner or operator.
+    pub frozen: bool,
+}
+
+pub fn get_inbox_item_status(ctx: Context<GetInboxItemStatus>) -> Result<InboxItemStatus> {
+    let config = &ctx.accounts.config;
+    let inbox_item = &ctx.accounts.inbox_item;
+
+    let approved = inbox_item
+        .votes
+        .count_enabled_votes(config.enabled_transceivers)
+        >= config.threshold;
+    let releasable = match inbox_item.release_status {
+        ReleaseStatus::ReleaseAfter(release_timestamp) => {
+            !inbox_item.frozen && release_timestamp <= current_timestamp()
+        }
+        ReleaseStatus::NotApproved | ReleaseStatus::Released => false,
+    };
+
+    Ok(InboxItemStatus {
+        release_status: inbox_item.release_status.clone(),
+        votes: inbox_item.votes,
+        approved,
+        executed: inbox_item.release_status == ReleaseStatus::Released,
+        releasable,
+        frozen: inbox_item.frozen,
+    })
+}
+
+// * Transfer hook accounts
+
+/// The accounts of a token transfer made by NTT. For outbound transfers, this
+/// is the sender's token account, the custody account and the session
+/// authority (or the token account owner for the `_direct` variants). For
+/// releases, the custody account, the recipient's token account and the token
+/// authority.
+///
+/// The transfer hook's validation account has to be passed as a remaining
+/// account (along with any extra accounts whose data other extra accounts are
+/// derived from). See [`crate::transfer_hook`].
+#[derive(Accounts)]
+pub struct GetTransferHookAccounts<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        address = config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    /// CHECK: only used to resolve the extra accounts
+    pub source: UncheckedAccount<'info>,
+
+    /// CHECK: only used to resolve the extra accounts
+    pub destination: UncheckedAccount<'info>,
+
+    /// CHECK: only used to resolve the extra accounts
+    pub authority: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct GetTransferHookAccountsArgs {
+    pub amount: u64,
+}
+
+/// Returns the remaining accounts that have to be passed to the transfer or
+/// release instruction. Empty if the mint has no transfer hook.
+pub fn get_transfer_hook_accounts<'info>(
+    ctx: Context<'_, '_, '_, 'info, GetTransferHookAccounts<'info>>,
+    args: GetTransferHookAccountsArgs,
+) -> Result<Vec<TransferHookAccount>> {
+    resolve_transfer_hook_accounts(
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.source,
+        &ctx.accounts.destination,
+        &ctx.accounts.authority,
+        args.amount,
+        ctx.remaining_accounts,
+    )
+}
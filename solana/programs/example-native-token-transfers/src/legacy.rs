@@ -0,0 +1,155 @@
+//! Account layouts of earlier versions of the program.
+//!
+//! Accounts created by an earlier version can't be deserialized with their
+//! current layout, so they are read with these instead, and rewritten in the
+//! current layout by the migration instructions (see
+//! [`crate::instructions::migrate`]). A legacy account is detected by its
+//! length, as it was allocated with the space of its layout at the time.
+//!
+//! NOTE: earlier versions only supported single-token deployments, so legacy
+//! accounts are never keyed by a mint.
+
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use ntt_messages::{chain_id::ChainId, mode::Mode, trimmed_amount::TrimmedAmount};
+
+use crate::{
+    bitmap::Bitmap,
+    error::NTTError,
+    queue::{inbox::ReleaseStatus, rate_limit::RateLimitState},
+};
+
+/// Reads an account of type `T` that is stored in the legacy layout `L`.
+/// Fails with [`NTTError::AccountAlreadyMigrated`] if the account isn't in the
+/// legacy layout.
+pub fn read<T: Discriminator, L: AnchorDeserialize + Space>(data: &[u8]) -> Result<L> {
+    if data.len() < 8 {
+        return Err(ErrorCode::AccountDiscriminatorNotFound.into());
+    }
+    if data[..8] != T::DISCRIMINATOR {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+    if data.len() != 8 + L::INIT_SPACE {
+        return Err(NTTError::AccountAlreadyMigrated.into());
+    }
+    L::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+/// Rewrites `account` with `data` in the current layout, reallocating it to
+/// `space` bytes. The payer tops up the rent.
+pub fn rewrite<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    data: &T,
+    space: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let top_up = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    account.realloc(space, false)?;
+    data.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
+}
+
+/// The layout of [`crate::config::Config`] before the admin action timelock.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyConfig {
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub pending_owner: Option<Pubkey>,
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub mode: Mode,
+    pub chain_id: ChainId,
+    pub next_transceiver_id: u8,
+    pub threshold: u8,
+    pub enabled_transceivers: Bitmap,
+    pub paused: bool,
+    pub custody: Pubkey,
+}
+
+/// The layout of [`RateLimitState`] before limit ramps.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyRateLimitState {
+    pub limit: u64,
+    pub capacity_at_last_tx: u64,
+    pub last_tx_timestamp: i64,
+}
+
+impl From<LegacyRateLimitState> for RateLimitState {
+    fn from(legacy: LegacyRateLimitState) -> Self {
+        Self {
+            limit: legacy.limit,
+            capacity_at_last_tx: legacy.capacity_at_last_tx,
+            last_tx_timestamp: legacy.last_tx_timestamp,
+            ramp: None,
+        }
+    }
+}
+
+/// The layout of [`crate::queue::outbox::OutboxRateLimit`] before the queued
+/// amount was tracked.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyOutboxRateLimit {
+    pub rate_limit: LegacyRateLimitState,
+}
+
+/// The layout of [`crate::queue::inbox::InboxRateLimit`] before limit ramps.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyInboxRateLimit {
+    pub bump: u8,
+    pub rate_limit: LegacyRateLimitState,
+}
+
+/// The layout of [`crate::peer::NttManagerPeer`] before settlement delays.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyNttManagerPeer {
+    pub bump: u8,
+    pub address: [u8; 32],
+    pub token_decimals: u8,
+}
+
+/// The layout of [`crate::transceivers::accounts::peer::TransceiverPeer`]
+/// before per-peer finality.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyTransceiverPeer {
+    pub bump: u8,
+    pub address: [u8; 32],
+}
+
+/// The layout of [`crate::queue::inbox::InboxItem`] before the source chain
+/// of the transfer was recorded.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyInboxItem {
+    pub init: bool,
+    pub bump: u8,
+    pub amount: u64,
+    pub recipient_address: Pubkey,
+    pub votes: Bitmap,
+    pub release_status: ReleaseStatus,
+}
+
+/// The layout of [`crate::queue::outbox::OutboxItem`] before deferred
+/// capacity.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyOutboxItem {
+    pub amount: TrimmedAmount,
+    pub sender: Pubkey,
+    pub recipient_chain: ChainId,
+    pub recipient_ntt_manager: [u8; 32],
+    pub recipient_address: [u8; 32],
+    pub release_timestamp: i64,
+    pub released: Bitmap,
+}
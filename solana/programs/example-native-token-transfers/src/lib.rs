@@ -14,18 +14,25 @@ compile_error!("Cannot enable both solana-devnet and tilt-devnet features at the
 pub mod bitmap;
 pub mod clock;
 pub mod config;
+pub mod custody_ledger;
 pub mod error;
 pub mod instructions;
+pub mod legacy;
 pub mod messages;
+pub mod mint_extensions;
 pub mod peer;
 pub mod queue;
+pub mod registered_receiver;
 pub mod registered_transceiver;
+pub mod timelock;
 pub mod transceivers;
 pub mod transfer;
+pub mod transfer_hook;
 
 use transceivers::wormhole::instructions::*;
 
 use instructions::*;
+use transfer_hook::TransferHookAccount;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "tilt-devnet2")] {
@@ -63,7 +70,7 @@ pub const TOKEN_AUTHORITY_SEED: &[u8] = b"token_authority";
 /// user, atomically).
 pub const SESSION_AUTHORITY_SEED: &[u8] = b"session_authority";
 
-pub const VERSION: &str = "3.0.0";
+pub const VERSION: &str = "4.0.0";
 
 #[program]
 pub mod example_native_token_transfers {
@@ -78,6 +85,26 @@ pub mod example_native_token_transfers {
         instructions::initialize_lut(ctx, recent_slot)
     }
 
+    pub fn extend_lut_with_peer(ctx: Context<ExtendLUTWithPeer>, chain_id: u16) -> Result<()> {
+        instructions::extend_lut_with_peer(ctx, chain_id)
+    }
+
+    pub fn extend_lut_with_transceiver(ctx: Context<ExtendLUTWithTransceiver>) -> Result<()> {
+        instructions::extend_lut_with_transceiver(ctx)
+    }
+
+    pub fn extend_lut_with_transfer_hook(ctx: Context<ExtendLUTWithTransferHook>) -> Result<()> {
+        instructions::extend_lut_with_transfer_hook(ctx)
+    }
+
+    pub fn deactivate_lut(ctx: Context<RetireLUT>) -> Result<()> {
+        instructions::deactivate_lut(ctx)
+    }
+
+    pub fn close_lut(ctx: Context<RetireLUT>) -> Result<()> {
+        instructions::close_lut(ctx)
+    }
+
     pub fn version(_ctx: Context<Version>) -> Result<String> {
         Ok(VERSION.to_string())
     }
@@ -96,6 +123,20 @@ pub mod example_native_token_transfers {
         instructions::transfer_lock(ctx, args)
     }
 
+    pub fn transfer_burn_direct<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferBurnDirect<'info>>,
+        args: TransferArgs,
+    ) -> Result<()> {
+        instructions::transfer_burn_direct(ctx, args)
+    }
+
+    pub fn transfer_lock_direct<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferLockDirect<'info>>,
+        args: TransferArgs,
+    ) -> Result<()> {
+        instructions::transfer_lock_direct(ctx, args)
+    }
+
     pub fn redeem(ctx: Context<Redeem>, args: RedeemArgs) -> Result<()> {
         instructions::redeem(ctx, args)
     }
@@ -114,6 +155,20 @@ pub mod example_native_token_transfers {
         instructions::release_inbound_unlock(ctx, args)
     }
 
+    pub fn release_inbound_and_call_mint<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReleaseInboundAndCallMint<'info>>,
+        args: ReleaseInboundArgs,
+    ) -> Result<()> {
+        instructions::release_inbound_and_call_mint(ctx, args)
+    }
+
+    pub fn release_inbound_and_call_unlock<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReleaseInboundAndCallUnlock<'info>>,
+        args: ReleaseInboundArgs,
+    ) -> Result<()> {
+        instructions::release_inbound_and_call_unlock(ctx, args)
+    }
+
     pub fn transfer_ownership(ctx: Context<TransferOwnership>) -> Result<()> {
         instructions::transfer_ownership(ctx)
     }
@@ -126,18 +181,96 @@ pub mod example_native_token_transfers {
         instructions::claim_ownership(ctx)
     }
 
+    pub fn cancel_ownership_transfer(ctx: Context<CancelOwnershipTransfer>) -> Result<()> {
+        instructions::cancel_ownership_transfer(ctx)
+    }
+
+    pub fn renounce_ownership(
+        ctx: Context<RenounceOwnership>,
+        args: RenounceOwnershipArgs,
+    ) -> Result<()> {
+        instructions::renounce_ownership(ctx, args)
+    }
+
     pub fn set_paused(ctx: Context<SetPaused>, pause: bool) -> Result<()> {
         instructions::set_paused(ctx, pause)
     }
 
+    pub fn upgrade(ctx: Context<Upgrade>) -> Result<()> {
+        instructions::upgrade(ctx)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate_config(ctx)
+    }
+
+    pub fn migrate_outbox_rate_limit(ctx: Context<MigrateOutboxRateLimit>) -> Result<()> {
+        instructions::migrate_outbox_rate_limit(ctx)
+    }
+
+    pub fn migrate_inbox_rate_limit(
+        ctx: Context<MigrateInboxRateLimit>,
+        chain_id: u16,
+    ) -> Result<()> {
+        instructions::migrate_inbox_rate_limit(ctx, chain_id)
+    }
+
+    pub fn migrate_peer(ctx: Context<MigratePeer>, chain_id: u16) -> Result<()> {
+        instructions::migrate_peer(ctx, chain_id)
+    }
+
+    pub fn migrate_wormhole_peer(
+        ctx: Context<MigrateTransceiverPeer>,
+        chain_id: u16,
+    ) -> Result<()> {
+        instructions::migrate_transceiver_peer(ctx, chain_id)
+    }
+
+    pub fn migrate_inbox_item(
+        ctx: Context<MigrateInboxItem>,
+        args: MigrateInboxItemArgs,
+    ) -> Result<()> {
+        instructions::migrate_inbox_item(ctx, args)
+    }
+
+    pub fn migrate_outbox_item(ctx: Context<MigrateOutboxItem>) -> Result<()> {
+        instructions::migrate_outbox_item(ctx)
+    }
+
+    pub fn initialize_custody_ledger(ctx: Context<InitializeCustodyLedger>) -> Result<()> {
+        instructions::initialize_custody_ledger(ctx)
+    }
+
+    pub fn check_custody_invariant<'info>(
+        ctx: Context<'_, '_, '_, 'info, CheckCustodyInvariant<'info>>,
+        args: CheckCustodyInvariantArgs,
+    ) -> Result<CustodyInvariant> {
+        instructions::check_custody_invariant(ctx, args)
+    }
+
     pub fn set_peer(ctx: Context<SetPeer>, args: SetPeerArgs) -> Result<()> {
         instructions::set_peer(ctx, args)
     }
 
+    pub fn set_settlement_delays(
+        ctx: Context<SetSettlementDelays>,
+        args: SetSettlementDelaysArgs,
+    ) -> Result<()> {
+        instructions::set_settlement_delays(ctx, args)
+    }
+
     pub fn register_transceiver(ctx: Context<RegisterTransceiver>) -> Result<()> {
         instructions::register_transceiver(ctx)
     }
 
+    pub fn register_receiver(ctx: Context<RegisterReceiver>) -> Result<()> {
+        instructions::register_receiver(ctx)
+    }
+
+    pub fn deregister_receiver(ctx: Context<DeregisterReceiver>) -> Result<()> {
+        instructions::deregister_receiver(ctx)
+    }
+
     pub fn set_outbound_limit(
         ctx: Context<SetOutboundLimit>,
         args: SetOutboundLimitArgs,
@@ -145,6 +278,13 @@ pub mod example_native_token_transfers {
         instructions::set_outbound_limit(ctx, args)
     }
 
+    pub fn set_max_queued_outbound(
+        ctx: Context<SetMaxQueuedOutbound>,
+        args: SetMaxQueuedOutboundArgs,
+    ) -> Result<()> {
+        instructions::set_max_queued_outbound(ctx, args)
+    }
+
     pub fn set_inbound_limit(
         ctx: Context<SetInboundLimit>,
         args: SetInboundLimitArgs,
@@ -152,10 +292,127 @@ pub mod example_native_token_transfers {
         instructions::set_inbound_limit(ctx, args)
     }
 
+    pub fn set_outbound_limit_ramp(
+        ctx: Context<SetOutboundLimitRamp>,
+        args: SetOutboundLimitRampArgs,
+    ) -> Result<()> {
+        instructions::set_outbound_limit_ramp(ctx, args)
+    }
+
+    pub fn set_inbound_limit_ramp(
+        ctx: Context<SetInboundLimitRamp>,
+        args: SetInboundLimitRampArgs,
+    ) -> Result<()> {
+        instructions::set_inbound_limit_ramp(ctx, args)
+    }
+
+    pub fn cancel_outbound_limit_ramp(ctx: Context<CancelOutboundLimitRamp>) -> Result<()> {
+        instructions::cancel_outbound_limit_ramp(ctx)
+    }
+
+    pub fn cancel_inbound_limit_ramp(
+        ctx: Context<CancelInboundLimitRamp>,
+        args: CancelInboundLimitRampArgs,
+    ) -> Result<()> {
+        instructions::cancel_inbound_limit_ramp(ctx, args)
+    }
+
+    pub fn set_owner_set(ctx: Context<SetOwnerSet>, args: SetOwnerSetArgs) -> Result<()> {
+        instructions::set_owner_set(ctx, args)
+    }
+
+    pub fn propose_admin_action(
+        ctx: Context<ProposeAdminAction>,
+        action: timelock::AdminAction,
+    ) -> Result<()> {
+        instructions::propose_admin_action(ctx, action)
+    }
+
+    pub fn cancel_admin_action(ctx: Context<CancelAdminAction>) -> Result<()> {
+        instructions::cancel_admin_action(ctx)
+    }
+
+    pub fn execute_admin_action(
+        ctx: Context<ExecuteAdminAction>,
+        action: timelock::AdminAction,
+    ) -> Result<()> {
+        instructions::execute_admin_action(ctx, action)
+    }
+
+    pub fn set_timelock_delay(
+        ctx: Context<SetTimelockDelay>,
+        args: SetTimelockDelayArgs,
+    ) -> Result<()> {
+        instructions::set_timelock_delay(ctx, args)
+    }
+
+    pub fn set_max_queue_age(ctx: Context<SetMaxQueueAge>, args: SetMaxQueueAgeArgs) -> Result<()> {
+        instructions::set_max_queue_age(ctx, args)
+    }
+
+    pub fn set_capacity_aware_release(
+        ctx: Context<SetCapacityAwareRelease>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_capacity_aware_release(ctx, enabled)
+    }
+
+    pub fn set_operator(ctx: Context<SetOperator>, operator: Option<Pubkey>) -> Result<()> {
+        instructions::set_operator(ctx, operator)
+    }
+
+    pub fn expedite_inbox_item(ctx: Context<OverrideInboxItem>) -> Result<()> {
+        instructions::expedite_inbox_item(ctx)
+    }
+
+    pub fn set_inbox_item_frozen(ctx: Context<OverrideInboxItem>, frozen: bool) -> Result<()> {
+        instructions::set_inbox_item_frozen(ctx, frozen)
+    }
+
+    pub fn reclaim_outbox_item_mint<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReclaimOutboxItemMint<'info>>,
+    ) -> Result<()> {
+        instructions::reclaim_outbox_item_mint(ctx)
+    }
+
+    pub fn reclaim_outbox_item_unlock<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReclaimOutboxItemUnlock<'info>>,
+    ) -> Result<()> {
+        instructions::reclaim_outbox_item_unlock(ctx)
+    }
+
     pub fn mark_outbox_item_as_released(ctx: Context<MarkOutboxItemAsReleased>) -> Result<bool> {
         instructions::mark_outbox_item_as_released(ctx)
     }
 
+    // views
+
+    pub fn get_outbound_capacity(ctx: Context<GetOutboundCapacity>) -> Result<u64> {
+        instructions::get_outbound_capacity(ctx)
+    }
+
+    pub fn get_inbound_capacity(
+        ctx: Context<GetInboundCapacity>,
+        args: GetInboundCapacityArgs,
+    ) -> Result<u64> {
+        instructions::get_inbound_capacity(ctx, args)
+    }
+
+    pub fn get_outbox_item_status(ctx: Context<GetOutboxItemStatus>) -> Result<OutboxItemStatus> {
+        instructions::get_outbox_item_status(ctx)
+    }
+
+    pub fn get_inbox_item_status(ctx: Context<GetInboxItemStatus>) -> Result<InboxItemStatus> {
+        instructions::get_inbox_item_status(ctx)
+    }
+
+    pub fn get_transfer_hook_accounts<'info>(
+        ctx: Context<'_, '_, '_, 'info, GetTransferHookAccounts<'info>>,
+        args: GetTransferHookAccountsArgs,
+    ) -> Result<Vec<TransferHookAccount>> {
+        instructions::get_transfer_hook_accounts(ctx, args)
+    }
+
     // standalone transceiver stuff
 
     pub fn set_wormhole_peer(
@@ -165,8 +422,47 @@ pub mod example_native_token_transfers {
         transceivers::wormhole::instructions::set_transceiver_peer(ctx, args)
     }
 
-    pub fn receive_wormhole_message(ctx: Context<ReceiveMessage>) -> Result<()> {
-        transceivers::wormhole::instructions::receive_message(ctx)
+    pub fn set_wormhole_peer_finality(
+        ctx: Context<SetTransceiverPeerFinality>,
+        args: SetTransceiverPeerFinalityArgs,
+    ) -> Result<()> {
+        transceivers::wormhole::instructions::set_transceiver_peer_finality(ctx, args)
+    }
+
+    pub fn set_wormhole_finality(
+        ctx: Context<SetWormholeFinality>,
+        args: SetWormholeFinalityArgs,
+    ) -> Result<()> {
+        transceivers::wormhole::instructions::set_wormhole_finality(ctx, args)
+    }
+
+    pub fn receive_wormhole_message(
+        ctx: Context<ReceiveMessage>,
+        args: ReceiveMessageArgs,
+    ) -> Result<()> {
+        transceivers::wormhole::instructions::receive_message(ctx, args)
+    }
+
+    pub fn receive_and_redeem_wormhole_message<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReceiveAndRedeem<'info>>,
+        args: ReceiveAndRedeemArgs,
+    ) -> Result<()> {
+        transceivers::wormhole::instructions::receive_and_redeem(ctx, args)
+    }
+
+    pub fn receive_wormhole_transceiver_info(ctx: Context<ReceiveBroadcast>) -> Result<()> {
+        transceivers::wormhole::instructions::receive_transceiver_info(ctx)
+    }
+
+    pub fn receive_wormhole_transceiver_registration(ctx: Context<ReceiveBroadcast>) -> Result<()> {
+        transceivers::wormhole::instructions::receive_transceiver_registration(ctx)
+    }
+
+    pub fn accept_wormhole_pending_peer(
+        ctx: Context<AcceptPendingPeer>,
+        args: AcceptPendingPeerArgs,
+    ) -> Result<()> {
+        transceivers::wormhole::instructions::accept_pending_peer(ctx, args)
     }
 
     pub fn release_wormhole_outbound(
@@ -176,6 +472,24 @@ pub mod example_native_token_transfers {
         transceivers::wormhole::instructions::release_outbound(ctx, args)
     }
 
+    pub fn approve_wormhole_relayer(
+        ctx: Context<ApproveRelayer>,
+        args: ApproveRelayerArgs,
+    ) -> Result<()> {
+        transceivers::wormhole::instructions::approve_relayer(ctx, args)
+    }
+
+    pub fn revoke_wormhole_relayer(ctx: Context<RevokeRelayer>) -> Result<()> {
+        transceivers::wormhole::instructions::revoke_relayer(ctx)
+    }
+
+    pub fn release_wormhole_outbound_reusable(
+        ctx: Context<ReleaseOutboundReusable>,
+        args: ReleaseOutboundReusableArgs,
+    ) -> Result<()> {
+        transceivers::wormhole::instructions::release_outbound_reusable(ctx, args)
+    }
+
     pub fn broadcast_wormhole_id(ctx: Context<BroadcastId>) -> Result<()> {
         transceivers::wormhole::instructions::broadcast_id(ctx)
     }
@@ -186,6 +500,20 @@ pub mod example_native_token_transfers {
     ) -> Result<()> {
         transceivers::wormhole::instructions::broadcast_peer(ctx, args)
     }
+
+    pub fn send_wormhole_pause_signal(
+        ctx: Context<SendPauseSignal>,
+        args: SendPauseSignalArgs,
+    ) -> Result<()> {
+        transceivers::wormhole::instructions::send_pause_signal(ctx, args)
+    }
+
+    pub fn receive_wormhole_pause_signal(
+        ctx: Context<ReceivePauseSignal>,
+        args: ReceivePauseSignalArgs,
+    ) -> Result<()> {
+        transceivers::wormhole::instructions::receive_pause_signal(ctx, args)
+    }
 }
 
 // The Version struct is just a dummy type because anchor needs every function
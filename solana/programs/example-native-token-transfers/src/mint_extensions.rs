@@ -0,0 +1,152 @@
+//! Token-2022 mint extension compatibility.
+//!
+//! The extensions of the mint are inspected once, at
+//! [`crate::instructions::initialize`], and each is classified as supported,
+//! warned or rejected (see [`Compatibility`]). Mints with rejected extensions
+//! can't be managed by NTT. The detected extensions are recorded in
+//! [`crate::config::Config::mint_extensions`].
+//!
+//! NOTE: extensions that are unknown to the token-2022 version this program is
+//! built against (such as the pausable extension) can't be inspected, so mints
+//! using them are rejected.
+
+use anchor_lang::prelude::*;
+use ntt_messages::mode::Mode;
+use spl_token_2022::{
+    extension::{
+        default_account_state::DefaultAccountState,
+        transfer_fee::{TransferFee, TransferFeeConfig},
+        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+    state::AccountState,
+};
+
+use crate::error::NTTError;
+
+/// The maximum number of extensions recorded in [`MintExtensions`].
+pub const MAX_MINT_EXTENSIONS: usize = 16;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Compatibility {
+    /// The extension doesn't affect NTT.
+    Supported,
+    /// NTT works with the extension, but it changes the token's behaviour in
+    /// ways integrators should be aware of. A warning is logged at initialize.
+    Warned(&'static str),
+    /// NTT can't (safely) manage tokens with the extension.
+    Rejected(NTTError),
+}
+
+/// Classifies the extension `extension` of `mint` for a program running in
+/// `mode`.
+pub fn compatibility(
+    mint: &StateWithExtensions<spl_token_2022::state::Mint>,
+    extension: ExtensionType,
+    mode: Mode,
+) -> Result<Compatibility> {
+    Ok(match extension {
+        ExtensionType::Uninitialized
+        | ExtensionType::TransferHook
+        | ExtensionType::MetadataPointer
+        | ExtensionType::TokenMetadata
+        | ExtensionType::GroupPointer
+        | ExtensionType::TokenGroup
+        | ExtensionType::GroupMemberPointer
+        | ExtensionType::TokenGroupMember => Compatibility::Supported,
+        // Transfers check that exactly the transferred amount is locked or
+        // burned, so they would all fail with a fee. The fee authority can
+        // still raise the fee later, which stops transfers without losing
+        // funds.
+        ExtensionType::TransferFeeConfig => {
+            let config = mint.get_extension::<TransferFeeConfig>()?;
+            if is_zero_fee(&config.older_transfer_fee) && is_zero_fee(&config.newer_transfer_fee) {
+                Compatibility::Supported
+            } else {
+                Compatibility::Rejected(NTTError::TransferFeeMint)
+            }
+        }
+        ExtensionType::InterestBearingConfig => {
+            Compatibility::Warned("amounts are bridged without the accrued interest")
+        }
+        ExtensionType::MintCloseAuthority => {
+            Compatibility::Warned("the mint can be closed by the close authority")
+        }
+        // The delegate can move tokens out of the custody account, which
+        // holds all the locked tokens in locking mode.
+        ExtensionType::PermanentDelegate => match mode {
+            Mode::Locking => Compatibility::Rejected(NTTError::PermanentDelegateMint),
+            Mode::Burning => {
+                Compatibility::Warned("the permanent delegate can burn or move any tokens")
+            }
+        },
+        // The custody and recipient accounts would be created frozen.
+        ExtensionType::DefaultAccountState => {
+            let default_state = mint.get_extension::<DefaultAccountState>()?;
+            if default_state.state == AccountState::Frozen as u8 {
+                Compatibility::Rejected(NTTError::FrozenByDefaultMint)
+            } else {
+                Compatibility::Supported
+            }
+        }
+        ExtensionType::NonTransferable => Compatibility::Rejected(NTTError::NonTransferableMint),
+        // NTT only moves non-confidential balances.
+        ExtensionType::ConfidentialTransferMint | ExtensionType::ConfidentialTransferFeeConfig => {
+            Compatibility::Rejected(NTTError::ConfidentialTransferMint)
+        }
+        // account extensions
+        _ => Compatibility::Rejected(NTTError::UnsupportedMintExtension),
+    })
+}
+
+fn is_zero_fee(fee: &TransferFee) -> bool {
+    u16::from(fee.transfer_fee_basis_points) == 0 || u64::from(fee.maximum_fee) == 0
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct MintExtensions {
+    /// The [`ExtensionType`]s of the mint. Empty for legacy token mints.
+    #[max_len(MAX_MINT_EXTENSIONS)]
+    pub extensions: Vec<u16>,
+    /// Whether any of the extensions is [`Compatibility::Warned`].
+    pub warned: bool,
+}
+
+impl MintExtensions {
+    /// Inspects the extensions of `mint`, and fails if any of them is
+    /// [`Compatibility::Rejected`].
+    pub fn check(mint: &AccountInfo, mode: Mode) -> Result<Self> {
+        if *mint.owner != spl_token_2022::ID {
+            return Ok(Self::default());
+        }
+
+        let data = mint.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+        let extension_types = mint
+            .get_extension_types()
+            .map_err(|_| NTTError::UnsupportedMintExtension)?;
+
+        if extension_types.len() > MAX_MINT_EXTENSIONS {
+            return Err(NTTError::UnsupportedMintExtension.into());
+        }
+
+        let mut warned = false;
+        for extension in &extension_types {
+            match compatibility(&mint, *extension, mode)? {
+                Compatibility::Supported => {}
+                Compatibility::Warned(warning) => {
+                    msg!("Warning: {:?}: {}", extension, warning);
+                    warned = true;
+                }
+                Compatibility::Rejected(error) => {
+                    msg!("Unsupported mint extension: {:?}", extension);
+                    return Err(error.into());
+                }
+            }
+        }
+
+        Ok(Self {
+            extensions: extension_types.into_iter().map(|e| e as u16).collect(),
+            warned,
+        })
+    }
+}
@@ -1,5 +1,10 @@
 use anchor_lang::prelude::*;
 
+use crate::error::NTTError;
+
+/// The maximum number of [`SettlementDelayTier`]s per peer.
+pub const MAX_SETTLEMENT_DELAY_TIERS: usize = 4;
+
 #[account]
 #[derive(InitSpace)]
 /// A peer on another chain. Stored in a PDA seeded by the chain id.
@@ -7,8 +12,46 @@ pub struct NttManagerPeer {
     pub bump: u8,
     pub address: [u8; 32],
     pub token_decimals: u8,
+    /// The address of the token on the peer chain. Inbound transfers have to
+    /// carry this as their `source_token`.
+    pub token_address: [u8; 32],
+    /// Minimum delay before inbound transfers from this peer can be released,
+    /// regardless of the rate limit. See [`NttManagerPeer::settlement_delay`].
+    #[max_len(MAX_SETTLEMENT_DELAY_TIERS)]
+    pub settlement_delays: Vec<SettlementDelayTier>,
+    /// Set by the peer with a [`ntt_messages::pause::PauseSignal`]. Outbound
+    /// transfers to the peer are rejected while this is set.
+    pub outbound_paused: bool,
 }
 
 impl NttManagerPeer {
     pub const SEED_PREFIX: &'static [u8] = b"peer";
+
+    /// The minimum number of seconds an inbound transfer of `amount` has to
+    /// wait before it can be released. This is the largest delay of all the
+    /// tiers that apply to the amount, or zero if none do.
+    pub fn settlement_delay(&self, amount: u64) -> i64 {
+        self.settlement_delays
+            .iter()
+            .filter(|tier| amount >= tier.min_amount)
+            .map(|tier| tier.delay)
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn set_settlement_delays(&mut self, tiers: Vec<SettlementDelayTier>) -> Result<()> {
+        if tiers.len() > MAX_SETTLEMENT_DELAY_TIERS || tiers.iter().any(|tier| tier.delay < 0) {
+            return Err(NTTError::InvalidSettlementDelays.into());
+        }
+        self.settlement_delays = tiers;
+        Ok(())
+    }
+}
+
+/// Inbound transfers of at least `min_amount` (in local token units) have to
+/// wait at least `delay` seconds before they can be released.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct SettlementDelayTier {
+    pub min_amount: u64,
+    pub delay: i64,
 }
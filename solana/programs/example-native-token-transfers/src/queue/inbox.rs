@@ -1,10 +1,11 @@
 use std::ops::{Deref, DerefMut};
 
 use anchor_lang::prelude::*;
+use ntt_messages::chain_id::ChainId;
 
-use crate::{bitmap::Bitmap, clock::current_timestamp, error::NTTError};
+use crate::{bitmap::Bitmap, clock::current_timestamp, error::NTTError, transfer::Payload};
 
-use super::rate_limit::RateLimitState;
+use super::rate_limit::{RateLimitResult, RateLimitState};
 
 #[account]
 #[derive(InitSpace)]
@@ -18,6 +19,25 @@ pub struct InboxItem {
     pub recipient_address: Pubkey,
     pub votes: Bitmap,
     pub release_status: ReleaseStatus,
+    /// The chain the transfer was sent from.
+    pub from_chain: ChainId,
+    /// The inbound rate limit capacity that the transfer did not consume when
+    /// it was delayed. Zero once the transfer has been released.
+    /// See [`crate::config::Config::capacity_aware_release`].
+    pub deferred_capacity: u64,
+    /// Frozen transfers can't be released until they are unfrozen.
+    /// See [`crate::instructions::set_inbox_item_frozen`].
+    pub frozen: bool,
+    /// The sender on the source chain. Passed to registered receivers.
+    /// See [`crate::registered_receiver`].
+    pub sender: [u8; 32],
+    /// The mint of the transferred token. Checked against the config by
+    /// instructions that take the inbox item.
+    pub mint: Pubkey,
+    /// The additional payload of the transfer. Passed to registered receivers.
+    /// NOTE: the inbox item is allocated with room for it, see
+    /// [`InboxItem::space`].
+    pub additional_payload: Payload,
 }
 
 /// The status of an InboxItem. This determines whether the tokens are minted/unlocked to the recipient. As
@@ -34,9 +54,30 @@ pub enum ReleaseStatus {
 impl InboxItem {
     pub const SEED_PREFIX: &'static [u8] = b"inbox_item";
 
+    /// The account space of an inbox item whose transfer carries an
+    /// additional payload of `additional_payload_len` bytes.
+    pub fn space(additional_payload_len: usize) -> usize {
+        8 + Self::INIT_SPACE + additional_payload_len
+    }
+
     /// Attempt to release the transfer.
     /// Returns true if the transfer was released, false if it was not yet time to release it.
-    pub fn try_release(&mut self) -> Result<bool> {
+    ///
+    /// When `rate_limits` are passed (capacity-aware release), as the inbound
+    /// rate limit of the source chain and the outbound rate limit, a delayed
+    /// transfer additionally has to consume its deferred capacity before it's
+    /// released. If there is not enough capacity, the transfer is delayed
+    /// again. Otherwise the outbound rate limit is refilled with the same
+    /// amount (backflow), as it would have been had the transfer not been
+    /// delayed.
+    pub fn try_release(
+        &mut self,
+        rate_limits: Option<(&mut RateLimitState, &mut RateLimitState)>,
+    ) -> Result<bool> {
+        if self.frozen {
+            return Err(NTTError::InboxItemFrozen.into());
+        }
+
         let now = current_timestamp();
 
         match self.release_status {
@@ -45,7 +86,24 @@ impl InboxItem {
                 if release_timestamp > now {
                     return Ok(false);
                 }
+                if let Some((rate_limit, backflow)) =
+                    rate_limits.filter(|_| self.deferred_capacity > 0)
+                {
+                    match rate_limit.consume_or_delay(self.deferred_capacity) {
+                        RateLimitResult::Consumed(now) => {
+                            backflow.refill(now, self.deferred_capacity)
+                        }
+                        RateLimitResult::Delayed(release_timestamp) => {
+                            // NOTE: this doesn't move the state machine
+                            // backward, it only pushes the release timestamp
+                            // further out.
+                            self.release_status = ReleaseStatus::ReleaseAfter(release_timestamp);
+                            return Ok(false);
+                        }
+                    }
+                }
                 self.release_status = ReleaseStatus::Released;
+                self.deferred_capacity = 0;
                 Ok(true)
             }
             ReleaseStatus::Released => Err(NTTError::TransferAlreadyRedeemed.into()),
@@ -59,6 +117,33 @@ impl InboxItem {
         self.release_status = ReleaseStatus::ReleaseAfter(release_timestamp);
         Ok(())
     }
+
+    /// Make a delayed transfer releasable right away, without consuming any
+    /// deferred rate limit capacity.
+    /// NOTE: this doesn't move the state machine backward, the transfer stays
+    /// in `ReleaseAfter` with an earlier release timestamp.
+    pub fn expedite(&mut self) -> Result<()> {
+        match self.release_status {
+            ReleaseStatus::NotApproved => Err(NTTError::TransferNotApproved.into()),
+            ReleaseStatus::ReleaseAfter(release_timestamp) => {
+                let now = current_timestamp();
+                self.release_status = ReleaseStatus::ReleaseAfter(release_timestamp.min(now));
+                self.deferred_capacity = 0;
+                Ok(())
+            }
+            ReleaseStatus::Released => Err(NTTError::TransferAlreadyRedeemed.into()),
+        }
+    }
+
+    /// Freezing is orthogonal to the release status, so it can be applied
+    /// (and lifted) at any point before the transfer is released.
+    pub fn set_frozen(&mut self, frozen: bool) -> Result<()> {
+        if self.release_status == ReleaseStatus::Released {
+            return Err(NTTError::TransferAlreadyRedeemed.into());
+        }
+        self.frozen = frozen;
+        Ok(())
+    }
 }
 
 /// Inbound rate limit per chain.
@@ -5,7 +5,7 @@ use ntt_messages::{chain_id::ChainId, trimmed_amount::TrimmedAmount};
 
 use crate::{bitmap::*, clock::current_timestamp, error::NTTError};
 
-use super::rate_limit::RateLimitState;
+use super::rate_limit::{RateLimitResult, RateLimitState};
 
 #[account]
 #[derive(InitSpace, Debug, PartialEq, Eq)]
@@ -18,13 +18,33 @@ pub struct OutboxItem {
     pub recipient_address: [u8; 32],
     pub release_timestamp: i64,
     pub released: Bitmap,
+    /// The outbound rate limit capacity that the transfer did not consume when
+    /// it was queued. Zero once the transfer has been released.
+    /// Like the rate limit, this is in the mint's native (untrimmed) units,
+    /// and it's also what the transfer adds to [`OutboxRateLimit::queued_amount`].
+    /// See [`crate::config::Config::capacity_aware_release`].
+    pub deferred_capacity: u64,
+    /// The mint of the transferred token. Checked against the config by
+    /// instructions that take the outbox item, as it is not keyed by the mint.
+    pub mint: Pubkey,
 }
 
 impl OutboxItem {
     /// Attempt to release the transfer.
     /// Returns true if the transfer was released, false if it was not yet time to release it.
+    ///
+    /// When `rate_limits` are passed (capacity-aware release), as the outbound
+    /// rate limit and the inbound rate limit of the recipient chain, a queued
+    /// transfer additionally has to consume its deferred capacity before it's
+    /// released. If there is not enough capacity, the transfer is delayed
+    /// again. Otherwise the inbound rate limit is refilled with the same amount
+    /// (backflow), as it would have been had the transfer not been queued.
     /// TODO: this is duplicated in inbox.rs. factor out?
-    pub fn try_release(&mut self, transceiver_index: u8) -> Result<bool> {
+    pub fn try_release(
+        &mut self,
+        transceiver_index: u8,
+        rate_limits: Option<(&mut RateLimitState, &mut RateLimitState)>,
+    ) -> Result<bool> {
         let now = current_timestamp();
 
         if self.release_timestamp > now {
@@ -35,22 +55,73 @@ impl OutboxItem {
             return Err(NTTError::MessageAlreadySent.into());
         }
 
+        if let Some((rate_limit, backflow)) = rate_limits.filter(|_| self.deferred_capacity > 0) {
+            match rate_limit.consume_or_delay(self.deferred_capacity) {
+                RateLimitResult::Consumed(now) => backflow.refill(now, self.deferred_capacity),
+                RateLimitResult::Delayed(release_timestamp) => {
+                    self.release_timestamp = release_timestamp;
+                    return Ok(false);
+                }
+            }
+        }
+
         self.released.set(transceiver_index, true)?;
+        self.deferred_capacity = 0;
 
         Ok(true)
     }
+
+    /// Whether the transfer has been releasable for at least `max_queue_age`
+    /// seconds, in which case the sender may reclaim it (if no transceiver has
+    /// released it yet). A `max_queue_age` of zero means transfers never expire.
+    pub fn is_expired(&self, max_queue_age: i64) -> bool {
+        max_queue_age > 0
+            && current_timestamp() >= self.release_timestamp.saturating_add(max_queue_age)
+    }
 }
 
 #[account]
 #[derive(InitSpace, PartialEq, Eq, Debug)]
 pub struct OutboxRateLimit {
     pub rate_limit: RateLimitState,
+    /// The total amount of the outbound transfers that are queued, i.e. that
+    /// were delayed by the rate limit and haven't been released (or
+    /// reclaimed) yet. Their tokens have already left the sender.
+    /// NOTE: like the rate limit, this is in the mint's native (untrimmed)
+    /// units, which (unlike trimmed amounts) don't depend on the recipient
+    /// chain.
+    pub queued_amount: u64,
+    /// The maximum of [`OutboxRateLimit::queued_amount`], in the same units.
+    /// Transfers that would be queued beyond it are rejected. `None` means no
+    /// cap.
+    pub max_queued_amount: Option<u64>,
 }
 
 /// Global rate limit for all outbound transfers to all chains.
-/// NOTE: only one of this account can exist, so we don't need to check the PDA.
+/// SECURITY: must check the PDA, as there is one for each token in multi-token
+/// mode (see [`crate::config::mint_seed`]).
 impl OutboxRateLimit {
     pub const SEED_PREFIX: &'static [u8] = b"outbox_rate_limit";
+
+    /// Adds a transfer of `amount` (untrimmed) to the queued total.
+    pub fn enqueue(&mut self, amount: u64) -> Result<()> {
+        let queued_amount = self
+            .queued_amount
+            .checked_add(amount)
+            .filter(|queued_amount| {
+                self.max_queued_amount
+                    .map_or(true, |max| *queued_amount <= max)
+            })
+            .ok_or(NTTError::QueuedAmountExceedsCap)?;
+        self.queued_amount = queued_amount;
+        Ok(())
+    }
+
+    /// NOTE: saturating, as transfers queued before the total was tracked
+    /// aren't included in it.
+    pub fn dequeue(&mut self, amount: u64) {
+        self.queued_amount = self.queued_amount.saturating_sub(amount);
+    }
 }
 
 impl Deref for OutboxRateLimit {
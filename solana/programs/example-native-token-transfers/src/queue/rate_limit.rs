@@ -15,6 +15,44 @@ pub struct RateLimitState {
     /// capacity. Transactions that exceeded the capacity do not count, they are
     /// just delayed.
     pub last_tx_timestamp: i64,
+    /// A scheduled change of the limit, in progress (or not yet applied).
+    /// While it is set, the effective limit is interpolated (see
+    /// [`RateLimitState::limit_at`]) and `limit` holds the limit the ramp
+    /// started from. Once the ramp is over, the next transaction moves its
+    /// end limit into `limit`.
+    pub ramp: Option<LimitRamp>,
+}
+
+/// A linear change of the limit from `start_limit` to `end_limit` between
+/// `start_timestamp` and `end_timestamp`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Eq, Debug)]
+pub struct LimitRamp {
+    pub start_limit: u64,
+    pub end_limit: u64,
+    pub start_timestamp: i64,
+    pub end_timestamp: i64,
+}
+
+impl LimitRamp {
+    // SECURITY: Integer division is OK here, the interpolated limit is rounded
+    // towards `start_limit`.
+    // SECURITY: Sign loss and truncation are OK, `now` is clamped to the
+    // (non-empty) ramp period, and the result is between the two limits.
+    #[allow(clippy::integer_division)]
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn limit_at(&self, now: UnixTimestamp) -> u64 {
+        let elapsed =
+            (now.clamp(self.start_timestamp, self.end_timestamp) - self.start_timestamp) as u128;
+        let duration = (self.end_timestamp - self.start_timestamp) as u128;
+
+        let (start, end) = (u128::from(self.start_limit), u128::from(self.end_limit));
+        if end >= start {
+            (start + (end - start) * elapsed / duration) as u64
+        } else {
+            (start - (start - end) * elapsed / duration) as u64
+        }
+    }
 }
 
 /// The result of attempting to consume from a rate limiter.
@@ -34,6 +72,15 @@ impl RateLimitState {
             limit,
             capacity_at_last_tx: limit,
             last_tx_timestamp: 0,
+            ramp: None,
+        }
+    }
+
+    /// The limit in effect at `now`, taking the scheduled ramp into account.
+    pub fn limit_at(&self, now: UnixTimestamp) -> u64 {
+        match &self.ramp {
+            Some(ramp) => ramp.limit_at(now),
+            None => self.limit,
         }
     }
 
@@ -58,7 +105,7 @@ impl RateLimitState {
     pub fn capacity_at(&self, now: UnixTimestamp) -> u64 {
         assert!(self.last_tx_timestamp <= now);
 
-        let limit = u128::from(self.limit);
+        let limit = u128::from(self.limit_at(now));
 
         // morally this is
         // capacity = old_capacity + (limit / rate_limit_duration) * time_passed
@@ -67,17 +114,17 @@ impl RateLimitState {
         // capacity = old_capacity + (limit * time_passed) / rate_limit_duration
         // as it has better numerical stability.
         //
+        // While a ramp is in progress, `limit * time_passed` is the integral of
+        // the limit over the time passed instead. See [`Self::limit_seconds`].
+        //
         // This can overflow u64 (if limit is close to u64 max), so we use u128
         // for the intermediate calculations. Theoretically it could also overflow u128
         // if limit == time_passed == u64 max, but that will take a very long time.
 
         let capacity_at_last_tx = self.capacity_at_last_tx;
 
-        let calculated_capacity = {
-            let time_passed = now - self.last_tx_timestamp;
-            u128::from(capacity_at_last_tx)
-                + time_passed as u128 * limit / (Self::RATE_LIMIT_DURATION as u128)
-        };
+        let calculated_capacity = u128::from(capacity_at_last_tx)
+            + self.limit_seconds(self.last_tx_timestamp, now) / (Self::RATE_LIMIT_DURATION as u128);
 
         // The use of `min` here prevents truncation.
         // The value of `limit` is u64 in reality. If both `calculated_capacity` and `limit` are at
@@ -86,6 +133,36 @@ impl RateLimitState {
         calculated_capacity.min(limit) as u64
     }
 
+    /// The integral of the limit over `[from, to]` (in limit * seconds).
+    ///
+    /// With a ramp, the period is split into the parts before, during, and
+    /// after the ramp, where the limit is constant or linear, so the integral
+    /// is exact (up to rounding down) even when the period straddles either
+    /// end of the ramp.
+    // SECURITY: Integer division is OK here, rounding down reduces the refill.
+    // SECURITY: Sign loss is OK here, the differences are clamped to be
+    // non-negative.
+    #[allow(clippy::integer_division)]
+    #[allow(clippy::cast_sign_loss)]
+    fn limit_seconds(&self, from: UnixTimestamp, to: UnixTimestamp) -> u128 {
+        let seconds = |start: UnixTimestamp, end: UnixTimestamp| (end - start).max(0) as u128;
+
+        let Some(ramp) = &self.ramp else {
+            return u128::from(self.limit) * seconds(from, to);
+        };
+
+        let ramp_from = from.clamp(ramp.start_timestamp, ramp.end_timestamp);
+        let ramp_to = to.clamp(ramp.start_timestamp, ramp.end_timestamp);
+
+        let before = u128::from(ramp.start_limit) * seconds(from, to.min(ramp.start_timestamp));
+        let during = (u128::from(ramp.limit_at(ramp_from)) + u128::from(ramp.limit_at(ramp_to)))
+            * seconds(ramp_from, ramp_to)
+            / 2;
+        let after = u128::from(ramp.end_limit) * seconds(from.max(ramp.end_timestamp), to);
+
+        before + during + after
+    }
+
     /// Computes the timestamp at which the given amount can be consumed.
     /// If it fits within the current capacity, the current timestamp is
     /// returned, and the remaining capacity is reduced.
@@ -97,6 +174,7 @@ impl RateLimitState {
         if capacity >= amount {
             self.capacity_at_last_tx = capacity - amount;
             self.last_tx_timestamp = now;
+            self.settle_ramp(now);
             RateLimitResult::Consumed(now)
         } else {
             RateLimitResult::Delayed(now + Self::RATE_LIMIT_DURATION)
@@ -106,16 +184,34 @@ impl RateLimitState {
     /// Refills the capacity by the given amount.
     /// This is used to replenish the capacity via backflows.
     pub fn refill(&mut self, now: UnixTimestamp, amount: u64) {
-        self.capacity_at_last_tx = self.capacity_at(now).saturating_add(amount).min(self.limit);
+        self.capacity_at_last_tx = self
+            .capacity_at(now)
+            .saturating_add(amount)
+            .min(self.limit_at(now));
         self.last_tx_timestamp = now;
+        self.settle_ramp(now);
     }
 
+    /// Clears the ramp once it's over. Must only be called right after the
+    /// capacity has been brought up to date (i.e. `last_tx_timestamp == now`),
+    /// otherwise capacity accrued during the ramp would be computed with the
+    /// end limit.
+    fn settle_ramp(&mut self, now: UnixTimestamp) {
+        if let Some(ramp) = self.ramp.as_ref().filter(|ramp| ramp.end_timestamp <= now) {
+            self.limit = ramp.end_limit;
+            self.ramp = None;
+        }
+    }
+
+    /// Sets the limit immediately, adjusting the current capacity by the
+    /// difference. Cancels the scheduled ramp, if any.
     pub fn set_limit(&mut self, limit: u64) {
-        let old_limit = self.limit;
         let now = current_timestamp();
+        let old_limit = self.limit_at(now);
         let current_capacity = self.capacity_at(now);
 
         self.limit = limit;
+        self.ramp = None;
 
         let new_capacity: u64 = if old_limit > limit {
             // decrease in limit,
@@ -130,22 +226,61 @@ impl RateLimitState {
         self.capacity_at_last_tx = new_capacity.min(limit);
         self.last_tx_timestamp = now;
     }
+
+    /// Schedules the limit to change linearly from its current value to
+    /// `limit` over the next `duration` seconds. Unlike [`Self::set_limit`],
+    /// an increase doesn't add to the capacity at once: it refills at the
+    /// interpolated rate. Replaces the scheduled ramp, if any.
+    pub fn ramp_limit(&mut self, limit: u64, duration: i64) {
+        assert!(duration > 0);
+        let now = current_timestamp();
+        let start_limit = self.limit_at(now);
+
+        self.capacity_at_last_tx = self.capacity_at(now);
+        self.last_tx_timestamp = now;
+        self.limit = start_limit;
+        self.ramp = Some(LimitRamp {
+            start_limit,
+            end_limit: limit,
+            start_timestamp: now,
+            end_timestamp: now.saturating_add(duration),
+        });
+    }
+
+    /// Stops the scheduled ramp, keeping the limit at its current
+    /// (interpolated) value.
+    pub fn cancel_ramp(&mut self) {
+        let now = current_timestamp();
+        let limit = self.limit_at(now);
+
+        self.capacity_at_last_tx = self.capacity_at(now);
+        self.last_tx_timestamp = now;
+        self.limit = limit;
+        self.ramp = None;
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Mutex;
+
     use crate::clock::set_test_timestamp;
 
     use super::*;
 
+    /// The tests move the (global) test clock, so they can't run concurrently.
+    static CLOCK: Mutex<()> = Mutex::new(());
+
     #[test]
     #[allow(clippy::integer_division)]
     fn test_rate_limit() {
+        let _clock = CLOCK.lock().unwrap_or_else(|e| e.into_inner());
         let now = current_timestamp();
         let mut rate_limit_state = RateLimitState {
             limit: 100_000,
             capacity_at_last_tx: 100_000,
             last_tx_timestamp: now,
+            ramp: None,
         };
 
         // consume 30k. should be immediate
@@ -191,4 +326,105 @@ mod tests {
         rate_limit_state.refill(now, 50_000);
         assert_eq!(rate_limit_state.capacity(), 100_000);
     }
+
+    #[test]
+    #[allow(clippy::integer_division)]
+    fn test_limit_ramp() {
+        let _clock = CLOCK.lock().unwrap_or_else(|e| e.into_inner());
+        const DAY: i64 = RateLimitState::RATE_LIMIT_DURATION;
+
+        let mut rate_limit_state = RateLimitState::new(100_000);
+        rate_limit_state.last_tx_timestamp = current_timestamp();
+
+        // drain the capacity, then ramp the limit up to 200k over a day
+        rate_limit_state.consume_or_delay(100_000);
+        rate_limit_state.ramp_limit(200_000, DAY);
+
+        // the increase isn't added to the capacity at once
+        assert_eq!(rate_limit_state.capacity(), 0);
+        assert_eq!(rate_limit_state.limit_at(current_timestamp()), 100_000);
+
+        // halfway through, the limit is 150k and the capacity has refilled at
+        // the average of 100k and 150k per day
+        set_test_timestamp(current_timestamp() + DAY / 2);
+        assert_eq!(rate_limit_state.limit_at(current_timestamp()), 150_000);
+        assert_eq!(rate_limit_state.capacity(), 62_500);
+
+        // consuming during the ramp doesn't end it
+        let now = current_timestamp();
+        assert_eq!(
+            rate_limit_state.consume_or_delay(2_500),
+            RateLimitResult::Consumed(now)
+        );
+        assert_eq!(rate_limit_state.capacity(), 60_000);
+        assert_eq!(rate_limit_state.limit, 100_000);
+        assert!(rate_limit_state.ramp.is_some());
+
+        // after the ramp, the limit stays at 200k, and the next transaction
+        // settles it
+        set_test_timestamp(current_timestamp() + DAY);
+        assert_eq!(rate_limit_state.capacity(), 200_000);
+        rate_limit_state.consume_or_delay(50_000);
+        assert_eq!(rate_limit_state.limit, 200_000);
+        assert_eq!(rate_limit_state.ramp, None);
+        assert_eq!(rate_limit_state.capacity(), 150_000);
+    }
+
+    #[test]
+    #[allow(clippy::integer_division)]
+    fn test_limit_ramp_end() {
+        let _clock = CLOCK.lock().unwrap_or_else(|e| e.into_inner());
+        const DAY: i64 = RateLimitState::RATE_LIMIT_DURATION;
+
+        // ramping down from 300k to 200k over a quarter of a day, with the
+        // elapsed period straddling the end of the ramp
+        let mut rate_limit_state = RateLimitState::new(300_000);
+        rate_limit_state.last_tx_timestamp = current_timestamp();
+        rate_limit_state.consume_or_delay(300_000);
+        rate_limit_state.ramp_limit(200_000, DAY / 4);
+
+        // a quarter of a day at an average of 250k, then a quarter of a day at
+        // 200k (and not half a day at the average of 300k and 200k, which
+        // would be 125k)
+        set_test_timestamp(current_timestamp() + DAY / 2);
+        assert_eq!(rate_limit_state.capacity(), 62_500 + 50_000);
+
+        // ramping up from 100k to 300k over half a day
+        let mut rate_limit_state = RateLimitState::new(100_000);
+        rate_limit_state.last_tx_timestamp = current_timestamp();
+        rate_limit_state.consume_or_delay(100_000);
+        rate_limit_state.ramp_limit(300_000, DAY / 2);
+
+        // half a day at an average of 200k, then a quarter of a day at 300k
+        set_test_timestamp(current_timestamp() + DAY * 3 / 4);
+        assert_eq!(rate_limit_state.capacity(), 100_000 + 75_000);
+    }
+
+    #[test]
+    fn test_cancel_limit_ramp() {
+        let _clock = CLOCK.lock().unwrap_or_else(|e| e.into_inner());
+        const DAY: i64 = RateLimitState::RATE_LIMIT_DURATION;
+
+        let mut rate_limit_state = RateLimitState::new(100_000);
+        rate_limit_state.last_tx_timestamp = current_timestamp();
+
+        // ramping down lowers the capacity along with the limit
+        rate_limit_state.ramp_limit(0, DAY);
+        set_test_timestamp(current_timestamp() + DAY / 4);
+        assert_eq!(rate_limit_state.capacity(), 75_000);
+
+        // cancelling keeps the limit where the ramp got to
+        rate_limit_state.cancel_ramp();
+        assert_eq!(rate_limit_state.limit, 75_000);
+        assert_eq!(rate_limit_state.ramp, None);
+
+        set_test_timestamp(current_timestamp() + DAY);
+        assert_eq!(rate_limit_state.capacity(), 75_000);
+
+        // setting the limit directly also cancels the ramp
+        rate_limit_state.ramp_limit(150_000, DAY);
+        rate_limit_state.set_limit(100_000);
+        assert_eq!(rate_limit_state.ramp, None);
+        assert_eq!(rate_limit_state.capacity(), 100_000);
+    }
 }
@@ -0,0 +1,69 @@
+//! Transfer-and-call.
+//!
+//! A receiver program is registered by the owner with
+//! [`crate::instructions::register_receiver`]. Inbound transfers to the
+//! receiver's authority PDA (see [`receiver_authority`]) are delivered to that
+//! PDA's associated token account, after which NTT invokes the receiver's
+//! `receive_ntt_transfer` handler with [`ReceiveNttTransferArgs`]. The
+//! accounts passed to the handler are:
+//! 1. the caller PDA (seeded by [`RECEIVER_CALLER_SEED`]), as a signer, so
+//!    the receiver can check that it's being invoked by NTT
+//! 2. the receiver's token account (writable)
+//! 3. the mint
+//! 4. the inbox item
+//!
+//! followed by the remaining accounts of the release instruction.
+//!
+//! Transfers to registered receivers can only be released with the `_and_call`
+//! release instructions. If the handler fails, the whole release reverts, so
+//! the inbox item stays releasable and can be retried.
+
+use anchor_lang::prelude::*;
+use ntt_messages::chain_id::ChainId;
+
+/// Seed of a receiver program's authority PDA, which owns the token account
+/// inbound transfers are delivered to.
+pub const RECEIVER_AUTHORITY_SEED: &[u8] = b"ntt_receiver";
+
+/// Seed of the PDA that NTT signs the receiver handler invocation with.
+pub const RECEIVER_CALLER_SEED: &[u8] = b"receiver_caller";
+
+#[account]
+#[derive(InitSpace)]
+/// Stored in a PDA seeded by the receiver's authority, since that's what
+/// inbound transfers name as their recipient.
+pub struct RegisteredReceiver {
+    pub bump: u8,
+    pub program: Pubkey,
+}
+
+impl RegisteredReceiver {
+    pub const SEED_PREFIX: &'static [u8] = b"registered_receiver";
+}
+
+/// The authority PDA of the receiver `program`.
+pub fn receiver_authority(program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[RECEIVER_AUTHORITY_SEED], program).0
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReceiveNttTransferArgs {
+    pub from_chain: ChainId,
+    /// The sender on the source chain.
+    pub sender: [u8; 32],
+    /// The amount delivered to the receiver's token account.
+    pub amount: u64,
+    /// The additional payload of the transfer. Empty if it has none.
+    pub additional_payload: Vec<u8>,
+}
+
+impl ReceiveNttTransferArgs {
+    /// Instruction data of the handler: the Anchor discriminator of
+    /// `receive_ntt_transfer`, followed by the arguments.
+    pub fn data(&self) -> Vec<u8> {
+        let mut data =
+            solana_program::hash::hash(b"global:receive_ntt_transfer").to_bytes()[..8].to_vec();
+        data.extend(self.try_to_vec().unwrap());
+        data
+    }
+}
@@ -0,0 +1,170 @@
+//! Optional timelock for owner actions.
+//!
+//! When [`Config::timelock_delay`] is non-zero, every privileged admin action
+//! (other than pausing) has to be proposed first with
+//! [`crate::instructions::propose_admin_action`]. The proposal is stored in a
+//! [`PendingAdminAction`] PDA seeded by the hash of the action (and the mint in
+//! multi-token mode), and the action itself can only be executed once
+//! [`PendingAdminAction::earliest_execution`] has passed. Executing the
+//! action closes the pending action account. Until then, the owner can cancel
+//! the proposal with [`crate::instructions::cancel_admin_action`].
+//!
+//! This gives observers a window to react (e.g. by pausing, which is never
+//! timelocked) if the owner key is compromised.
+
+use anchor_lang::prelude::*;
+use ntt_messages::chain_id::ChainId;
+
+use crate::{
+    clock::current_timestamp,
+    config::{Config, OwnerSet},
+    error::NTTError,
+    peer::{SettlementDelayTier, MAX_SETTLEMENT_DELAY_TIERS},
+    transceivers::wormhole::accounts::WormholeFinality,
+};
+
+/// An admin action that is subject to the timelock. The fields mirror the
+/// arguments (and relevant accounts) of the corresponding instruction, so a
+/// proposal commits to exactly what is going to be executed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub enum AdminAction {
+    TransferOwnership {
+        new_owner: Pubkey,
+    },
+    TransferOwnershipOneStepUnchecked {
+        new_owner: Pubkey,
+    },
+    SetPeer {
+        chain_id: ChainId,
+        address: [u8; 32],
+        limit: u64,
+        token_decimals: u8,
+        token_address: [u8; 32],
+    },
+    RegisterTransceiver {
+        transceiver: Pubkey,
+    },
+    SetOutboundLimit {
+        limit: u64,
+    },
+    SetInboundLimit {
+        chain_id: ChainId,
+        limit: u64,
+    },
+    SetWormholePeer {
+        chain_id: ChainId,
+        address: [u8; 32],
+    },
+    SetWormholePeerFinality {
+        chain_id: ChainId,
+        finality: Option<WormholeFinality>,
+    },
+    SetTimelockDelay {
+        delay: i64,
+    },
+    SetOwnerSet {
+        owner_set: OwnerSet,
+    },
+    SetMaxQueueAge {
+        max_queue_age: i64,
+    },
+    SetCapacityAwareRelease {
+        enabled: bool,
+    },
+    SetOperator {
+        operator: Option<Pubkey>,
+    },
+    SetSettlementDelays {
+        chain_id: ChainId,
+        #[max_len(MAX_SETTLEMENT_DELAY_TIERS)]
+        tiers: Vec<SettlementDelayTier>,
+    },
+    AcceptPendingPeer {
+        chain_id: ChainId,
+        transceiver_address: [u8; 32],
+        limit: u64,
+        allow_mismatch: bool,
+    },
+    RegisterReceiver {
+        receiver: Pubkey,
+    },
+    DeregisterReceiver {
+        receiver: Pubkey,
+    },
+    Upgrade {
+        buffer: Pubkey,
+    },
+    RenounceOwnership,
+    SetMaxQueuedOutbound {
+        max_queued_amount: Option<u64>,
+    },
+    SetOutboundLimitRamp {
+        limit: u64,
+        duration: i64,
+    },
+    SetInboundLimitRamp {
+        chain_id: ChainId,
+        limit: u64,
+        duration: i64,
+    },
+    ApproveRelayer {
+        relayer: Pubkey,
+    },
+    SetWormholeFinality {
+        finality: WormholeFinality,
+    },
+    InitializeCustodyLedger,
+}
+
+impl AdminAction {
+    pub fn keccak256(&self) -> solana_program::keccak::Hash {
+        solana_program::keccak::hash(&self.try_to_vec().unwrap())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+/// A proposed admin action. Stored in a PDA seeded by the hash of the action.
+pub struct PendingAdminAction {
+    pub bump: u8,
+    pub action: AdminAction,
+    /// The timestamp from which the action may be executed.
+    pub earliest_execution: i64,
+    /// The mint of the config the action was proposed for. See
+    /// [`crate::config::mint_seed`].
+    pub mint: Pubkey,
+}
+
+impl PendingAdminAction {
+    pub const SEED_PREFIX: &'static [u8] = b"pending_admin_action";
+}
+
+/// Checks that `action` is allowed to be executed now.
+///
+/// When the timelock is disabled, this is a no-op. Otherwise `pending_action`
+/// must hold a matured proposal of exactly `action`, which is then closed and
+/// its rent sent to `sol_destination`.
+pub fn enforce_timelock<'info>(
+    config: &Config,
+    pending_action: &Option<Account<'info, PendingAdminAction>>,
+    action: &AdminAction,
+    sol_destination: &AccountInfo<'info>,
+) -> Result<()> {
+    if !config.timelock_enabled() {
+        return Ok(());
+    }
+
+    let pending_action = pending_action
+        .as_ref()
+        .ok_or(NTTError::AdminActionNotProposed)?;
+
+    if pending_action.action != *action || pending_action.mint != config.mint {
+        return Err(NTTError::AdminActionMismatch.into());
+    }
+
+    if current_timestamp() < pending_action.earliest_execution {
+        return Err(NTTError::TimelockNotExpired.into());
+    }
+
+    pending_action.close(sol_destination.clone())
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+/// A relayer that the owner allows to release other senders' outbound
+/// transfers into its reusable message accounts. Stored in a PDA seeded by the
+/// relayer (and the mint in multi-token mode).
+///
+/// Reusable message accounts can be overwritten by the relayer before the
+/// guardians observe them, so releasing into one means trusting the relayer
+/// not to lose the release.
+/// See [`crate::transceivers::wormhole::instructions::release_outbound_reusable`].
+pub struct ApprovedRelayer {
+    pub bump: u8,
+}
+
+impl ApprovedRelayer {
+    pub const SEED_PREFIX: &'static [u8] = b"approved_relayer";
+}
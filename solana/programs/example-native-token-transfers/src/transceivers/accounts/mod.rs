@@ -1 +1,3 @@
+pub mod approved_relayer;
 pub mod peer;
+pub mod pending_peer;
@@ -1,11 +1,19 @@
 use anchor_lang::prelude::*;
 
+use crate::transceivers::wormhole::accounts::WormholeFinality;
+
 #[account]
 #[derive(InitSpace)]
 /// A peer on another chain. Stored in a PDA seeded by the chain id.
 pub struct TransceiverPeer {
     pub bump: u8,
     pub address: [u8; 32],
+    /// The finality of messages sent to this chain. When not set,
+    /// [`crate::config::Config::wormhole_finality`] is used.
+    pub finality: Option<WormholeFinality>,
+    /// The sequence of the last pause signal received from this peer.
+    /// Sequences are per emitter, so this is reset when the address changes.
+    pub pause_signal_sequence: Option<u64>,
 }
 
 impl TransceiverPeer {
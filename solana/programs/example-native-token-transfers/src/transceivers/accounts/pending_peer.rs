@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use ntt_messages::{chain_id::ChainId, mode::Mode};
+
+#[account]
+#[derive(InitSpace)]
+/// A peer transceiver, as claimed by its own broadcasts. Stored in a PDA seeded
+/// by the chain id and the address of the transceiver (the emitter of the
+/// broadcasts).
+///
+/// Anyone can post broadcasts from any address, so nothing in here is trusted
+/// until the owner accepts it with
+/// [`crate::transceivers::wormhole::instructions::accept_pending_peer`].
+pub struct PendingPeer {
+    pub bump: u8,
+    pub chain_id: ChainId,
+    pub transceiver_address: [u8; 32],
+    /// Set by a `WormholeTransceiverInfo` broadcast.
+    pub info: Option<PendingPeerInfo>,
+    /// Whether the peer has broadcast a `WormholeTransceiverRegistration` of
+    /// this transceiver.
+    pub registered: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub struct PendingPeerInfo {
+    pub manager_address: [u8; 32],
+    pub manager_mode: Mode,
+    pub token_address: [u8; 32],
+    pub token_decimals: u8,
+}
+
+impl PendingPeer {
+    pub const SEED_PREFIX: &'static [u8] = b"pending_peer";
+}
+
+impl PendingPeerInfo {
+    /// Returns true if the claimed info conflicts with this chain's setup:
+    /// - both sides lock tokens (so neither side can mint), or
+    /// - the token decimals differ from those of an already configured peer
+    ///   (`configured_decimals`).
+    pub fn mismatches(&self, mode: Mode, configured_decimals: Option<u8>) -> bool {
+        (self.manager_mode == Mode::Locking && mode == Mode::Locking)
+            || configured_decimals.is_some_and(|decimals| decimals != self.token_decimals)
+    }
+}
@@ -1,26 +1,44 @@
 use anchor_lang::prelude::*;
 use ntt_messages::chain_id::ChainId;
 
-use crate::{config::Config, transceivers::accounts::peer::TransceiverPeer};
+use crate::{
+    config::Config,
+    error::NTTError,
+    peer::NttManagerPeer,
+    queue::{inbox::InboxRateLimit, rate_limit::RateLimitState},
+    timelock::{enforce_timelock, AdminAction, PendingAdminAction},
+    transceivers::{
+        accounts::{
+            approved_relayer::ApprovedRelayer, peer::TransceiverPeer, pending_peer::PendingPeer,
+        },
+        wormhole::accounts::WormholeFinality,
+    },
+};
 
 #[derive(Accounts)]
 #[instruction(args: SetTransceiverPeerArgs)]
 pub struct SetTransceiverPeer<'info> {
-    #[account(
-        has_one = owner,
-    )]
     pub config: Account<'info, Config>,
 
+    #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
     #[account(
         init,
         space = 8 + TransceiverPeer::INIT_SPACE,
         payer = payer,
-        seeds = [TransceiverPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        seeds = [
+            TransceiverPeer::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
         bump
     )]
     pub peer: Account<'info, TransceiverPeer>,
@@ -38,10 +56,361 @@ pub fn set_transceiver_peer(
     ctx: Context<SetTransceiverPeer>,
     args: SetTransceiverPeerArgs,
 ) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetWormholePeer {
+            chain_id: args.chain_id,
+            address: args.address,
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
     ctx.accounts.peer.set_inner(TransceiverPeer {
         bump: ctx.bumps.peer,
         address: args.address,
+        finality: None,
+        pause_signal_sequence: None,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetTransceiverPeerFinalityArgs)]
+pub struct SetTransceiverPeerFinality<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
+    #[account(
+        mut,
+        seeds = [
+            TransceiverPeer::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, TransceiverPeer>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetTransceiverPeerFinalityArgs {
+    pub chain_id: ChainId,
+    /// `None` reverts to the default finality.
+    pub finality: Option<WormholeFinality>,
+}
+
+pub fn set_transceiver_peer_finality(
+    ctx: Context<SetTransceiverPeerFinality>,
+    args: SetTransceiverPeerFinalityArgs,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetWormholePeerFinality {
+            chain_id: args.chain_id,
+            finality: args.finality,
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    ctx.accounts.peer.finality = args.finality;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetWormholeFinality<'info> {
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetWormholeFinalityArgs {
+    pub finality: WormholeFinality,
+}
+
+/// Sets the finality of messages to peers that don't override it. See
+/// [`Config::wormhole_finality`].
+pub fn set_wormhole_finality(
+    ctx: Context<SetWormholeFinality>,
+    args: SetWormholeFinalityArgs,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::SetWormholeFinality {
+            finality: args.finality,
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    ctx.accounts.config.wormhole_finality = args.finality;
+
+    Ok(())
+}
+
+// * Pending peers
+
+/// Accepts a [`PendingPeer`] recorded from the peer's broadcasts (see
+/// [`crate::transceivers::wormhole::instructions::receive_transceiver_info`]).
+/// This registers both the manager peer and the transceiver peer, like
+/// [`crate::instructions::set_peer`] and [`set_transceiver_peer`] would, and
+/// closes the pending peer.
+///
+/// If the claimed info conflicts with this chain's setup (see
+/// [`crate::transceivers::accounts::pending_peer::PendingPeerInfo::mismatches`]),
+/// or the peer hasn't registered this transceiver (see
+/// [`crate::transceivers::wormhole::instructions::receive_transceiver_registration`]),
+/// the owner has to acknowledge it by setting `allow_mismatch`.
+#[derive(Accounts)]
+#[instruction(args: AcceptPendingPeerArgs)]
+pub struct AcceptPendingPeer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub config: Account<'info, Config>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            PendingPeer::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref(),
+            args.transceiver_address.as_ref(),
+        ],
+        bump = pending_peer.bump,
+    )]
+    pub pending_peer: Account<'info, PendingPeer>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + NttManagerPeer::INIT_SPACE,
+        payer = payer,
+        seeds = [
+            NttManagerPeer::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + InboxRateLimit::INIT_SPACE,
+        payer = payer,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + TransceiverPeer::INIT_SPACE,
+        payer = payer,
+        seeds = [
+            TransceiverPeer::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump
+    )]
+    pub transceiver_peer: Account<'info, TransceiverPeer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct AcceptPendingPeerArgs {
+    pub chain_id: ChainId,
+    pub transceiver_address: [u8; 32],
+    pub limit: u64,
+    pub allow_mismatch: bool,
+}
+
+pub fn accept_pending_peer(
+    ctx: Context<AcceptPendingPeer>,
+    args: AcceptPendingPeerArgs,
+) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::AcceptPendingPeer {
+            chain_id: args.chain_id,
+            transceiver_address: args.transceiver_address,
+            limit: args.limit,
+            allow_mismatch: args.allow_mismatch,
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    let info = ctx
+        .accounts
+        .pending_peer
+        .info
+        .clone()
+        .ok_or(NTTError::PendingPeerIncomplete)?;
+
+    // a freshly created peer has no address yet
+    let peer = &mut ctx.accounts.peer;
+    let configured_decimals = (peer.address != [0u8; 32]).then_some(peer.token_decimals);
+    let mismatch = !ctx.accounts.pending_peer.registered
+        || info.mismatches(ctx.accounts.config.mode, configured_decimals);
+    if !args.allow_mismatch && mismatch {
+        return Err(NTTError::PendingPeerMismatch.into());
+    }
+
+    // NOTE: the fields are updated in place so that settlement delays and the
+    // finality of existing peers are kept.
+    peer.bump = ctx.bumps.peer;
+    peer.address = info.manager_address;
+    peer.token_decimals = info.token_decimals;
+    peer.token_address = info.token_address;
+
+    ctx.accounts.inbox_rate_limit.set_inner(InboxRateLimit {
+        bump: ctx.bumps.inbox_rate_limit,
+        rate_limit: RateLimitState::new(args.limit),
     });
 
+    let transceiver_peer = &mut ctx.accounts.transceiver_peer;
+    if transceiver_peer.address != args.transceiver_address {
+        transceiver_peer.pause_signal_sequence = None;
+    }
+    transceiver_peer.bump = ctx.bumps.transceiver_peer;
+    transceiver_peer.address = args.transceiver_address;
+
+    Ok(())
+}
+
+// * Relayers
+
+#[derive(Accounts)]
+#[instruction(args: ApproveRelayerArgs)]
+pub struct ApproveRelayer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub config: Account<'info, Config>,
+
+    /// Required when the timelock is enabled. See [`crate::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
+    #[account(
+        init,
+        space = 8 + ApprovedRelayer::INIT_SPACE,
+        payer = payer,
+        seeds = [
+            ApprovedRelayer::SEED_PREFIX,
+            args.relayer.as_ref(),
+            config.mint_seed(),
+        ],
+        bump
+    )]
+    pub approved_relayer: Account<'info, ApprovedRelayer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ApproveRelayerArgs {
+    pub relayer: Pubkey,
+}
+
+/// Allows `relayer` to release any outbound transfer into its reusable message
+/// accounts. See [`ApprovedRelayer`].
+pub fn approve_relayer(ctx: Context<ApproveRelayer>, args: ApproveRelayerArgs) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+
+    enforce_timelock(
+        &ctx.accounts.config,
+        &ctx.accounts.pending_action,
+        &AdminAction::ApproveRelayer {
+            relayer: args.relayer,
+        },
+        &ctx.accounts.owner.to_account_info(),
+    )?;
+
+    ctx.accounts.approved_relayer.bump = ctx.bumps.approved_relayer;
+
     Ok(())
 }
+
+// NOTE: like pausing, revoking is not subject to the timelock, so that a
+// misbehaving relayer can be stopped right away.
+
+#[derive(Accounts)]
+pub struct RevokeRelayer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            ApprovedRelayer::SEED_PREFIX,
+            relayer.key().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = approved_relayer.bump,
+    )]
+    pub approved_relayer: Account<'info, ApprovedRelayer>,
+
+    /// CHECK: only used to derive the address of `approved_relayer`.
+    pub relayer: UncheckedAccount<'info>,
+}
+
+pub fn revoke_relayer(ctx: Context<RevokeRelayer>) -> Result<()> {
+    ctx.accounts
+        .config
+        .authorize_owner(&ctx.accounts.owner.key(), ctx.remaining_accounts)
+}
@@ -47,6 +47,7 @@ pub fn broadcast_id(ctx: Context<BroadcastId>) -> Result<()> {
         accs.emitter.to_account_info(),
         ctx.bumps.emitter,
         &message,
+        accs.config.wormhole_finality,
         &[],
     )?;
 
@@ -15,7 +15,7 @@ pub struct BroadcastPeer<'info> {
     pub config: Account<'info, Config>,
 
     #[account(
-        seeds = [TransceiverPeer::SEED_PREFIX, args.chain_id.to_be_bytes().as_ref()],
+        seeds = [TransceiverPeer::SEED_PREFIX, args.chain_id.to_be_bytes().as_ref(), config.mint_seed()],
         bump
     )]
     pub peer: Account<'info, TransceiverPeer>,
@@ -55,6 +55,7 @@ pub fn broadcast_peer(ctx: Context<BroadcastPeer>, args: BroadcastPeerArgs) -> R
         accs.emitter.to_account_info(),
         ctx.bumps.emitter,
         &message,
+        accs.config.wormhole_finality,
         &[],
     )?;
 
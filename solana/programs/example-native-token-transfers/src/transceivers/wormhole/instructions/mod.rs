@@ -1,11 +1,17 @@
 pub mod admin;
 pub mod broadcast_id;
 pub mod broadcast_peer;
+pub mod pause_signal;
+pub mod receive_and_redeem;
+pub mod receive_broadcast;
 pub mod receive_message;
 pub mod release_outbound;
 
 pub use admin::*;
 pub use broadcast_id::*;
 pub use broadcast_peer::*;
+pub use pause_signal::*;
+pub use receive_and_redeem::*;
+pub use receive_broadcast::*;
 pub use receive_message::*;
 pub use release_outbound::*;
@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use ntt_messages::{
+    chain_id::ChainId, ntt_manager::NttManagerMessage, pause::PauseSignal,
+    transceiver::TransceiverMessage, transceivers::wormhole::WormholeTransceiver,
+};
+
+use crate::{
+    config::*,
+    error::NTTError,
+    peer::NttManagerPeer,
+    registered_transceiver::*,
+    transceivers::{
+        accounts::peer::TransceiverPeer,
+        wormhole::{accounts::*, vaa_account::VaaAccount},
+    },
+};
+
+// * Sending
+
+/// Asks the peer on `args.chain_id` to pause (or resume) outbound transfers to
+/// this chain, e.g. while this deployment is paused.
+///
+/// NOTE: like [`crate::instructions::set_paused`], this is not subject to the
+/// timelock.
+#[derive(Accounts)]
+#[instruction(args: SendPauseSignalArgs)]
+pub struct SendPauseSignal<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref(), config.mint_seed()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        seeds = [TransceiverPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref(), config.mint_seed()],
+        bump = transceiver_peer.bump,
+    )]
+    pub transceiver_peer: Account<'info, TransceiverPeer>,
+
+    /// CHECK: initialized and written to by wormhole core bridge
+    #[account(mut)]
+    pub wormhole_message: Signer<'info>,
+
+    #[account(
+        seeds = [b"emitter"],
+        bump
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub emitter: UncheckedAccount<'info>,
+
+    pub wormhole: WormholeAccounts<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SendPauseSignalArgs {
+    pub chain_id: ChainId,
+    pub paused: bool,
+}
+
+pub fn send_pause_signal(ctx: Context<SendPauseSignal>, args: SendPauseSignalArgs) -> Result<()> {
+    let accs = ctx.accounts;
+
+    accs.config
+        .authorize_owner(&accs.owner.key(), ctx.remaining_accounts)?;
+
+    // The message account is a fresh keypair, so its address doubles as a
+    // unique message id.
+    let message: TransceiverMessage<WormholeTransceiver, PauseSignal> = TransceiverMessage::new(
+        crate::ID.to_bytes(),
+        accs.peer.address,
+        NttManagerMessage {
+            id: accs.wormhole_message.key().to_bytes(),
+            sender: accs.owner.key().to_bytes(),
+            payload: PauseSignal {
+                paused: args.paused,
+                to_chain: args.chain_id,
+            },
+        },
+        vec![],
+    );
+
+    post_message(
+        &accs.wormhole,
+        accs.payer.to_account_info(),
+        accs.wormhole_message.to_account_info(),
+        accs.emitter.to_account_info(),
+        ctx.bumps.emitter,
+        &message,
+        accs.transceiver_peer
+            .finality
+            .unwrap_or(accs.config.wormhole_finality),
+        &[],
+    )?;
+
+    Ok(())
+}
+
+// * Receiving
+
+/// Applies a [`PauseSignal`] sent by the peer on the VAA's emitter chain,
+/// pausing or resuming outbound transfers to that chain.
+///
+/// Signals are applied in order of their Wormhole sequence. Unlike transfers,
+/// a signal takes effect with a single transceiver's attestation, regardless
+/// of the threshold.
+#[derive(Accounts)]
+#[instruction(args: ReceivePauseSignalArgs)]
+pub struct ReceivePauseSignal<'info> {
+    pub config: Account<'info, Config>,
+
+    /// CHECK: either a legacy posted VAA or a verified encoded VAA. The owner
+    /// (the core bridge) and the layout are checked by [`VaaAccount::load`],
+    /// and the emitter by [`VaaAccount::transceiver_message`].
+    pub vaa: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TransceiverPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref(), config.mint_seed()],
+        bump = transceiver_peer.bump,
+    )]
+    pub transceiver_peer: Account<'info, TransceiverPeer>,
+
+    #[account(
+        mut,
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref(), config.mint_seed()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        seeds = [RegisteredTransceiver::SEED_PREFIX, crate::ID.as_ref(), config.mint_seed()],
+        bump = transceiver.bump,
+        constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
+    )]
+    pub transceiver: Account<'info, RegisteredTransceiver>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReceivePauseSignalArgs {
+    /// The emitter chain of the VAA.
+    pub chain_id: ChainId,
+}
+
+pub fn receive_pause_signal(
+    ctx: Context<ReceivePauseSignal>,
+    args: ReceivePauseSignalArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+
+    let (sequence, message) = {
+        let vaa = VaaAccount::load(&accs.vaa)?;
+        let message =
+            vaa.transceiver_message::<PauseSignal>(args.chain_id, accs.transceiver_peer.address)?;
+        (vaa.sequence(), message)
+    };
+
+    if message.ntt_manager_payload.payload.to_chain != accs.config.chain_id {
+        return Err(NTTError::InvalidChainId.into());
+    }
+
+    if message.recipient_ntt_manager != crate::ID.to_bytes() {
+        return Err(NTTError::InvalidRecipientNttManager.into());
+    }
+
+    if message.source_ntt_manager != accs.peer.address {
+        return Err(NTTError::InvalidNttManagerPeer.into());
+    }
+
+    if accs
+        .transceiver_peer
+        .pause_signal_sequence
+        .is_some_and(|last| sequence <= last)
+    {
+        return Err(NTTError::StalePauseSignal.into());
+    }
+
+    accs.transceiver_peer.pause_signal_sequence = Some(sequence);
+    accs.peer.outbound_paused = message.ntt_manager_payload.payload.paused;
+
+    Ok(())
+}
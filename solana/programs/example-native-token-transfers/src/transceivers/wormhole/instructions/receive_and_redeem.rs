@@ -0,0 +1,277 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::get_associated_token_address_with_program_id, token_interface};
+use ntt_messages::{chain_id::ChainId, mode::Mode, ntt::NativeTokenTransfer};
+
+use crate::{
+    config::*,
+    custody_ledger::CustodyLedger,
+    error::NTTError,
+    instructions::{record_vote, TokenRelease},
+    peer::NttManagerPeer,
+    queue::{
+        inbox::{InboxItem, InboxRateLimit},
+        outbox::OutboxRateLimit,
+    },
+    registered_receiver::RegisteredReceiver,
+    registered_transceiver::*,
+    transceivers::{accounts::peer::TransceiverPeer, wormhole::vaa_account::VaaAccount},
+    transfer::Payload,
+};
+
+/// Receives a message from the Wormhole transceiver and redeems it in one
+/// instruction: the VAA is verified, the transceiver's vote is recorded in the
+/// inbox item, and (if requested) the transfer is released.
+///
+/// Unlike [`crate::transceivers::wormhole::receive_message`] followed by
+/// [`crate::instructions::redeem`], the message is read straight from the
+/// VAA, so no [`crate::messages::ValidatedTransceiverMessage`] is allocated.
+///
+/// The release accounts are required even when `release` is not set.
+/// Transfers to registered receivers have to be released separately, with one
+/// of the `release_inbound_and_call_*` instructions.
+#[derive(Accounts)]
+#[instruction(args: ReceiveAndRedeemArgs)]
+pub struct ReceiveAndRedeem<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // NOTE: like `redeem`, this works when the contract is paused. Releasing
+    // does not.
+    #[account(
+        constraint = config.threshold > 0 @ NTTError::ZeroThreshold
+    )]
+    pub config: Account<'info, Config>,
+
+    // NOTE: we don't replay protect VAAs. Instead, we replay protect
+    // executing the messages themselves with the [`released`] flag.
+    /// CHECK: either a legacy posted VAA or a verified encoded VAA. The owner
+    /// (the core bridge) and the layout are checked by [`VaaAccount::load`],
+    /// and the emitter by [`VaaAccount::transceiver_message`].
+    pub vaa: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [TransceiverPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref(), config.mint_seed()],
+        bump = transceiver_peer.bump,
+    )]
+    pub transceiver_peer: Account<'info, TransceiverPeer>,
+
+    #[account(
+        seeds = [NttManagerPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref(), config.mint_seed()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, NttManagerPeer>,
+
+    #[account(
+        seeds = [RegisteredTransceiver::SEED_PREFIX, crate::ID.as_ref(), config.mint_seed()],
+        bump = transceiver.bump,
+        constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
+    )]
+    pub transceiver: Account<'info, RegisteredTransceiver>,
+
+    #[account(
+        mut,
+        address = config.mint,
+    )]
+    pub mint: InterfaceAccount<'info, token_interface::Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = InboxItem::space(args.additional_payload_len.into()),
+        seeds = [
+            InboxItem::SEED_PREFIX,
+            args.message_hash.as_ref(),
+            config.mint_seed(),
+        ],
+        bump,
+    )]
+    /// NOTE: the same inbox item as [`crate::instructions::Redeem::inbox_item`],
+    /// so votes recorded by either instruction are counted together.
+    pub inbox_item: Account<'info, InboxItem>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            args.chain_id.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = inbox_rate_limit.bump,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(mut)]
+    /// NOTE: checked against the recipient of the inbox item before releasing,
+    /// as the inbox item may be initialised by this very instruction.
+    pub recipient: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    /// CHECK: checked against the recipient of the inbox item before
+    /// releasing. See [`crate::instructions::ReleaseInbound::registered_receiver`].
+    pub registered_receiver: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [crate::TOKEN_AUTHORITY_SEED, config.mint_seed()],
+        bump,
+    )]
+    /// CHECK The seeds constraint ensures that this is the correct address
+    pub token_authority: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, token_interface::TokenInterface>,
+
+    #[account(
+        mut,
+        address = config.custody
+    )]
+    pub custody: InterfaceAccount<'info, token_interface::TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [CustodyLedger::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
+    /// CHECK: the seeds constraint ensures that this is the custody ledger,
+    /// which may not be initialized. See [`CustodyLedger::record_if_initialized`].
+    pub custody_ledger: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ReceiveAndRedeemArgs {
+    /// Whether to release the transfer once the vote is recorded. The release
+    /// is skipped (without reverting) if the transfer can't be released yet.
+    pub release: bool,
+    /// The emitter chain of the VAA.
+    pub chain_id: ChainId,
+    /// The hash of the NTT manager message in the VAA, which keys the inbox
+    /// item. See [`ntt_messages::ntt_manager::NttManagerMessage::keccak256`].
+    pub message_hash: [u8; 32],
+    /// The length of the additional payload of the transfer, which the inbox
+    /// item is allocated with room for.
+    pub additional_payload_len: u16,
+}
+
+pub fn receive_and_redeem<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReceiveAndRedeem<'info>>,
+    args: ReceiveAndRedeemArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+
+    let from_chain = args.chain_id;
+    let message = VaaAccount::load(&accs.vaa)?
+        .transceiver_message::<NativeTokenTransfer<Payload>>(
+            from_chain,
+            accs.transceiver_peer.address,
+        )?;
+
+    if message.ntt_manager_payload.keccak256(from_chain).to_bytes() != args.message_hash {
+        return Err(ErrorCode::ConstraintSeeds.into());
+    }
+
+    if message
+        .ntt_manager_payload
+        .payload
+        .additional_payload
+        .bytes()
+        .len()
+        != usize::from(args.additional_payload_len)
+    {
+        return Err(ErrorCode::ConstraintSpace.into());
+    }
+
+    // check that the message is targeted to this chain
+    if message.ntt_manager_payload.payload.to_chain != accs.config.chain_id {
+        return Err(NTTError::InvalidChainId.into());
+    }
+
+    // check that we're the intended recipient
+    if message.recipient_ntt_manager != crate::ID.to_bytes() {
+        return Err(NTTError::InvalidRecipientNttManager.into());
+    }
+
+    if message.source_ntt_manager != accs.peer.address {
+        return Err(NTTError::InvalidNttManagerPeer.into());
+    }
+
+    record_vote(
+        &accs.config,
+        &accs.peer,
+        &accs.mint,
+        &accs.transceiver,
+        &mut accs.inbox_item,
+        ctx.bumps.inbox_item,
+        &mut accs.inbox_rate_limit,
+        &mut accs.outbox_rate_limit,
+        from_chain,
+        message.message_data.ntt_manager_payload,
+    )?;
+
+    if !args.release {
+        return Ok(());
+    }
+
+    if accs.config.paused {
+        return Err(NTTError::Paused.into());
+    }
+
+    let recipient_address = accs.inbox_item.recipient_address;
+    if accs.recipient.key()
+        != get_associated_token_address_with_program_id(
+            &recipient_address,
+            &accs.mint.key(),
+            &accs.token_program.key(),
+        )
+    {
+        return Err(ErrorCode::ConstraintAssociated.into());
+    }
+
+    let (registered_receiver, _) = Pubkey::find_program_address(
+        &[
+            RegisteredReceiver::SEED_PREFIX,
+            recipient_address.as_ref(),
+            accs.config.mint_seed(),
+        ],
+        &crate::ID,
+    );
+    if accs.registered_receiver.key() != registered_receiver {
+        return Err(ErrorCode::ConstraintSeeds.into());
+    }
+    if !accs.registered_receiver.data_is_empty() {
+        return Err(NTTError::ReleaseRequiresCall.into());
+    }
+
+    let rate_limits = accs.config.capacity_aware_release.then_some((
+        &mut accs.inbox_rate_limit.rate_limit,
+        &mut accs.outbox_rate_limit.rate_limit,
+    ));
+    if !accs.inbox_item.try_release(rate_limits)? {
+        return Ok(());
+    }
+
+    let release = TokenRelease {
+        config: &accs.config,
+        mint: &accs.mint,
+        custody: &accs.custody,
+        recipient: &accs.recipient,
+        token_authority: &accs.token_authority,
+        token_authority_bump: ctx.bumps.token_authority,
+        token_program: &accs.token_program,
+    };
+    match accs.config.mode {
+        Mode::Burning => release.mint_to_recipient(accs.inbox_item.amount, ctx.remaining_accounts),
+        Mode::Locking => {
+            release.unlock_to_recipient(accs.inbox_item.amount, ctx.remaining_accounts)?;
+            let amount = accs.inbox_item.amount;
+            CustodyLedger::record_if_initialized(&accs.custody_ledger, |ledger| {
+                ledger.record_inbound(amount)
+            })
+        }
+    }
+}
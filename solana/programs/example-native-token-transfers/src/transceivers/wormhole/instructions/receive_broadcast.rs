@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use ntt_messages::{
+    chain_id::ChainId,
+    transceivers::wormhole::{WormholeTransceiverInfo, WormholeTransceiverRegistration},
+};
+
+use crate::{
+    config::*,
+    error::NTTError,
+    transceivers::{
+        accounts::pending_peer::{PendingPeer, PendingPeerInfo},
+        wormhole::vaa_account::VaaAccount,
+    },
+};
+
+/// Records the broadcasts of a transceiver on another chain (see
+/// [`crate::transceivers::wormhole::instructions::broadcast_id`] and
+/// [`crate::transceivers::wormhole::instructions::broadcast_peer`] for the
+/// sending side) in a [`PendingPeer`], so the owner can review and accept it.
+#[derive(Accounts)]
+pub struct ReceiveBroadcast<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub config: Account<'info, Config>,
+
+    // NOTE: like in [`crate::transceivers::wormhole::instructions::receive_message`],
+    // VAAs are not replay protected. Replaying a broadcast just records the
+    // same claim again.
+    /// CHECK: either a legacy posted VAA or a verified encoded VAA. The owner
+    /// (the core bridge) and the layout are checked by [`VaaAccount::load`].
+    #[account(
+        constraint = VaaAccount::load(&vaa)?.emitter_chain() != config.chain_id.id @ NTTError::InvalidChainId,
+    )]
+    pub vaa: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PendingPeer::INIT_SPACE,
+        seeds = [
+            PendingPeer::SEED_PREFIX,
+            VaaAccount::load(&vaa)?.emitter_chain().to_be_bytes().as_ref(),
+            VaaAccount::load(&vaa)?.emitter_address().as_ref(),
+        ],
+        bump,
+    )]
+    pub pending_peer: Account<'info, PendingPeer>,
+
+    #[account(
+        seeds = [b"emitter"],
+        bump
+    )]
+    /// CHECK: The seeds constraint ensures that this is the correct address
+    pub emitter: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReceiveBroadcast<'info> {
+    fn init_pending_peer(&mut self, bump: u8) -> Result<()> {
+        let vaa = VaaAccount::load(&self.vaa)?;
+        self.pending_peer.bump = bump;
+        self.pending_peer.chain_id = ChainId {
+            id: vaa.emitter_chain(),
+        };
+        self.pending_peer.transceiver_address = vaa.emitter_address();
+        Ok(())
+    }
+}
+
+pub fn receive_transceiver_info(ctx: Context<ReceiveBroadcast>) -> Result<()> {
+    let accs = ctx.accounts;
+    accs.init_pending_peer(ctx.bumps.pending_peer)?;
+
+    let info: WormholeTransceiverInfo = VaaAccount::load(&accs.vaa)?.message()?;
+    accs.pending_peer.info = Some(PendingPeerInfo {
+        manager_address: info.manager_address,
+        manager_mode: info.manager_mode,
+        token_address: info.token_address,
+        token_decimals: info.token_decimals,
+    });
+
+    Ok(())
+}
+
+pub fn receive_transceiver_registration(ctx: Context<ReceiveBroadcast>) -> Result<()> {
+    let accs = ctx.accounts;
+    accs.init_pending_peer(ctx.bumps.pending_peer)?;
+
+    let registration: WormholeTransceiverRegistration = VaaAccount::load(&accs.vaa)?.message()?;
+
+    // registrations of other chains' transceivers are of no interest here
+    if registration.chain_id != accs.config.chain_id {
+        return Err(NTTError::InvalidChainId.into());
+    }
+    if registration.transceiver_address != accs.emitter.key().to_bytes() {
+        return Err(NTTError::InvalidTransceiverPeer.into());
+    }
+
+    accs.pending_peer.registered = true;
+
+    Ok(())
+}
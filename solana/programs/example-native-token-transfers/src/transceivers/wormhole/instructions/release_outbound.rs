@@ -6,8 +6,18 @@ use ntt_messages::{
 };
 
 use crate::{
-    config::*, error::NTTError, queue::outbox::OutboxItem, registered_transceiver::*,
-    transceivers::wormhole::accounts::*, transfer::Payload,
+    config::*,
+    error::NTTError,
+    queue::{
+        inbox::InboxRateLimit,
+        outbox::{OutboxItem, OutboxRateLimit},
+    },
+    registered_transceiver::*,
+    transceivers::{
+        accounts::{approved_relayer::ApprovedRelayer, peer::TransceiverPeer},
+        wormhole::accounts::*,
+    },
+    transfer::Payload,
 };
 
 #[derive(Accounts)]
@@ -20,15 +30,48 @@ pub struct ReleaseOutbound<'info> {
     #[account(
         mut,
         constraint = !outbox_item.released.get(transceiver.id)? @ NTTError::MessageAlreadySent,
+        constraint = outbox_item.mint == config.mint @ NTTError::InvalidMint,
     )]
     pub outbox_item: Account<'info, OutboxItem>,
 
     #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            outbox_item.recipient_chain.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = inbox_rate_limit.bump,
+    )]
+    /// Refilled (backflow) when a queued transfer consumes its deferred
+    /// capacity. See [`OutboxItem::try_release`].
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [RegisteredTransceiver::SEED_PREFIX, transceiver.transceiver_address.as_ref(), config.mint_seed()],
+        bump = transceiver.bump,
         constraint = transceiver.transceiver_address == crate::ID,
         constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
     )]
     pub transceiver: Account<'info, RegisteredTransceiver>,
 
+    #[account(
+        seeds = [
+            TransceiverPeer::SEED_PREFIX,
+            outbox_item.recipient_chain.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, TransceiverPeer>,
+
     #[account(
         mut,
         seeds = [b"message", outbox_item.key().as_ref()],
@@ -55,35 +98,18 @@ pub struct ReleaseOutboundArgs {
 
 pub fn release_outbound(ctx: Context<ReleaseOutbound>, args: ReleaseOutboundArgs) -> Result<()> {
     let accs = ctx.accounts;
-    let released = accs.outbox_item.try_release(accs.transceiver.id)?;
-
-    if !released {
-        if args.revert_on_delay {
-            return Err(NTTError::CantReleaseYet.into());
-        } else {
-            return Ok(());
-        }
-    }
 
-    assert!(accs.outbox_item.released.get(accs.transceiver.id)?);
-    let message: TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>> =
-        TransceiverMessage::new(
-            // TODO: should we just put the ntt id here statically?
-            accs.outbox_item.to_account_info().owner.to_bytes(),
-            accs.outbox_item.recipient_ntt_manager,
-            NttManagerMessage {
-                id: accs.outbox_item.key().to_bytes(),
-                sender: accs.outbox_item.sender.to_bytes(),
-                payload: NativeTokenTransfer {
-                    amount: accs.outbox_item.amount,
-                    source_token: accs.config.mint.to_bytes(),
-                    to: accs.outbox_item.recipient_address,
-                    to_chain: accs.outbox_item.recipient_chain,
-                    additional_payload: Payload {},
-                },
-            },
-            vec![],
-        );
+    let Some(message) = try_release(
+        &accs.config,
+        &mut accs.outbox_item,
+        &mut accs.outbox_rate_limit,
+        &mut accs.inbox_rate_limit,
+        &accs.transceiver,
+        args.revert_on_delay,
+    )?
+    else {
+        return Ok(());
+    };
 
     post_message(
         &accs.wormhole,
@@ -92,6 +118,7 @@ pub fn release_outbound(ctx: Context<ReleaseOutbound>, args: ReleaseOutboundArgs
         accs.emitter.to_account_info(),
         ctx.bumps.emitter,
         &message,
+        accs.peer.finality.unwrap_or(accs.config.wormhole_finality),
         &[&[
             b"message",
             accs.outbox_item.key().as_ref(),
@@ -101,3 +128,198 @@ pub fn release_outbound(ctx: Context<ReleaseOutbound>, args: ReleaseOutboundArgs
 
     Ok(())
 }
+
+pub const REUSABLE_MESSAGE_SEED: &[u8] = b"reusable_message";
+
+/// Same as [`ReleaseOutbound`], except that the message is posted into a
+/// reusable account owned by the payer, instead of a fresh one per outbox
+/// item. See [`post_message_unreliable`] for the trade-offs.
+///
+/// SECURITY: the payer can overwrite the message before the guardians observe
+/// it, after which the transfer can neither be released again nor reclaimed.
+/// So only the sender of the transfer, or a relayer approved by the owner (see
+/// [`ApprovedRelayer`]), may release it this way.
+#[derive(Accounts)]
+#[instruction(args: ReleaseOutboundReusableArgs)]
+pub struct ReleaseOutboundReusable<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub config: NotPausedConfig<'info>,
+
+    #[account(
+        mut,
+        constraint = !outbox_item.released.get(transceiver.id)? @ NTTError::MessageAlreadySent,
+        constraint = outbox_item.mint == config.mint @ NTTError::InvalidMint,
+    )]
+    pub outbox_item: Account<'info, OutboxItem>,
+
+    #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            outbox_item.recipient_chain.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = inbox_rate_limit.bump,
+    )]
+    /// Refilled (backflow) when a queued transfer consumes its deferred
+    /// capacity. See [`OutboxItem::try_release`].
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [RegisteredTransceiver::SEED_PREFIX, transceiver.transceiver_address.as_ref(), config.mint_seed()],
+        bump = transceiver.bump,
+        constraint = transceiver.transceiver_address == crate::ID,
+        constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
+    )]
+    pub transceiver: Account<'info, RegisteredTransceiver>,
+
+    #[account(
+        seeds = [
+            TransceiverPeer::SEED_PREFIX,
+            outbox_item.recipient_chain.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, TransceiverPeer>,
+
+    #[account(
+        mut,
+        seeds = [
+            REUSABLE_MESSAGE_SEED,
+            payer.key().as_ref(),
+            args.message_index.to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    /// CHECK: initialized and (re)written to by wormhole core bridge
+    pub wormhole_message: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"emitter"],
+        bump
+    )]
+    /// CHECK: wormhole uses this as the emitter address
+    pub emitter: UncheckedAccount<'info>,
+
+    pub wormhole: WormholeAccounts<'info>,
+
+    #[account(
+        seeds = [
+            ApprovedRelayer::SEED_PREFIX,
+            payer.key().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = approved_relayer.bump,
+    )]
+    /// Required unless the payer is the sender of the outbox item.
+    pub approved_relayer: Option<Account<'info, ApprovedRelayer>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReleaseOutboundReusableArgs {
+    pub revert_on_delay: bool,
+    /// Selects which of the payer's reusable message accounts to post into.
+    /// Relayers that release several transfers before the guardians observe
+    /// them should rotate through different indices.
+    pub message_index: u16,
+}
+
+pub fn release_outbound_reusable(
+    ctx: Context<ReleaseOutboundReusable>,
+    args: ReleaseOutboundReusableArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+
+    if accs.payer.key() != accs.outbox_item.sender && accs.approved_relayer.is_none() {
+        return Err(NTTError::UnapprovedRelayer.into());
+    }
+
+    let Some(message) = try_release(
+        &accs.config,
+        &mut accs.outbox_item,
+        &mut accs.outbox_rate_limit,
+        &mut accs.inbox_rate_limit,
+        &accs.transceiver,
+        args.revert_on_delay,
+    )?
+    else {
+        return Ok(());
+    };
+
+    post_message_unreliable(
+        &accs.wormhole,
+        accs.payer.to_account_info(),
+        accs.wormhole_message.to_account_info(),
+        accs.emitter.to_account_info(),
+        ctx.bumps.emitter,
+        &message,
+        accs.peer.finality.unwrap_or(accs.config.wormhole_finality),
+        &[&[
+            REUSABLE_MESSAGE_SEED,
+            accs.payer.key().as_ref(),
+            args.message_index.to_be_bytes().as_ref(),
+            &[ctx.bumps.wormhole_message],
+        ]],
+    )?;
+
+    Ok(())
+}
+
+/// Marks the outbox item as released by this transceiver, and returns the
+/// message to post. Returns `None` if the item can't be released yet (and
+/// `revert_on_delay` is not set).
+fn try_release(
+    config: &Config,
+    outbox_item: &mut Account<OutboxItem>,
+    outbox_rate_limit: &mut OutboxRateLimit,
+    inbox_rate_limit: &mut InboxRateLimit,
+    transceiver: &RegisteredTransceiver,
+    revert_on_delay: bool,
+) -> Result<Option<TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>>>> {
+    let queued = outbox_item.deferred_capacity;
+    let rate_limits = config.capacity_aware_release.then_some((
+        &mut outbox_rate_limit.rate_limit,
+        &mut inbox_rate_limit.rate_limit,
+    ));
+    let released = outbox_item.try_release(transceiver.id, rate_limits)?;
+    if released {
+        outbox_rate_limit.dequeue(queued);
+    }
+
+    if !released {
+        if revert_on_delay {
+            return Err(NTTError::CantReleaseYet.into());
+        } else {
+            return Ok(None);
+        }
+    }
+
+    assert!(outbox_item.released.get(transceiver.id)?);
+    Ok(Some(TransceiverMessage::new(
+        // TODO: should we just put the ntt id here statically?
+        outbox_item.to_account_info().owner.to_bytes(),
+        outbox_item.recipient_ntt_manager,
+        NttManagerMessage {
+            id: outbox_item.key().to_bytes(),
+            sender: outbox_item.sender.to_bytes(),
+            payload: NativeTokenTransfer {
+                amount: outbox_item.amount,
+                source_token: config.mint.to_bytes(),
+                to: outbox_item.recipient_address,
+                to_chain: outbox_item.recipient_chain,
+                additional_payload: Payload::default(),
+            },
+        },
+        vec![],
+    )))
+}
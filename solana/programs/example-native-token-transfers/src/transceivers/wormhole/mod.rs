@@ -1,4 +1,5 @@
 pub mod accounts;
 pub mod instructions;
+pub mod vaa_account;
 
 pub use instructions::*;
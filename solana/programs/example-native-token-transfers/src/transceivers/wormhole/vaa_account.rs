@@ -0,0 +1,194 @@
+//! Zero-copy reader for VAA accounts owned by the Wormhole core bridge.
+//!
+//! Two kinds of accounts are supported:
+//! - the legacy `PostedVAA` accounts, created by `post_vaa`, and
+//! - `EncodedVaa` accounts, created by `init_encoded_vaa`, `write_encoded_vaa`
+//!   and `verify_encoded_vaa_v1`. These are written in chunks, so (unlike
+//!   posted VAAs) their size is not bounded by the size of a transaction.
+//!
+//! Only the fields that the transceiver needs are exposed, and the payload is
+//! borrowed from the account data, so the reader doesn't commit to a message
+//! type. See [`VaaAccount::transceiver_message`] for reading the payload as a
+//! transceiver message from a peer.
+
+use std::cell::Ref;
+
+use anchor_lang::prelude::*;
+use ntt_messages::{
+    chain_id::ChainId, transceiver::TransceiverMessage,
+    transceivers::wormhole::WormholeTransceiver, utils::maybe_space::MaybeSpace,
+};
+use wormhole_anchor_sdk::wormhole;
+use wormhole_io::TypePrefixedPayload;
+
+use crate::error::NTTError;
+
+/// Account data prefix of legacy posted VAAs.
+const POSTED_VAA_PREFIX: &[u8] = b"vaa";
+
+/// Offset of the emitter chain in a posted VAA. The layout is:
+/// prefix (3), vaa_version (1), consistency_level (1), vaa_time (4),
+/// vaa_signature_account (32), submission_time (4), nonce (4), sequence (8),
+/// emitter_chain (2, LE), emitter_address (32), payload (4 byte LE length + data).
+const POSTED_VAA_EMITTER_CHAIN_OFFSET: usize = 57;
+
+/// Anchor discriminator of `EncodedVaa` accounts.
+const ENCODED_VAA_DISCRIMINATOR: [u8; 8] = [226, 101, 163, 4, 133, 160, 84, 245];
+
+/// `ProcessingStatus::Verified` of encoded VAAs.
+const ENCODED_VAA_STATUS_VERIFIED: u8 = 2;
+
+/// Offset of the VAA bytes in an encoded VAA. The layout is:
+/// discriminator (8), status (1), write_authority (32), version (1),
+/// buf (4 byte LE length + data).
+const ENCODED_VAA_BUF_OFFSET: usize = 46;
+
+/// Length of a guardian signature in a VAA.
+const GUARDIAN_SIGNATURE_LEN: usize = 66;
+
+/// Length of the VAA body before the payload: timestamp (4), nonce (4),
+/// emitter_chain (2), emitter_address (32), sequence (8), consistency_level (1).
+const VAA_BODY_HEADER_LEN: usize = 51;
+
+enum Layout {
+    Posted,
+    /// The offset of the VAA body in the account data.
+    Encoded {
+        body: usize,
+    },
+}
+
+pub struct VaaAccount<'a, 'info> {
+    data: Ref<'a, &'info mut [u8]>,
+    layout: Layout,
+}
+
+impl<'a, 'info> VaaAccount<'a, 'info> {
+    /// Loads a VAA account. Encoded VAAs have to be verified.
+    pub fn load(info: &'a AccountInfo<'info>) -> Result<Self> {
+        if *info.owner != wormhole::program::ID {
+            return Err(Error::from(ErrorCode::AccountOwnedByWrongProgram)
+                .with_pubkeys((*info.owner, wormhole::program::ID)));
+        }
+
+        let data = info.try_borrow_data()?;
+
+        let layout = if data.starts_with(POSTED_VAA_PREFIX) {
+            let payload_len = read_u32_le(&data, POSTED_VAA_EMITTER_CHAIN_OFFSET + 34)?;
+            if data.len() < POSTED_VAA_EMITTER_CHAIN_OFFSET + 38 + payload_len {
+                return Err(NTTError::InvalidVaaAccount.into());
+            }
+            Layout::Posted
+        } else if data.starts_with(&ENCODED_VAA_DISCRIMINATOR) {
+            if data.get(8) != Some(&ENCODED_VAA_STATUS_VERIFIED) {
+                return Err(NTTError::InvalidVaaAccount.into());
+            }
+            // The VAA itself starts with its version (1), guardian set index
+            // (4), and the number of signatures (1), followed by the signatures.
+            let num_signatures = *data
+                .get(ENCODED_VAA_BUF_OFFSET + 5)
+                .ok_or(NTTError::InvalidVaaAccount)?;
+            let body =
+                ENCODED_VAA_BUF_OFFSET + 6 + usize::from(num_signatures) * GUARDIAN_SIGNATURE_LEN;
+            let end = ENCODED_VAA_BUF_OFFSET + read_u32_le(&data, ENCODED_VAA_BUF_OFFSET - 4)?;
+            if data.len() < end || end < body + VAA_BODY_HEADER_LEN {
+                return Err(NTTError::InvalidVaaAccount.into());
+            }
+            Layout::Encoded { body }
+        } else {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        };
+
+        Ok(Self { data, layout })
+    }
+
+    pub fn emitter_chain(&self) -> u16 {
+        match self.layout {
+            Layout::Posted => u16::from_le_bytes(
+                self.data[POSTED_VAA_EMITTER_CHAIN_OFFSET..POSTED_VAA_EMITTER_CHAIN_OFFSET + 2]
+                    .try_into()
+                    .unwrap(),
+            ),
+            Layout::Encoded { body } => {
+                u16::from_be_bytes(self.data[body + 8..body + 10].try_into().unwrap())
+            }
+        }
+    }
+
+    pub fn emitter_address(&self) -> [u8; 32] {
+        let offset = match self.layout {
+            Layout::Posted => POSTED_VAA_EMITTER_CHAIN_OFFSET + 2,
+            Layout::Encoded { body } => body + 10,
+        };
+        self.data[offset..offset + 32].try_into().unwrap()
+    }
+
+    pub fn sequence(&self) -> u64 {
+        match self.layout {
+            Layout::Posted => u64::from_le_bytes(
+                self.data[POSTED_VAA_EMITTER_CHAIN_OFFSET - 8..POSTED_VAA_EMITTER_CHAIN_OFFSET]
+                    .try_into()
+                    .unwrap(),
+            ),
+            Layout::Encoded { body } => {
+                u64::from_be_bytes(self.data[body + 42..body + 50].try_into().unwrap())
+            }
+        }
+    }
+
+    pub fn payload(&self) -> &[u8] {
+        match self.layout {
+            Layout::Posted => {
+                let start = POSTED_VAA_EMITTER_CHAIN_OFFSET + 38;
+                // NOTE: the length was checked in [`VaaAccount::load`]
+                let len = read_u32_le(&self.data, POSTED_VAA_EMITTER_CHAIN_OFFSET + 34).unwrap();
+                &self.data[start..start + len]
+            }
+            Layout::Encoded { body } => {
+                // The payload fills the rest of the buffer.
+                // NOTE: the length was checked in [`VaaAccount::load`]
+                let len = read_u32_le(&self.data, ENCODED_VAA_BUF_OFFSET - 4).unwrap();
+                &self.data[body + VAA_BODY_HEADER_LEN..ENCODED_VAA_BUF_OFFSET + len]
+            }
+        }
+    }
+
+    /// Deserializes the payload as `M`.
+    pub fn message<M: AnchorDeserialize>(&self) -> Result<M> {
+        let mut payload = self.payload();
+        M::deserialize(&mut payload).map_err(|_| NTTError::InvalidVaaAccount.into())
+    }
+
+    /// Deserializes the payload as a transceiver message carrying an `A`,
+    /// after checking that the VAA was emitted by `peer_address` on `chain_id`.
+    ///
+    /// The receiving instructions take the emitter chain as an argument, so
+    /// that their accounts can be derived without loading the VAA in the
+    /// account constraints. This ties the argument (and the peer, which is
+    /// derived from it) back to the VAA.
+    pub fn transceiver_message<A>(
+        &self,
+        chain_id: ChainId,
+        peer_address: [u8; 32],
+    ) -> Result<TransceiverMessage<WormholeTransceiver, A>>
+    where
+        A: TypePrefixedPayload + MaybeSpace,
+    {
+        if self.emitter_chain() != chain_id.id {
+            return Err(ErrorCode::ConstraintSeeds.into());
+        }
+
+        if self.emitter_address() != peer_address {
+            return Err(NTTError::InvalidTransceiverPeer.into());
+        }
+
+        self.message()
+    }
+}
+
+fn read_u32_le(data: &[u8], offset: usize) -> Result<usize> {
+    let bytes = data
+        .get(offset..offset + 4)
+        .ok_or(NTTError::InvalidVaaAccount)?;
+    Ok(u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+}
@@ -1,3 +1,3 @@
-use ntt_messages::ntt::EmptyPayload;
+use ntt_messages::ntt::RawPayload;
 
-pub type Payload = EmptyPayload;
+pub type Payload = RawPayload;
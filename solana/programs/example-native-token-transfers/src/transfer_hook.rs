@@ -0,0 +1,175 @@
+//! Transfer hook account resolution.
+//!
+//! When the mint has a transfer hook, every `transfer_checked` made by NTT
+//! (see [`crate::instructions::transfer_burn`],
+//! [`crate::instructions::transfer_lock`] and the release instructions) needs
+//! the hook's extra accounts, which are passed in as remaining accounts. They
+//! are described by the hook's `ExtraAccountMetaList` (the "validation
+//! account"), and can be resolved with
+//! [`crate::instructions::get_transfer_hook_accounts`].
+//!
+//! [`check_transfer_hook_accounts`] is called before each transfer, so that
+//! missing accounts are reported as [`NTTError::InvalidTransferHookAccounts`]
+//! instead of failing somewhere inside the token program.
+
+use std::cell::Ref;
+
+use anchor_lang::prelude::*;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_token_2022::{extension::StateWithExtensions, state::Mint};
+use spl_transfer_hook_interface::{
+    get_extra_account_metas_address,
+    instruction::{ExecuteInstruction, TransferHookInstruction},
+};
+use spl_type_length_value::state::TlvStateBorrowed;
+
+use crate::error::NTTError;
+
+/// An account that has to be passed to the token program for the transfer
+/// hook to be invoked.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TransferHookAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl From<AccountMeta> for TransferHookAccount {
+    fn from(meta: AccountMeta) -> Self {
+        Self {
+            pubkey: meta.pubkey,
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        }
+    }
+}
+
+/// The transfer hook program of `mint`, if any.
+pub fn transfer_hook_program(mint: &AccountInfo) -> Result<Option<Pubkey>> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(None);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    Ok(spl_token_2022::extension::transfer_hook::get_program_id(
+        &mint,
+    ))
+}
+
+/// Resolves the accounts the token program needs to invoke the transfer hook
+/// of `mint` when transferring `amount` from `source` to `destination`: the
+/// extra accounts, followed by the validation account and the hook program.
+/// Returns an empty list if the mint has no transfer hook.
+///
+/// The validation account has to be in `remaining_accounts`. Extra accounts
+/// that are derived from the data of other extra accounts can only be
+/// resolved if those are in `remaining_accounts` too.
+pub fn resolve_transfer_hook_accounts<'info>(
+    mint: &AccountInfo<'info>,
+    source: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<Vec<TransferHookAccount>> {
+    let Some(program_id) = transfer_hook_program(mint)? else {
+        return Ok(vec![]);
+    };
+
+    let validation_address = get_extra_account_metas_address(mint.key, &program_id);
+    let find = |pubkey: &Pubkey| remaining_accounts.iter().find(|info| info.key == pubkey);
+    let validation = find(&validation_address).ok_or_else(|| {
+        msg!(
+            "Missing transfer hook validation account {}",
+            validation_address
+        );
+        NTTError::InvalidTransferHookAccounts
+    })?;
+
+    let mut accounts = vec![];
+
+    // a hook without extra accounts doesn't need to initialise the validation
+    // account
+    if !validation.data_is_empty() {
+        let validation_data = validation.try_borrow_data()?;
+        let tlv_state = TlvStateBorrowed::unpack(&validation_data)?;
+        let extra_account_metas =
+            ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&tlv_state)?;
+        let instruction_data = TransferHookInstruction::Execute { amount }.pack();
+
+        // the accounts of the hook's execute instruction, which extra accounts
+        // may refer to (by index)
+        let mut keys = vec![
+            *source.key,
+            *mint.key,
+            *destination.key,
+            *authority.key,
+            validation_address,
+        ];
+        let mut infos = vec![
+            Some(source),
+            Some(mint),
+            Some(destination),
+            Some(authority),
+            Some(validation),
+        ];
+
+        for extra_account_meta in extra_account_metas.data() {
+            let data = infos
+                .iter()
+                .map(|info| info.and_then(|info| info.try_borrow_data().ok()))
+                .collect::<Vec<Option<Ref<&mut [u8]>>>>();
+
+            let meta = extra_account_meta.resolve(&instruction_data, &program_id, |index| {
+                keys.get(index)
+                    .map(|key| (key, data[index].as_deref().map(|data| &**data)))
+            })?;
+            drop(data);
+
+            keys.push(meta.pubkey);
+            infos.push(find(&meta.pubkey));
+            accounts.push(meta.into());
+        }
+    }
+
+    accounts.push(AccountMeta::new_readonly(validation_address, false).into());
+    accounts.push(AccountMeta::new_readonly(program_id, false).into());
+
+    Ok(accounts)
+}
+
+/// Checks that `remaining_accounts` contain all the accounts needed to invoke
+/// the transfer hook of `mint` (see [`resolve_transfer_hook_accounts`]), with
+/// the right privileges.
+pub fn check_transfer_hook_accounts<'info>(
+    mint: &AccountInfo<'info>,
+    source: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let required = resolve_transfer_hook_accounts(
+        mint,
+        source,
+        destination,
+        authority,
+        amount,
+        remaining_accounts,
+    )?;
+
+    for account in required {
+        let valid = remaining_accounts.iter().any(|info| {
+            *info.key == account.pubkey
+                && (info.is_writable || !account.is_writable)
+                && (info.is_signer || !account.is_signer)
+        });
+        if !valid {
+            msg!("Missing transfer hook account {}", account.pubkey);
+            return Err(NTTError::InvalidTransferHookAccounts.into());
+        }
+    }
+
+    Ok(())
+}
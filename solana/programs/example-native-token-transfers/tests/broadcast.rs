@@ -1,19 +1,44 @@
 #![cfg(feature = "test-sbf")]
 #![feature(type_changing_struct_update)]
 
-use common::setup::OTHER_CHAIN;
+use anchor_lang::prelude::{AnchorSerialize, Pubkey};
+use common::setup::{TestData, OTHER_CHAIN, THIS_CHAIN};
+use example_native_token_transfers::{
+    error::NTTError,
+    peer::NttManagerPeer,
+    transceivers::{
+        accounts::{
+            peer::TransceiverPeer,
+            pending_peer::{PendingPeer, PendingPeerInfo},
+        },
+        wormhole::AcceptPendingPeerArgs,
+    },
+};
 use ntt_messages::chain_id::ChainId;
 use ntt_messages::mode::Mode;
 use ntt_messages::transceivers::wormhole::{
     WormholeTransceiverInfo, WormholeTransceiverRegistration,
 };
 use solana_program_test::*;
-use solana_sdk::{signature::Keypair, signer::Signer};
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError,
+};
 use wormhole_anchor_sdk::wormhole::PostedVaa;
+use wormhole_sdk::{Address, Vaa};
 
 use crate::common::query::GetAccountDataAnchor;
 use crate::common::setup::{setup, OTHER_TRANSCEIVER};
 use crate::sdk::transceivers::wormhole::instructions::broadcast_id::{broadcast_id, BroadcastId};
+use crate::sdk::{
+    instructions::post_vaa::post_vaa,
+    transceivers::wormhole::instructions::{
+        admin::{accept_pending_peer, AcceptPendingPeer},
+        receive_broadcast::{
+            receive_transceiver_info, receive_transceiver_registration, ReceiveBroadcast,
+        },
+    },
+};
 use crate::{
     common::submit::Submittable,
     sdk::transceivers::wormhole::instructions::broadcast_peer::{broadcast_peer, BroadcastPeer},
@@ -85,3 +110,234 @@ async fn test_broadcast_id() {
         }
     );
 }
+
+const NEW_CHAIN: u16 = 3;
+const NEW_TRANSCEIVER: [u8; 32] = [8u8; 32];
+
+async fn post_broadcast_vaa<A: AnchorSerialize + Clone>(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    payload: A,
+) -> Pubkey {
+    let vaa = Vaa {
+        version: 1,
+        guardian_set_index: 0,
+        signatures: vec![],
+        timestamp: 123232,
+        nonce: 0,
+        emitter_chain: NEW_CHAIN.into(),
+        emitter_address: Address(NEW_TRANSCEIVER),
+        sequence: 0,
+        consistency_level: 0,
+        payload,
+    };
+
+    post_vaa(&test_data.ntt.wormhole, ctx, vaa).await
+}
+
+async fn receive_info(ctx: &mut ProgramTestContext, test_data: &TestData, manager_mode: Mode) {
+    let vaa = post_broadcast_vaa(
+        ctx,
+        test_data,
+        WormholeTransceiverInfo {
+            manager_address: [5u8; 32],
+            manager_mode,
+            token_address: [6u8; 32],
+            token_decimals: 18,
+        },
+    )
+    .await;
+
+    receive_transceiver_info(
+        &test_data.ntt,
+        ReceiveBroadcast {
+            payer: ctx.payer.pubkey(),
+            vaa,
+            chain_id: NEW_CHAIN,
+            transceiver_address: NEW_TRANSCEIVER,
+        },
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+}
+
+async fn receive_registration(ctx: &mut ProgramTestContext, test_data: &TestData) {
+    let vaa = post_broadcast_vaa(
+        ctx,
+        test_data,
+        WormholeTransceiverRegistration {
+            chain_id: ChainId { id: THIS_CHAIN },
+            transceiver_address: test_data.ntt.emitter().to_bytes(),
+        },
+    )
+    .await;
+
+    receive_transceiver_registration(
+        &test_data.ntt,
+        ReceiveBroadcast {
+            payer: ctx.payer.pubkey(),
+            vaa,
+            chain_id: NEW_CHAIN,
+            transceiver_address: NEW_TRANSCEIVER,
+        },
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+}
+
+fn accept_args(allow_mismatch: bool) -> AcceptPendingPeerArgs {
+    AcceptPendingPeerArgs {
+        chain_id: ChainId { id: NEW_CHAIN },
+        transceiver_address: NEW_TRANSCEIVER,
+        limit: 1000,
+        allow_mismatch,
+    }
+}
+
+#[tokio::test]
+async fn test_receive_broadcasts_and_accept_pending_peer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    receive_info(&mut ctx, &test_data, Mode::Burning).await;
+    receive_registration(&mut ctx, &test_data).await;
+
+    let pending_peer: PendingPeer = ctx
+        .get_account_data_anchor(test_data.ntt.pending_peer(NEW_CHAIN, &NEW_TRANSCEIVER))
+        .await;
+
+    assert_eq!(pending_peer.chain_id, ChainId { id: NEW_CHAIN });
+    assert_eq!(pending_peer.transceiver_address, NEW_TRANSCEIVER);
+    assert!(pending_peer.registered);
+    assert_eq!(
+        pending_peer.info,
+        Some(PendingPeerInfo {
+            manager_address: [5u8; 32],
+            manager_mode: Mode::Burning,
+            token_address: [6u8; 32],
+            token_decimals: 18,
+        })
+    );
+
+    accept_pending_peer(
+        &test_data.ntt,
+        AcceptPendingPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        accept_args(false),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(test_data.ntt.peer(NEW_CHAIN))
+        .await;
+    assert_eq!(peer.address, [5u8; 32]);
+    assert_eq!(peer.token_decimals, 18);
+    assert_eq!(peer.token_address, [6u8; 32]);
+
+    let transceiver_peer: TransceiverPeer = ctx
+        .get_account_data_anchor(test_data.ntt.transceiver_peer(NEW_CHAIN))
+        .await;
+    assert_eq!(transceiver_peer.address, NEW_TRANSCEIVER);
+
+    // the pending peer is closed
+    assert!(ctx
+        .banks_client
+        .get_account(test_data.ntt.pending_peer(NEW_CHAIN, &NEW_TRANSCEIVER))
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_accept_pending_peer_mismatch() {
+    // both sides locking
+    assert_accept_requires_allow_mismatch(Mode::Locking, true).await;
+}
+
+#[tokio::test]
+async fn test_accept_unregistered_pending_peer() {
+    // the peer hasn't registered this transceiver
+    assert_accept_requires_allow_mismatch(Mode::Burning, false).await;
+}
+
+async fn assert_accept_requires_allow_mismatch(manager_mode: Mode, registered: bool) {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    receive_info(&mut ctx, &test_data, manager_mode).await;
+    if registered {
+        receive_registration(&mut ctx, &test_data).await;
+    }
+
+    let err = accept_pending_peer(
+        &test_data.ntt,
+        AcceptPendingPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        accept_args(false),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::PendingPeerMismatch.into())
+        )
+    );
+
+    accept_pending_peer(
+        &test_data.ntt,
+        AcceptPendingPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        accept_args(true),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_receive_registration_of_other_transceiver() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let vaa = post_broadcast_vaa(
+        &mut ctx,
+        &test_data,
+        WormholeTransceiverRegistration {
+            chain_id: ChainId { id: THIS_CHAIN },
+            transceiver_address: [1u8; 32],
+        },
+    )
+    .await;
+
+    let err = receive_transceiver_registration(
+        &test_data.ntt,
+        ReceiveBroadcast {
+            payer: ctx.payer.pubkey(),
+            vaa,
+            chain_id: NEW_CHAIN,
+            transceiver_address: NEW_TRANSCEIVER,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidTransceiverPeer.into())
+        )
+    );
+}
@@ -2,11 +2,21 @@
 #![feature(type_changing_struct_update)]
 
 use anchor_lang::prelude::*;
-use common::setup::{TestData, OTHER_CHAIN, OTHER_MANAGER, OTHER_TRANSCEIVER, THIS_CHAIN};
+use anchor_spl::token::TokenAccount;
+use common::setup::{
+    TestData, INBOUND_LIMIT, OTHER_CHAIN, OTHER_MANAGER, OTHER_TOKEN, OTHER_TRANSCEIVER, THIS_CHAIN,
+};
 use example_native_token_transfers::{
     error::NTTError,
-    instructions::{RedeemArgs, TransferArgs},
-    queue::{inbox::InboxRateLimit, outbox::OutboxRateLimit},
+    instructions::{
+        RedeemArgs, ReleaseInboundArgs, SetPeerArgs, SetSettlementDelaysArgs, TransferArgs,
+    },
+    peer::SettlementDelayTier,
+    queue::{
+        inbox::{InboxItem, InboxRateLimit, ReleaseStatus},
+        outbox::OutboxRateLimit,
+    },
+    transceivers::wormhole::ReceiveAndRedeemArgs,
     transfer::Payload,
 };
 use ntt_messages::{
@@ -27,12 +37,22 @@ use crate::{
 use crate::{
     common::{query::GetAccountDataAnchor, setup::setup},
     sdk::{
+        accounts::message_hash,
         instructions::{
-            post_vaa::post_vaa,
+            admin::{
+                deregister_receiver, expedite_inbox_item, register_receiver, set_inbox_item_frozen,
+                set_operator, set_peer, set_settlement_delays, DeregisterReceiver,
+                OverrideInboxItem, RegisterReceiver, SetOperator, SetPeer, SetSettlementDelays,
+            },
+            post_vaa::{post_vaa, write_encoded_vaa},
             redeem::{redeem, Redeem},
+            release_inbound::{release_inbound, release_inbound_and_call, ReleaseInbound},
             transfer::Transfer,
         },
-        transceivers::wormhole::instructions::receive_message::receive_message,
+        transceivers::wormhole::instructions::{
+            receive_and_redeem::{receive_and_redeem, ReceiveAndRedeem},
+            receive_message::receive_message,
+        },
     },
 };
 
@@ -97,20 +117,19 @@ fn init_receive_message_accs(
         vaa,
         chain_id,
         id,
+        additional_payload_len: 0,
     }
 }
 
-async fn post_transfer_vaa(
-    ctx: &mut ProgramTestContext,
+type TransferVaa = Vaa<TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>>>;
+
+fn transfer_vaa(
     test_data: &TestData,
     id: [u8; 32],
     amount: u64,
-    // TODO: this is used for a negative testing of the recipient ntt_manager
-    // address. this should not be done in the cancel flow tests, but instead a
-    // dedicated receive transfer test suite
     recipient_ntt_manager: Option<&Pubkey>,
     recipient: &Keypair,
-) -> (Pubkey, NttManagerMessage<NativeTokenTransfer<Payload>>) {
+) -> (TransferVaa, NttManagerMessage<NativeTokenTransfer<Payload>>) {
     let ntt_manager_message = NttManagerMessage {
         id,
         sender: [4u8; 32],
@@ -119,10 +138,10 @@ async fn post_transfer_vaa(
                 amount,
                 decimals: 9,
             },
-            source_token: [3u8; 32],
+            source_token: OTHER_TOKEN,
             to_chain: ChainId { id: THIS_CHAIN },
             to: recipient.pubkey().to_bytes(),
-            additional_payload: Payload {},
+            additional_payload: Payload::default(),
         },
     };
 
@@ -149,6 +168,23 @@ async fn post_transfer_vaa(
         payload: transceiver_message,
     };
 
+    (vaa, ntt_manager_message)
+}
+
+async fn post_transfer_vaa(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    id: [u8; 32],
+    amount: u64,
+    // TODO: this is used for a negative testing of the recipient ntt_manager
+    // address. this should not be done in the cancel flow tests, but instead a
+    // dedicated receive transfer test suite
+    recipient_ntt_manager: Option<&Pubkey>,
+    recipient: &Keypair,
+) -> (Pubkey, NttManagerMessage<NativeTokenTransfer<Payload>>) {
+    let (vaa, ntt_manager_message) =
+        transfer_vaa(test_data, id, amount, recipient_ntt_manager, recipient);
+
     let posted_vaa = post_vaa(&test_data.ntt.wormhole, ctx, vaa).await;
 
     (posted_vaa, ntt_manager_message)
@@ -270,6 +306,69 @@ async fn test_cancel() {
     );
 }
 
+#[tokio::test]
+async fn test_receive_and_redeem() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+
+    let (vaa, msg) =
+        post_transfer_vaa(&mut ctx, &test_data, [0u8; 32], 1000, None, &test_data.user).await;
+    let inbox_item = test_data.ntt.inbox_item(OTHER_CHAIN, msg.clone());
+
+    let balance_before: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+
+    let payer = ctx.payer.pubkey();
+    let receive_and_redeem_ix = || {
+        receive_and_redeem(
+            &test_data.ntt,
+            ReceiveAndRedeem {
+                payer,
+                vaa,
+                chain_id: OTHER_CHAIN,
+                inbox_item,
+                recipient: test_data.user.pubkey(),
+                mint: test_data.mint,
+            },
+            ReceiveAndRedeemArgs {
+                release: true,
+                chain_id: ChainId { id: OTHER_CHAIN },
+                message_hash: message_hash(OTHER_CHAIN, &msg),
+                additional_payload_len: 0,
+            },
+        )
+    };
+
+    receive_and_redeem_ix().submit(&mut ctx).await.unwrap();
+
+    let inbox_item_account: InboxItem = ctx.get_account_data_anchor(inbox_item).await;
+    assert_eq!(inbox_item_account.release_status, ReleaseStatus::Released);
+
+    let balance_after: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+    assert_eq!(balance_after.amount, balance_before.amount + 1000);
+
+    // no intermediate message account is created
+    assert!(ctx
+        .banks_client
+        .get_account(test_data.ntt.transceiver_message(OTHER_CHAIN, msg.id))
+        .await
+        .unwrap()
+        .is_none());
+
+    // the message can't be redeemed twice
+    ctx.get_new_latest_blockhash().await.unwrap();
+    let err = receive_and_redeem_ix().submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransferCannotBeRedeemed.into())
+        )
+    );
+}
+
 // TODO: this should not live in this file, move to a dedicated receive test suite
 #[tokio::test]
 async fn test_wrong_recipient_ntt_manager() {
@@ -311,3 +410,490 @@ async fn test_wrong_recipient_ntt_manager() {
         )
     );
 }
+
+#[tokio::test]
+async fn test_expedite_and_freeze_inbox_item() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+    let operator = Keypair::new();
+
+    set_operator(
+        &test_data.ntt,
+        SetOperator {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: None,
+        },
+        Some(operator.pubkey()),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // more than the inbound limit, so the transfer is queued
+    let amount = INBOUND_LIMIT + 10000;
+    let (vaa, msg) = post_transfer_vaa(
+        &mut ctx,
+        &test_data,
+        [0u8; 32],
+        amount,
+        None,
+        &test_data.user,
+    )
+    .await;
+    let inbox_item = test_data.ntt.inbox_item(OTHER_CHAIN, msg.clone());
+
+    receive_message(
+        &test_data.ntt,
+        init_receive_message_accs(&mut ctx, &test_data, vaa, OTHER_CHAIN, [0u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    redeem(
+        &test_data.ntt,
+        init_redeem_accs(&mut ctx, &test_data, OTHER_CHAIN, msg),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let release = |ctx: &mut ProgramTestContext| {
+        release_inbound(
+            &test_data.ntt,
+            ReleaseInbound {
+                payer: ctx.payer.pubkey(),
+                inbox_item,
+                from_chain: OTHER_CHAIN,
+                recipient: test_data.user.pubkey(),
+                mint: test_data.mint,
+            },
+            ReleaseInboundArgs {
+                revert_on_delay: true,
+            },
+            Mode::Burning,
+        )
+    };
+
+    let err = release(&mut ctx).submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::CantReleaseYet.into())
+        )
+    );
+
+    let override_accs = |authority: &Keypair| OverrideInboxItem {
+        authority: authority.pubkey(),
+        inbox_item,
+    };
+
+    set_inbox_item_frozen(&test_data.ntt, override_accs(&operator), true)
+        .submit_with_signers(&[&operator], &mut ctx)
+        .await
+        .unwrap();
+
+    // only the owner and the operator can expedite
+    let stranger = Keypair::new();
+    let err = expedite_inbox_item(&test_data.ntt, override_accs(&stranger))
+        .submit_with_signers(&[&stranger], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidOwner.into())
+        )
+    );
+
+    expedite_inbox_item(&test_data.ntt, override_accs(&operator))
+        .submit_with_signers(&[&operator], &mut ctx)
+        .await
+        .unwrap();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let inbox_item_account: InboxItem = ctx.get_account_data_anchor(inbox_item).await;
+    assert!(inbox_item_account.frozen);
+    assert!(matches!(
+        inbox_item_account.release_status,
+        ReleaseStatus::ReleaseAfter(release_timestamp) if release_timestamp <= clock.unix_timestamp
+    ));
+
+    // the transfer is releasable now, but frozen
+    ctx.get_new_latest_blockhash().await.unwrap();
+    let err = release(&mut ctx).submit(&mut ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InboxItemFrozen.into())
+        )
+    );
+
+    set_inbox_item_frozen(
+        &test_data.ntt,
+        override_accs(&test_data.program_owner),
+        false,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let token_account_before: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+
+    ctx.get_new_latest_blockhash().await.unwrap();
+    release(&mut ctx).submit(&mut ctx).await.unwrap();
+
+    let token_account_after: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+    assert_eq!(
+        token_account_before.amount + amount,
+        token_account_after.amount
+    );
+
+    // released transfers can't be overridden anymore
+    let err = set_inbox_item_frozen(&test_data.ntt, override_accs(&operator), true)
+        .submit_with_signers(&[&operator], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TransferAlreadyRedeemed.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_settlement_delay() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_settlement_delays(
+        &test_data.ntt,
+        SetSettlementDelays {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: None,
+        },
+        SetSettlementDelaysArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            tiers: vec![
+                SettlementDelayTier {
+                    min_amount: 0,
+                    delay: 60,
+                },
+                SettlementDelayTier {
+                    min_amount: 1000,
+                    delay: 3600,
+                },
+            ],
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // updating the peer keeps the settlement delays
+    set_peer(
+        &test_data.ntt,
+        SetPeer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            mint: test_data.mint,
+        },
+        SetPeerArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            address: OTHER_MANAGER,
+            limit: INBOUND_LIMIT,
+            token_decimals: 7,
+            token_address: OTHER_TOKEN,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let inbound_limit_before = inbound_capacity(&mut ctx, &test_data).await;
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+
+    for (id, amount, delay) in [([0u8; 32], 500, 60), ([1u8; 32], 2000, 3600)] {
+        let (vaa, msg) =
+            post_transfer_vaa(&mut ctx, &test_data, id, amount, None, &recipient).await;
+        let inbox_item = test_data.ntt.inbox_item(OTHER_CHAIN, msg.clone());
+
+        receive_message(
+            &test_data.ntt,
+            init_receive_message_accs(&mut ctx, &test_data, vaa, OTHER_CHAIN, id),
+        )
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+        redeem(
+            &test_data.ntt,
+            init_redeem_accs(&mut ctx, &test_data, OTHER_CHAIN, msg),
+            RedeemArgs {},
+        )
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+        let inbox_item_account: InboxItem = ctx.get_account_data_anchor(inbox_item).await;
+        assert_eq!(
+            inbox_item_account.release_status,
+            ReleaseStatus::ReleaseAfter(clock.unix_timestamp + delay)
+        );
+        // the transfers were within the limit, so they don't defer any capacity
+        assert_eq!(inbox_item_account.deferred_capacity, 0);
+    }
+
+    assert_eq!(
+        inbound_limit_before - 2500,
+        inbound_capacity(&mut ctx, &test_data).await
+    );
+}
+
+#[tokio::test]
+async fn test_receive_encoded_vaa() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let (vaa, msg) = transfer_vaa(&test_data, [0u8; 32], 1000, None, &recipient);
+
+    // unverified encoded vaas are rejected
+    let unverified = write_encoded_vaa(&mut ctx, vaa.clone(), false);
+
+    let err = receive_message(
+        &test_data.ntt,
+        init_receive_message_accs(&mut ctx, &test_data, unverified, OTHER_CHAIN, [0u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidVaaAccount.into())
+        )
+    );
+
+    let encoded_vaa = write_encoded_vaa(&mut ctx, vaa, true);
+
+    let inbound_limit_before = inbound_capacity(&mut ctx, &test_data).await;
+
+    receive_message(
+        &test_data.ntt,
+        init_receive_message_accs(&mut ctx, &test_data, encoded_vaa, OTHER_CHAIN, [0u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    redeem(
+        &test_data.ntt,
+        init_redeem_accs(&mut ctx, &test_data, OTHER_CHAIN, msg),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        inbound_limit_before - 1000,
+        inbound_capacity(&mut ctx, &test_data).await
+    );
+}
+
+#[tokio::test]
+async fn test_wrong_source_token() {
+    let recipient = Keypair::new();
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let (mut vaa, mut msg) = transfer_vaa(&test_data, [0u8; 32], 1000, None, &recipient);
+    msg.payload.source_token = [0xaa; 32];
+    vaa.payload.ntt_manager_payload = msg.clone();
+
+    let vaa = post_vaa(&test_data.ntt.wormhole, &mut ctx, vaa).await;
+
+    receive_message(
+        &test_data.ntt,
+        init_receive_message_accs(&mut ctx, &test_data, vaa, OTHER_CHAIN, [0u8; 32]),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let err = redeem(
+        &test_data.ntt,
+        init_redeem_accs(&mut ctx, &test_data, OTHER_CHAIN, msg),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidSourceToken.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_registered_receiver() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+
+    // any executable account can be registered, the handler is only invoked
+    // on release
+    let receiver = wormhole_governance::ID;
+    let receiver_authority = test_data.ntt.receiver_authority(&receiver);
+
+    register_receiver(
+        &test_data.ntt,
+        RegisterReceiver {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            receiver,
+            pending_action: None,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &receiver_authority,
+        &test_data.mint,
+        &anchor_spl::token::ID,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let (mut vaa, mut msg) = transfer_vaa(&test_data, [0u8; 32], 1000, None, &test_data.user);
+    msg.payload.to = receiver_authority.to_bytes();
+    msg.payload.additional_payload = Payload {
+        data: Some(b"hello receiver".to_vec()),
+    };
+    vaa.payload.ntt_manager_payload = msg.clone();
+    let inbox_item = test_data.ntt.inbox_item(OTHER_CHAIN, msg.clone());
+
+    let vaa = post_vaa(&test_data.ntt.wormhole, &mut ctx, vaa).await;
+
+    receive_message(
+        &test_data.ntt,
+        ReceiveMessage {
+            additional_payload_len: 14,
+            ..init_receive_message_accs(&mut ctx, &test_data, vaa, OTHER_CHAIN, [0u8; 32])
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    redeem(
+        &test_data.ntt,
+        init_redeem_accs(&mut ctx, &test_data, OTHER_CHAIN, msg.clone()),
+        RedeemArgs {},
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let inbox_item_account: InboxItem = ctx.get_account_data_anchor(inbox_item).await;
+    assert_eq!(inbox_item_account.sender, msg.sender);
+    assert_eq!(
+        inbox_item_account.additional_payload,
+        msg.payload.additional_payload
+    );
+
+    let release_accs = |ctx: &mut ProgramTestContext| ReleaseInbound {
+        payer: ctx.payer.pubkey(),
+        inbox_item,
+        from_chain: OTHER_CHAIN,
+        recipient: receiver_authority,
+        mint: test_data.mint,
+    };
+    let args = || ReleaseInboundArgs {
+        revert_on_delay: true,
+    };
+
+    // transfers to a registered receiver can't be released without the call
+    let err = release_inbound(
+        &test_data.ntt,
+        release_accs(&mut ctx),
+        args(),
+        Mode::Burning,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::ReleaseRequiresCall.into())
+        )
+    );
+
+    // ...and only the registered program can be called
+    let err = release_inbound_and_call(
+        &test_data.ntt,
+        release_accs(&mut ctx),
+        test_data.ntt.wormhole.program,
+        args(),
+        Mode::Burning,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidReceiverProgram.into())
+        )
+    );
+
+    // once deregistered, the transfer is released without a call
+    deregister_receiver(
+        &test_data.ntt,
+        DeregisterReceiver {
+            owner: test_data.program_owner.pubkey(),
+            receiver,
+            pending_action: None,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    release_inbound(
+        &test_data.ntt,
+        release_accs(&mut ctx),
+        args(),
+        Mode::Burning,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let receiver_token_account: TokenAccount = ctx
+        .get_account_data_anchor(anchor_spl::associated_token::get_associated_token_address(
+            &receiver_authority,
+            &test_data.mint,
+        ))
+        .await;
+    assert_eq!(receiver_token_account.amount, 1000);
+}
@@ -15,6 +15,10 @@ use solana_sdk::{
     transaction::Transaction,
 };
 use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::extension::{
+    transfer_fee::{TransferFee, TransferFeeConfig},
+    BaseStateWithExtensionsMut, StateWithExtensionsMut,
+};
 use wormhole_anchor_sdk::wormhole::{BridgeData, FeeCollector};
 
 use crate::sdk::{
@@ -39,6 +43,7 @@ pub const INBOUND_LIMIT: u64 = 50000;
 
 pub const OTHER_TRANSCEIVER: [u8; 32] = [7u8; 32];
 pub const OTHER_MANAGER: [u8; 32] = [9u8; 32];
+pub const OTHER_TOKEN: [u8; 32] = [3u8; 32];
 
 pub const THIS_CHAIN: u16 = 1;
 pub const OTHER_CHAIN: u16 = 2;
@@ -88,6 +93,10 @@ pub async fn setup_with_extra_accounts_with_transfer_fee(
     let test_data = setup_accounts_with_transfer_fee(&mut ctx, program_owner).await;
     setup_ntt_with_token_program_id(&mut ctx, &test_data, mode, &spl_token_2022::id()).await;
 
+    // NTT rejects fee-taking mints at initialize, so the fee is raised after
+    // (as the fee authority could)
+    set_transfer_fee(&mut ctx, &test_data.mint, 500, 5000).await;
+
     (ctx, test_data)
 }
 
@@ -185,6 +194,7 @@ pub async fn setup_ntt_with_token_program_id(
             chain_id: THIS_CHAIN,
             limit: OUTBOUND_LIMIT,
             mode,
+            multi_token: test_data.ntt.multi_token_mint.is_some(),
         },
         token_program_id,
     )
@@ -231,6 +241,7 @@ pub async fn setup_ntt_with_token_program_id(
             address: OTHER_MANAGER,
             limit: INBOUND_LIMIT,
             token_decimals: 7,
+            token_address: OTHER_TOKEN,
         },
     )
     .submit_with_signers(&[&test_data.program_owner], ctx)
@@ -285,6 +296,7 @@ pub async fn setup_accounts(ctx: &mut ProgramTestContext, program_owner: Keypair
             wormhole: Wormhole {
                 program: wormhole_anchor_sdk::wormhole::program::ID,
             },
+            multi_token_mint: None,
         },
         governance: Governance {
             program: wormhole_governance::ID,
@@ -308,7 +320,7 @@ pub async fn setup_accounts_with_transfer_fee(
     let user = Keypair::new();
     let payer = ctx.payer.pubkey();
 
-    create_mint_with_transfer_fee(ctx, &mint, &mint_authority.pubkey(), 9, 500, 5000)
+    create_mint_with_transfer_fee(ctx, &mint, &mint_authority.pubkey(), 9, 0, 0)
         .await
         .submit(ctx)
         .await
@@ -350,6 +362,7 @@ pub async fn setup_accounts_with_transfer_fee(
             wormhole: Wormhole {
                 program: wormhole_anchor_sdk::wormhole::program::ID,
             },
+            multi_token_mint: None,
         },
         governance: Governance {
             program: wormhole_governance::ID,
@@ -448,6 +461,31 @@ pub async fn create_mint_with_transfer_fee(
     )
 }
 
+/// Overwrites the older and newer transfer fees of `mint`, taking effect
+/// immediately.
+pub async fn set_transfer_fee(
+    ctx: &mut ProgramTestContext,
+    mint: &Pubkey,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) {
+    let mut account = ctx.banks_client.get_account(*mint).await.unwrap().unwrap();
+    {
+        let mut state =
+            StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack(&mut account.data)
+                .unwrap();
+        let config = state.get_extension_mut::<TransferFeeConfig>().unwrap();
+        let fee = TransferFee {
+            epoch: 0.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: transfer_fee_basis_points.into(),
+        };
+        config.older_transfer_fee = fee;
+        config.newer_transfer_fee = fee;
+    }
+    ctx.set_account(mint, &account.into());
+}
+
 // TODO: upstream this to solana-program-test
 
 /// Add a SBF program to the test environment. (copied from solana_program_test
@@ -1,7 +1,12 @@
+use anchor_lang::AnchorDeserialize;
+use example_native_token_transfers::error::NTTError;
 use solana_program_test::{BanksClientError, ProgramTestContext};
 use solana_sdk::{
-    instruction::Instruction, signature::Keypair, signer::Signer, signers::Signers,
-    transaction::Transaction,
+    instruction::{Instruction, InstructionError},
+    signature::Keypair,
+    signer::Signer,
+    signers::Signers,
+    transaction::{Transaction, TransactionError},
 };
 
 pub trait Submittable {
@@ -49,3 +54,42 @@ impl Submittable for Transaction {
         ctx.banks_client.process_transaction(self).await
     }
 }
+
+/// Moves to a new slot, so that repeating a transaction isn't rejected as a
+/// duplicate.
+pub async fn next_slot(ctx: &mut ProgramTestContext) {
+    let slot = ctx.banks_client.get_root_slot().await.unwrap();
+    ctx.warp_to_slot(slot + 2).unwrap();
+}
+
+pub trait Simulatable {
+    /// Simulate the instruction and deserialize its return data.
+    async fn simulate<R: AnchorDeserialize>(self, ctx: &mut ProgramTestContext) -> R;
+}
+
+impl Simulatable for Instruction {
+    async fn simulate<R: AnchorDeserialize>(self, ctx: &mut ProgramTestContext) -> R {
+        let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+
+        let mut transaction = Transaction::new_with_payer(&[self], Some(&ctx.payer.pubkey()));
+        transaction.partial_sign(&[&ctx.payer], blockhash);
+
+        let simulation = ctx
+            .banks_client
+            .simulate_transaction(transaction)
+            .await
+            .unwrap();
+        simulation.result.unwrap().unwrap();
+
+        let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+        R::try_from_slice(&return_data.data).unwrap()
+    }
+}
+
+/// Asserts that the (single instruction) transaction failed with `error`.
+pub fn assert_ntt_error(result: Result<(), BanksClientError>, error: NTTError) {
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(error.into()))
+    );
+}
@@ -0,0 +1,191 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use anchor_lang::prelude::Pubkey;
+use example_native_token_transfers::{
+    config::Config,
+    custody_ledger::CustodyLedger,
+    error::NTTError,
+    instructions::{CustodyInvariant, TransferArgs},
+};
+use ntt_messages::{chain_id::ChainId, mode::Mode};
+use solana_program_test::*;
+use solana_sdk::{
+    account::AccountSharedData, instruction::InstructionError, program_pack::Pack,
+    signature::Keypair, signer::Signer, transaction::TransactionError,
+};
+
+use crate::{
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{setup, TestData, OTHER_CHAIN},
+        submit::{Simulatable, Submittable},
+    },
+    sdk::instructions::{
+        admin::{initialize_custody_ledger, InitializeCustodyLedger},
+        check_custody::check_custody_invariant,
+        transfer::{approve_token_authority, transfer, Transfer},
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+async fn lock(ctx: &mut ProgramTestContext, test_data: &TestData, amount: u64) {
+    let outbox_item = Keypair::new();
+    let args = TransferArgs {
+        amount,
+        recipient_chain: ChainId { id: OTHER_CHAIN },
+        recipient_address: [1u8; 32],
+        should_queue: false,
+    };
+
+    approve_token_authority(
+        &test_data.ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+
+    transfer(
+        &test_data.ntt,
+        Transfer {
+            payer: ctx.payer.pubkey(),
+            mint: test_data.mint,
+            from: test_data.user_token_account,
+            from_authority: test_data.user.pubkey(),
+            peer: test_data.ntt.peer(OTHER_CHAIN),
+            outbox_item: outbox_item.pubkey(),
+        },
+        args,
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&outbox_item], ctx)
+    .await
+    .unwrap();
+}
+
+/// Overwrites the balance of `token_account`, as if tokens had been drained
+/// from (or donated to) it.
+async fn set_token_balance(ctx: &mut ProgramTestContext, token_account: Pubkey, amount: u64) {
+    let mut account = ctx
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let mut state = spl_token::state::Account::unpack(&account.data).unwrap();
+    state.amount = amount;
+    spl_token::state::Account::pack(state, &mut account.data).unwrap();
+    ctx.set_account(&token_account, &account.into());
+}
+
+#[tokio::test]
+async fn test_custody_invariant() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let custody = test_data.ntt.custody(&test_data.mint);
+
+    lock(&mut ctx, &test_data, 1000).await;
+    lock(&mut ctx, &test_data, 500).await;
+
+    let ledger: CustodyLedger = ctx
+        .get_account_data_anchor(test_data.ntt.custody_ledger())
+        .await;
+    assert_eq!(ledger.total_outbound, 1500);
+    assert_eq!(ledger.total_inbound, 0);
+
+    let check: CustodyInvariant =
+        check_custody_invariant(&test_data.ntt, test_data.mint, &[], false)
+            .simulate(&mut ctx)
+            .await;
+    assert_eq!(
+        check,
+        CustodyInvariant {
+            custody: 1500,
+            recorded: 1500,
+            pending_inbound: 0,
+            discrepancy: 0,
+        }
+    );
+
+    // a shortfall is reported, but only pauses the program when asked to
+    set_token_balance(&mut ctx, custody, 1200).await;
+
+    let check: CustodyInvariant =
+        check_custody_invariant(&test_data.ntt, test_data.mint, &[], false)
+            .simulate(&mut ctx)
+            .await;
+    assert_eq!(check.discrepancy, -300);
+
+    check_custody_invariant(&test_data.ntt, test_data.mint, &[], false)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    let config: Config = ctx.get_account_data_anchor(test_data.ntt.config()).await;
+    assert!(!config.paused);
+
+    check_custody_invariant(&test_data.ntt, test_data.mint, &[], true)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    let config: Config = ctx.get_account_data_anchor(test_data.ntt.config()).await;
+    assert!(config.paused);
+}
+
+#[tokio::test]
+async fn test_custody_invariant_rejects_burning_mode() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+
+    let err = check_custody_invariant(&test_data.ntt, test_data.mint, &[], true)
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidMode.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_custody_ledger_of_existing_deployment() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    lock(&mut ctx, &test_data, 1000).await;
+
+    // deployments created before the ledger existed don't have one
+    ctx.set_account(
+        &test_data.ntt.custody_ledger(),
+        &AccountSharedData::default(),
+    );
+
+    // transfers don't require the ledger until it's initialized
+    lock(&mut ctx, &test_data, 500).await;
+
+    initialize_custody_ledger(
+        &test_data.ntt,
+        InitializeCustodyLedger {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            mint: test_data.mint,
+            pending_action: None,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // the custody balance at initialization is taken as the baseline
+    lock(&mut ctx, &test_data, 200).await;
+
+    let ledger: CustodyLedger = ctx
+        .get_account_data_anchor(test_data.ntt.custody_ledger())
+        .await;
+    assert_eq!(ledger.total_outbound, 1700);
+    assert_eq!(ledger.total_inbound, 0);
+}
@@ -71,6 +71,7 @@ async fn test_governance() {
     let accs = example_native_token_transfers::accounts::TransferOwnership {
         config: test_data.ntt.config(),
         owner: test_data.program_owner.pubkey(),
+        pending_action: None,
         new_owner: governance_pda,
         upgrade_lock: test_data.ntt.upgrade_lock(),
         program_data: test_data.ntt.program_data(),
@@ -146,6 +147,7 @@ async fn test_governance_one_step_transfer() {
     let accs = example_native_token_transfers::accounts::TransferOwnership {
         config: test_data.ntt.config(),
         owner: test_data.program_owner.pubkey(),
+        pending_action: None,
         new_owner: governance_pda,
         upgrade_lock: test_data.ntt.upgrade_lock(),
         program_data: test_data.ntt.program_data(),
@@ -218,6 +220,7 @@ async fn test_governance_bad_governance_contract() {
     let accs = example_native_token_transfers::accounts::TransferOwnership {
         config: test_data.ntt.config(),
         owner: test_data.program_owner.pubkey(),
+        pending_action: None,
         new_owner: governance_pda,
         upgrade_lock: test_data.ntt.upgrade_lock(),
         program_data: test_data.ntt.program_data(),
@@ -282,6 +285,7 @@ async fn test_governance_replay() {
     let accs = example_native_token_transfers::accounts::TransferOwnership {
         config: test_data.ntt.config(),
         owner: test_data.program_owner.pubkey(),
+        pending_action: None,
         new_owner: governance_pda,
         upgrade_lock: test_data.ntt.upgrade_lock(),
         program_data: test_data.ntt.program_data(),
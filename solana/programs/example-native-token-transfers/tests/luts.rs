@@ -0,0 +1,225 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use anchor_lang::prelude::*;
+use example_native_token_transfers::{error::NTTError, instructions::LUT};
+use ntt_messages::mode::Mode;
+use solana_program::{
+    address_lookup_table::state::AddressLookupTable, hash::Hash, instruction::InstructionError,
+    slot_hashes::SlotHashes,
+};
+use solana_program_test::*;
+use solana_sdk::{signer::Signer, transaction::TransactionError};
+
+use crate::{
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{setup, TestData, OTHER_CHAIN},
+        submit::{next_slot, Submittable},
+    },
+    sdk::instructions::luts::{
+        close_lut, deactivate_lut, extend_lut_with_peer, extend_lut_with_transceiver,
+        extend_lut_with_transfer_hook, initialize_lut, ExtendLUT, InitializeLUT, RetireLUT,
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+/// Sets the slot hashes to only contain `slot`. LUTs are derived from a slot
+/// that has to be in the slot hashes, and a deactivated LUT can only be closed
+/// once its deactivation slot is no longer in them.
+fn set_slot_hashes(ctx: &mut ProgramTestContext, slot: u64) {
+    ctx.set_sysvar(&SlotHashes::new(&[(slot, Hash::new_unique())]));
+}
+
+/// Creates a new LUT from `recent_slot`, which becomes the current one.
+async fn new_lut(ctx: &mut ProgramTestContext, test_data: &TestData, recent_slot: u64) -> Pubkey {
+    set_slot_hashes(ctx, recent_slot);
+    initialize_lut(
+        &test_data.ntt,
+        InitializeLUT {
+            payer: ctx.payer.pubkey(),
+            mint: test_data.mint,
+        },
+        recent_slot,
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    let lut: LUT = ctx.get_account_data_anchor(test_data.ntt.lut()).await;
+    assert_eq!(lut.address, test_data.ntt.lut_address(recent_slot));
+    lut.address
+}
+
+async fn lut_entries(ctx: &mut ProgramTestContext, lut_address: Pubkey) -> Vec<Pubkey> {
+    let account = ctx
+        .banks_client
+        .get_account(lut_address)
+        .await
+        .unwrap()
+        .unwrap();
+    AddressLookupTable::deserialize(&account.data)
+        .unwrap()
+        .addresses
+        .to_vec()
+}
+
+#[tokio::test]
+async fn test_extend_lut() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let ntt = &test_data.ntt;
+
+    let lut_address = new_lut(&mut ctx, &test_data, 1).await;
+    let entries = lut_entries(&mut ctx, lut_address).await;
+    assert_eq!(entries[0], example_native_token_transfers::ID);
+    assert!(entries.contains(&ntt.config()));
+
+    let payer = ctx.payer.pubkey();
+    let extend = || ExtendLUT { payer, lut_address };
+
+    extend_lut_with_peer(ntt, extend(), OTHER_CHAIN)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    let with_peer = lut_entries(&mut ctx, lut_address).await;
+    assert_eq!(
+        with_peer[entries.len()..],
+        [
+            ntt.peer(OTHER_CHAIN),
+            ntt.inbox_rate_limit(OTHER_CHAIN),
+            ntt.transceiver_peer(OTHER_CHAIN),
+        ]
+    );
+
+    // the transceiver (the program itself) is already in the LUT, so only its
+    // registration is added
+    extend_lut_with_transceiver(ntt, extend(), &ntt.program)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    let with_transceiver = lut_entries(&mut ctx, lut_address).await;
+    assert_eq!(
+        with_transceiver[with_peer.len()..],
+        [ntt.registered_transceiver(&ntt.program)]
+    );
+
+    // the mint has no transfer hook
+    extend_lut_with_transfer_hook(ntt, extend(), test_data.mint)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    assert_eq!(lut_entries(&mut ctx, lut_address).await, with_transceiver);
+
+    // extending again doesn't add duplicates
+    next_slot(&mut ctx).await;
+    extend_lut_with_peer(ntt, extend(), OTHER_CHAIN)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    extend_lut_with_transceiver(ntt, extend(), &ntt.program)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    assert_eq!(lut_entries(&mut ctx, lut_address).await, with_transceiver);
+}
+
+#[tokio::test]
+async fn test_extend_old_lut_fails() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let ntt = &test_data.ntt;
+
+    let old_lut_address = new_lut(&mut ctx, &test_data, 1).await;
+    new_lut(&mut ctx, &test_data, 2).await;
+
+    let err = extend_lut_with_peer(
+        ntt,
+        ExtendLUT {
+            payer: ctx.payer.pubkey(),
+            lut_address: old_lut_address,
+        },
+        OTHER_CHAIN,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(ErrorCode::ConstraintAddress.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_retire_lut() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let ntt = &test_data.ntt;
+    let owner = &test_data.program_owner;
+
+    let old_lut_address = new_lut(&mut ctx, &test_data, 1).await;
+    let current_lut_address = new_lut(&mut ctx, &test_data, 2).await;
+
+    // the current LUT can't be deactivated
+    let err = deactivate_lut(
+        ntt,
+        RetireLUT {
+            owner: owner.pubkey(),
+            lut_address: current_lut_address,
+        },
+    )
+    .submit_with_signers(&[owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::CurrentLUT.into())
+        )
+    );
+
+    let retire = || RetireLUT {
+        owner: owner.pubkey(),
+        lut_address: old_lut_address,
+    };
+
+    deactivate_lut(ntt, retire())
+        .submit_with_signers(&[owner], &mut ctx)
+        .await
+        .unwrap();
+
+    // the LUT can't be closed until it has cooled down
+    assert!(close_lut(ntt, retire())
+        .submit_with_signers(&[owner], &mut ctx)
+        .await
+        .is_err());
+
+    let slot = ctx.banks_client.get_root_slot().await.unwrap();
+    ctx.warp_to_slot(slot + 2).unwrap();
+    set_slot_hashes(&mut ctx, slot + 2);
+
+    let lut_lamports = ctx.banks_client.get_balance(old_lut_address).await.unwrap();
+    let owner_lamports = ctx.banks_client.get_balance(owner.pubkey()).await.unwrap();
+
+    close_lut(ntt, retire())
+        .submit_with_signers(&[owner], &mut ctx)
+        .await
+        .unwrap();
+
+    assert!(ctx
+        .banks_client
+        .get_account(old_lut_address)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        ctx.banks_client.get_balance(owner.pubkey()).await.unwrap(),
+        owner_lamports + lut_lamports
+    );
+
+    // the current LUT is untouched
+    assert!(!lut_entries(&mut ctx, current_lut_address).await.is_empty());
+}
@@ -0,0 +1,415 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use anchor_lang::{prelude::*, Discriminator};
+use example_native_token_transfers::{
+    bitmap::Bitmap,
+    config::Config,
+    error::NTTError,
+    instructions::MigrateInboxItemArgs,
+    legacy::{
+        LegacyConfig, LegacyInboxItem, LegacyInboxRateLimit, LegacyNttManagerPeer,
+        LegacyOutboxItem, LegacyOutboxRateLimit, LegacyRateLimitState, LegacyTransceiverPeer,
+    },
+    peer::NttManagerPeer,
+    queue::{
+        inbox::{InboxItem, InboxRateLimit, ReleaseStatus},
+        outbox::{OutboxItem, OutboxRateLimit},
+    },
+    transceivers::accounts::peer::TransceiverPeer,
+    transfer::Payload,
+};
+use ntt_messages::{
+    chain_id::ChainId, mode::Mode, ntt::NativeTokenTransfer, ntt_manager::NttManagerMessage,
+    trimmed_amount::TrimmedAmount,
+};
+use solana_program::instruction::InstructionError;
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account, signature::Keypair, signer::Signer, transaction::TransactionError,
+};
+
+use crate::{
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{setup, OTHER_CHAIN, OTHER_MANAGER, OTHER_TRANSCEIVER},
+        submit::{next_slot, Submittable},
+    },
+    sdk::instructions::{
+        admin::{set_paused, SetPaused},
+        migrate::{
+            migrate_config, migrate_inbox_item, migrate_inbox_rate_limit, migrate_outbox_item,
+            migrate_outbox_rate_limit, migrate_peer, migrate_wormhole_peer,
+        },
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+/// Overwrites `address` with an account of type `T` in the legacy layout `L`,
+/// as created by an earlier version of the program.
+fn write_legacy<T: Discriminator, L: AnchorSerialize + Space>(
+    ctx: &mut ProgramTestContext,
+    address: &Pubkey,
+    legacy: &L,
+) {
+    let mut data = T::DISCRIMINATOR.to_vec();
+    data.extend(legacy.try_to_vec().unwrap());
+    data.resize(8 + L::INIT_SPACE, 0);
+    ctx.set_account(
+        address,
+        &Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: example_native_token_transfers::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+}
+
+fn assert_already_migrated(result: std::result::Result<(), BanksClientError>) {
+    assert_eq!(
+        result.unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::AccountAlreadyMigrated.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_config() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let ntt = &test_data.ntt;
+    let config: Config = ctx.get_account_data_anchor(ntt.config()).await;
+    let pending_owner = Keypair::new().pubkey();
+
+    // a config created by an earlier version, paused for the upgrade
+    write_legacy::<Config, _>(
+        &mut ctx,
+        &ntt.config(),
+        &LegacyConfig {
+            bump: config.bump,
+            owner: config.owner,
+            pending_owner: Some(pending_owner),
+            mint: config.mint,
+            token_program: config.token_program,
+            mode: config.mode,
+            chain_id: config.chain_id,
+            next_transceiver_id: config.next_transceiver_id,
+            threshold: config.threshold,
+            enabled_transceivers: config.enabled_transceivers,
+            paused: true,
+            custody: config.custody,
+        },
+    );
+
+    let unpause = || {
+        set_paused(
+            ntt,
+            SetPaused {
+                owner: test_data.program_owner.pubkey(),
+            },
+            false,
+        )
+    };
+
+    // the legacy config can't be read until it's migrated
+    assert!(unpause()
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .is_err());
+
+    migrate_config(ntt, ctx.payer.pubkey())
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let migrated: Config = ctx.get_account_data_anchor(ntt.config()).await;
+    assert_eq!(migrated.owner, config.owner);
+    assert_eq!(migrated.pending_owner, Some(pending_owner));
+    assert_eq!(migrated.mint, config.mint);
+    assert_eq!(migrated.custody, config.custody);
+    assert!(migrated.paused);
+    assert_eq!(migrated.timelock_delay, 0);
+    assert_eq!(migrated.layout_version, Config::LAYOUT_VERSION);
+    assert_eq!(migrated.wormhole_finality, config.wormhole_finality);
+    assert!(migrated.upgrade_authority_escrowed);
+
+    next_slot(&mut ctx).await;
+    assert_already_migrated(
+        migrate_config(ntt, ctx.payer.pubkey())
+            .submit(&mut ctx)
+            .await,
+    );
+
+    unpause()
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_migrate_rate_limits() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let ntt = &test_data.ntt;
+
+    let outbox_rate_limit: OutboxRateLimit =
+        ctx.get_account_data_anchor(ntt.outbox_rate_limit()).await;
+    write_legacy::<OutboxRateLimit, _>(
+        &mut ctx,
+        &ntt.outbox_rate_limit(),
+        &LegacyOutboxRateLimit {
+            rate_limit: LegacyRateLimitState {
+                limit: outbox_rate_limit.rate_limit.limit,
+                capacity_at_last_tx: 1234,
+                last_tx_timestamp: 5678,
+            },
+        },
+    );
+
+    let inbox_rate_limit: InboxRateLimit = ctx
+        .get_account_data_anchor(ntt.inbox_rate_limit(OTHER_CHAIN))
+        .await;
+    write_legacy::<InboxRateLimit, _>(
+        &mut ctx,
+        &ntt.inbox_rate_limit(OTHER_CHAIN),
+        &LegacyInboxRateLimit {
+            bump: inbox_rate_limit.bump,
+            rate_limit: LegacyRateLimitState {
+                limit: inbox_rate_limit.rate_limit.limit,
+                capacity_at_last_tx: 4321,
+                last_tx_timestamp: 8765,
+            },
+        },
+    );
+
+    migrate_outbox_rate_limit(ntt, ctx.payer.pubkey())
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    migrate_inbox_rate_limit(ntt, ctx.payer.pubkey(), OTHER_CHAIN)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let migrated: OutboxRateLimit = ctx.get_account_data_anchor(ntt.outbox_rate_limit()).await;
+    assert_eq!(
+        migrated.rate_limit.limit,
+        outbox_rate_limit.rate_limit.limit
+    );
+    assert_eq!(migrated.rate_limit.capacity_at_last_tx, 1234);
+    assert_eq!(migrated.rate_limit.last_tx_timestamp, 5678);
+    assert_eq!(migrated.rate_limit.ramp, None);
+    assert_eq!(migrated.queued_amount, 0);
+    assert_eq!(migrated.max_queued_amount, None);
+
+    let migrated: InboxRateLimit = ctx
+        .get_account_data_anchor(ntt.inbox_rate_limit(OTHER_CHAIN))
+        .await;
+    assert_eq!(migrated.bump, inbox_rate_limit.bump);
+    assert_eq!(migrated.rate_limit.limit, inbox_rate_limit.rate_limit.limit);
+    assert_eq!(migrated.rate_limit.capacity_at_last_tx, 4321);
+    assert_eq!(migrated.rate_limit.last_tx_timestamp, 8765);
+    assert_eq!(migrated.rate_limit.ramp, None);
+
+    next_slot(&mut ctx).await;
+    assert_already_migrated(
+        migrate_outbox_rate_limit(ntt, ctx.payer.pubkey())
+            .submit(&mut ctx)
+            .await,
+    );
+    assert_already_migrated(
+        migrate_inbox_rate_limit(ntt, ctx.payer.pubkey(), OTHER_CHAIN)
+            .submit(&mut ctx)
+            .await,
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_peers() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let ntt = &test_data.ntt;
+
+    let peer: NttManagerPeer = ctx.get_account_data_anchor(ntt.peer(OTHER_CHAIN)).await;
+    write_legacy::<NttManagerPeer, _>(
+        &mut ctx,
+        &ntt.peer(OTHER_CHAIN),
+        &LegacyNttManagerPeer {
+            bump: peer.bump,
+            address: OTHER_MANAGER,
+            token_decimals: peer.token_decimals,
+        },
+    );
+
+    let transceiver_peer: TransceiverPeer = ctx
+        .get_account_data_anchor(ntt.transceiver_peer(OTHER_CHAIN))
+        .await;
+    write_legacy::<TransceiverPeer, _>(
+        &mut ctx,
+        &ntt.transceiver_peer(OTHER_CHAIN),
+        &LegacyTransceiverPeer {
+            bump: transceiver_peer.bump,
+            address: OTHER_TRANSCEIVER,
+        },
+    );
+
+    migrate_peer(ntt, ctx.payer.pubkey(), OTHER_CHAIN)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    migrate_wormhole_peer(ntt, ctx.payer.pubkey(), OTHER_CHAIN)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let migrated: NttManagerPeer = ctx.get_account_data_anchor(ntt.peer(OTHER_CHAIN)).await;
+    assert_eq!(migrated.bump, peer.bump);
+    assert_eq!(migrated.address, OTHER_MANAGER);
+    assert_eq!(migrated.token_decimals, peer.token_decimals);
+    // the token address wasn't recorded, and has to be set by the owner
+    assert_eq!(migrated.token_address, [0; 32]);
+    assert!(migrated.settlement_delays.is_empty());
+    assert!(!migrated.outbound_paused);
+
+    let migrated: TransceiverPeer = ctx
+        .get_account_data_anchor(ntt.transceiver_peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(migrated.bump, transceiver_peer.bump);
+    assert_eq!(migrated.address, OTHER_TRANSCEIVER);
+    assert_eq!(migrated.finality, None);
+    assert_eq!(migrated.pause_signal_sequence, None);
+
+    next_slot(&mut ctx).await;
+    assert_already_migrated(
+        migrate_peer(ntt, ctx.payer.pubkey(), OTHER_CHAIN)
+            .submit(&mut ctx)
+            .await,
+    );
+    assert_already_migrated(
+        migrate_wormhole_peer(ntt, ctx.payer.pubkey(), OTHER_CHAIN)
+            .submit(&mut ctx)
+            .await,
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_queued_transfers() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let ntt = &test_data.ntt;
+    let config: Config = ctx.get_account_data_anchor(ntt.config()).await;
+
+    // an outbound transfer queued before the upgrade
+    let outbox_item = Keypair::new().pubkey();
+    let legacy_outbox_item = LegacyOutboxItem {
+        amount: TrimmedAmount {
+            amount: 10,
+            decimals: 7,
+        },
+        sender: test_data.user.pubkey(),
+        recipient_chain: ChainId { id: OTHER_CHAIN },
+        recipient_ntt_manager: OTHER_MANAGER,
+        recipient_address: [1u8; 32],
+        release_timestamp: 1234,
+        released: Bitmap::new(),
+    };
+    write_legacy::<OutboxItem, _>(&mut ctx, &outbox_item, &legacy_outbox_item);
+
+    // an inbound transfer redeemed before the upgrade
+    let message = NttManagerMessage {
+        id: [4u8; 32],
+        sender: [5u8; 32],
+        payload: NativeTokenTransfer {
+            amount: TrimmedAmount {
+                amount: 20,
+                decimals: 7,
+            },
+            source_token: [3u8; 32],
+            to_chain: ChainId { id: 1 },
+            to: test_data.user.pubkey().to_bytes(),
+            additional_payload: Payload::default(),
+        },
+    };
+    let inbox_item = ntt.inbox_item(OTHER_CHAIN, message.clone());
+    let (_, inbox_item_bump) = Pubkey::find_program_address(
+        &[
+            InboxItem::SEED_PREFIX,
+            message.keccak256(ChainId { id: OTHER_CHAIN }).as_ref(),
+        ],
+        &ntt.program,
+    );
+    write_legacy::<InboxItem, _>(
+        &mut ctx,
+        &inbox_item,
+        &LegacyInboxItem {
+            init: true,
+            bump: inbox_item_bump,
+            amount: 2000,
+            recipient_address: test_data.user.pubkey(),
+            votes: Bitmap::new(),
+            release_status: ReleaseStatus::ReleaseAfter(5678),
+        },
+    );
+
+    migrate_outbox_item(ntt, ctx.payer.pubkey(), outbox_item)
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+    let args = || MigrateInboxItemArgs {
+        from_chain: ChainId { id: OTHER_CHAIN },
+        id: message.id,
+        sender: message.sender,
+        amount: message.payload.amount,
+        source_token: message.payload.source_token,
+        to_chain: message.payload.to_chain,
+        to: message.payload.to,
+    };
+    migrate_inbox_item(ntt, ctx.payer.pubkey(), args())
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+    let migrated: OutboxItem = ctx.get_account_data_anchor(outbox_item).await;
+    assert_eq!(
+        migrated,
+        OutboxItem {
+            amount: legacy_outbox_item.amount,
+            sender: legacy_outbox_item.sender,
+            recipient_chain: legacy_outbox_item.recipient_chain,
+            recipient_ntt_manager: legacy_outbox_item.recipient_ntt_manager,
+            recipient_address: legacy_outbox_item.recipient_address,
+            release_timestamp: legacy_outbox_item.release_timestamp,
+            released: legacy_outbox_item.released,
+            deferred_capacity: 0,
+            mint: config.mint,
+        }
+    );
+
+    let migrated: InboxItem = ctx.get_account_data_anchor(inbox_item).await;
+    assert!(migrated.init);
+    assert_eq!(migrated.bump, inbox_item_bump);
+    assert_eq!(migrated.amount, 2000);
+    assert_eq!(migrated.recipient_address, test_data.user.pubkey());
+    assert_eq!(migrated.release_status, ReleaseStatus::ReleaseAfter(5678));
+    assert_eq!(migrated.from_chain, ChainId { id: OTHER_CHAIN });
+    assert_eq!(migrated.deferred_capacity, 0);
+    assert!(!migrated.frozen);
+    assert_eq!(migrated.sender, [5u8; 32]);
+    assert_eq!(migrated.mint, config.mint);
+    assert_eq!(migrated.additional_payload, Payload::default());
+
+    next_slot(&mut ctx).await;
+    assert_already_migrated(
+        migrate_outbox_item(ntt, ctx.payer.pubkey(), outbox_item)
+            .submit(&mut ctx)
+            .await,
+    );
+    assert_already_migrated(
+        migrate_inbox_item(ntt, ctx.payer.pubkey(), args())
+            .submit(&mut ctx)
+            .await,
+    );
+}
@@ -0,0 +1,248 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use example_native_token_transfers::{
+    config::Config, error::NTTError, instructions::InitializeArgs,
+};
+use ntt_messages::mode::Mode;
+use solana_program::instruction::Instruction;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::Keypair, signer::Signer, system_instruction, transaction::Transaction,
+};
+use spl_token_2022::{
+    extension::{
+        default_account_state::instruction::initialize_default_account_state,
+        transfer_fee::instruction::initialize_transfer_fee_config, ExtensionType,
+    },
+    state::AccountState,
+};
+
+use crate::{
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{setup_programs, OUTBOUND_LIMIT, THIS_CHAIN},
+        submit::{assert_ntt_error, Submittable},
+    },
+    sdk::{
+        accounts::{Wormhole, NTT},
+        instructions::initialize::{initialize_with_token_program_id, Initialize},
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+/// Creates a token-2022 mint with `extension_types`, initialised by
+/// `init_extensions`, and initializes NTT with it.
+async fn initialize_with_extensions(
+    mode: Mode,
+    extension_types: &[ExtensionType],
+    init_extensions: impl FnOnce(&Keypair) -> Vec<Instruction>,
+) -> (ProgramTestContext, NTT, Result<(), BanksClientError>) {
+    let program_owner = Keypair::new();
+    let program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    let mut ctx = program_test.start_with_context().await;
+
+    let ntt = NTT {
+        program: example_native_token_transfers::ID,
+        wormhole: Wormhole {
+            program: wormhole_anchor_sdk::wormhole::program::ID,
+        },
+        multi_token_mint: None,
+    };
+
+    let mint = Keypair::new();
+    let space =
+        ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(extension_types)
+            .unwrap();
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+
+    let mut instructions = vec![system_instruction::create_account(
+        &ctx.payer.pubkey(),
+        &mint.pubkey(),
+        rent.minimum_balance(space),
+        space as u64,
+        &spl_token_2022::id(),
+    )];
+    instructions.extend(init_extensions(&mint));
+    instructions.push(
+        spl_token_2022::instruction::initialize_mint2(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            &ntt.token_authority(),
+            Some(&ctx.payer.pubkey()),
+            9,
+        )
+        .unwrap(),
+    );
+
+    let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+    Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &mint],
+        blockhash,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let result = initialize_with_token_program_id(
+        &ntt,
+        Initialize {
+            payer: ctx.payer.pubkey(),
+            deployer: program_owner.pubkey(),
+            mint: mint.pubkey(),
+        },
+        InitializeArgs {
+            chain_id: THIS_CHAIN,
+            limit: OUTBOUND_LIMIT,
+            mode,
+            multi_token: false,
+        },
+        &spl_token_2022::id(),
+    )
+    .submit_with_signers(&[&program_owner], &mut ctx)
+    .await;
+
+    (ctx, ntt, result)
+}
+
+#[tokio::test]
+async fn test_transfer_fee_rejected() {
+    let (_, _, result) =
+        initialize_with_extensions(Mode::Locking, &[ExtensionType::TransferFeeConfig], |mint| {
+            vec![initialize_transfer_fee_config(
+                &spl_token_2022::id(),
+                &mint.pubkey(),
+                None,
+                None,
+                500,
+                5000,
+            )
+            .unwrap()]
+        })
+        .await;
+
+    assert_ntt_error(result, NTTError::TransferFeeMint);
+}
+
+#[tokio::test]
+async fn test_zero_transfer_fee_supported() {
+    let (mut ctx, ntt, result) =
+        initialize_with_extensions(Mode::Locking, &[ExtensionType::TransferFeeConfig], |mint| {
+            vec![initialize_transfer_fee_config(
+                &spl_token_2022::id(),
+                &mint.pubkey(),
+                None,
+                None,
+                0,
+                0,
+            )
+            .unwrap()]
+        })
+        .await;
+    result.unwrap();
+
+    let config: Config = ctx.get_account_data_anchor(ntt.config()).await;
+    assert_eq!(
+        config.mint_extensions.extensions,
+        vec![ExtensionType::TransferFeeConfig as u16]
+    );
+    assert!(!config.mint_extensions.warned);
+}
+
+#[tokio::test]
+async fn test_supported_extensions() {
+    let (mut ctx, ntt, result) = initialize_with_extensions(
+        Mode::Burning,
+        &[ExtensionType::DefaultAccountState],
+        |mint| {
+            vec![initialize_default_account_state(
+                &spl_token_2022::id(),
+                &mint.pubkey(),
+                &AccountState::Initialized,
+            )
+            .unwrap()]
+        },
+    )
+    .await;
+    result.unwrap();
+
+    let config: Config = ctx.get_account_data_anchor(ntt.config()).await;
+    assert_eq!(
+        config.mint_extensions.extensions,
+        vec![ExtensionType::DefaultAccountState as u16]
+    );
+    assert!(!config.mint_extensions.warned);
+}
+
+#[tokio::test]
+async fn test_non_transferable_rejected() {
+    let (_, _, result) =
+        initialize_with_extensions(Mode::Burning, &[ExtensionType::NonTransferable], |mint| {
+            vec![
+                spl_token_2022::instruction::initialize_non_transferable_mint(
+                    &spl_token_2022::id(),
+                    &mint.pubkey(),
+                )
+                .unwrap(),
+            ]
+        })
+        .await;
+
+    assert_ntt_error(result, NTTError::NonTransferableMint);
+}
+
+#[tokio::test]
+async fn test_frozen_by_default_rejected() {
+    let (_, _, result) = initialize_with_extensions(
+        Mode::Locking,
+        &[ExtensionType::DefaultAccountState],
+        |mint| {
+            vec![initialize_default_account_state(
+                &spl_token_2022::id(),
+                &mint.pubkey(),
+                &AccountState::Frozen,
+            )
+            .unwrap()]
+        },
+    )
+    .await;
+
+    assert_ntt_error(result, NTTError::FrozenByDefaultMint);
+}
+
+#[tokio::test]
+async fn test_permanent_delegate() {
+    let delegate = Keypair::new().pubkey();
+    let init_delegate = |mint: &Keypair| {
+        vec![spl_token_2022::instruction::initialize_permanent_delegate(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            &delegate,
+        )
+        .unwrap()]
+    };
+
+    // the delegate could drain the custody account
+    let (_, _, result) = initialize_with_extensions(
+        Mode::Locking,
+        &[ExtensionType::PermanentDelegate],
+        init_delegate,
+    )
+    .await;
+    assert_ntt_error(result, NTTError::PermanentDelegateMint);
+
+    let (mut ctx, ntt, result) = initialize_with_extensions(
+        Mode::Burning,
+        &[ExtensionType::PermanentDelegate],
+        init_delegate,
+    )
+    .await;
+    result.unwrap();
+
+    let config: Config = ctx.get_account_data_anchor(ntt.config()).await;
+    assert!(config.mint_extensions.warned);
+}
@@ -0,0 +1,213 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use example_native_token_transfers::{
+    config::Config,
+    error::NTTError,
+    instructions::{InitializeArgs, TransferArgs},
+    queue::outbox::OutboxItem,
+    transceivers::wormhole::ReleaseOutboundArgs,
+};
+use ntt_messages::{chain_id::ChainId, mode::Mode};
+use solana_program::instruction::InstructionError;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::TransactionError};
+
+use crate::{
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{
+            setup, setup_accounts, setup_ntt, setup_programs, TestData, OTHER_CHAIN,
+            OUTBOUND_LIMIT, THIS_CHAIN,
+        },
+        submit::{Simulatable, Submittable},
+    },
+    sdk::{
+        instructions::{
+            initialize::{initialize, Initialize},
+            transfer::{approve_token_authority, transfer, Transfer},
+            views::get_outbound_capacity,
+        },
+        transceivers::wormhole::instructions::release_outbound::{
+            release_outbound, ReleaseOutbound,
+        },
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+/// Sets up a single deployment in multi-token mode, managing two mints.
+async fn setup_multi_token(mode: Mode) -> (ProgramTestContext, TestData, TestData) {
+    let program_owner = Keypair::new();
+    let program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    let mut ctx = program_test.start_with_context().await;
+
+    let other_owner = Keypair::from_bytes(&program_owner.to_bytes()).unwrap();
+
+    let mut test_data_a = setup_accounts(&mut ctx, program_owner).await;
+    test_data_a.ntt.multi_token_mint = Some(test_data_a.mint);
+    setup_ntt(&mut ctx, &test_data_a, mode).await;
+
+    let mut test_data_b = setup_accounts(&mut ctx, other_owner).await;
+    test_data_b.ntt.multi_token_mint = Some(test_data_b.mint);
+    setup_ntt(&mut ctx, &test_data_b, mode).await;
+
+    (ctx, test_data_a, test_data_b)
+}
+
+#[tokio::test]
+async fn test_multi_token_transfer() {
+    let (mut ctx, test_data_a, test_data_b) = setup_multi_token(Mode::Locking).await;
+
+    assert_ne!(test_data_a.ntt.config(), test_data_b.ntt.config());
+
+    let config_a: Config = ctx.get_account_data_anchor(test_data_a.ntt.config()).await;
+    let config_b: Config = ctx.get_account_data_anchor(test_data_b.ntt.config()).await;
+    assert!(config_a.multi_token && config_b.multi_token);
+    assert_eq!(config_a.mint, test_data_a.mint);
+    assert_eq!(config_b.mint, test_data_b.mint);
+
+    let outbox_item = Keypair::new();
+    let args = TransferArgs {
+        amount: 100,
+        recipient_chain: ChainId { id: OTHER_CHAIN },
+        recipient_address: [1u8; 32],
+        should_queue: false,
+    };
+
+    approve_token_authority(
+        &test_data_a.ntt,
+        &test_data_a.user_token_account,
+        &test_data_a.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data_a.user], &mut ctx)
+    .await
+    .unwrap();
+
+    transfer(
+        &test_data_a.ntt,
+        Transfer {
+            payer: ctx.payer.pubkey(),
+            mint: test_data_a.mint,
+            from: test_data_a.user_token_account,
+            from_authority: test_data_a.user.pubkey(),
+            peer: test_data_a.ntt.peer(OTHER_CHAIN),
+            outbox_item: outbox_item.pubkey(),
+        },
+        args,
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&outbox_item], &mut ctx)
+    .await
+    .unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(outbox_item_account.mint, test_data_a.mint);
+
+    // only the rate limit of the transferred token is consumed
+    let capacity_a: u64 = get_outbound_capacity(&test_data_a.ntt)
+        .simulate(&mut ctx)
+        .await;
+    let capacity_b: u64 = get_outbound_capacity(&test_data_b.ntt)
+        .simulate(&mut ctx)
+        .await;
+    assert_eq!(capacity_a, OUTBOUND_LIMIT - 100);
+    assert_eq!(capacity_b, OUTBOUND_LIMIT);
+
+    // the outbox item can't be released through the other token's config
+    let err = release_outbound(
+        &test_data_b.ntt,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidMint.into())
+        )
+    );
+
+    release_outbound(
+        &test_data_a.ntt,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+}
+
+async fn initialize_other_mint(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    multi_token: bool,
+) -> TransactionError {
+    let program_owner = Keypair::from_bytes(&test_data.program_owner.to_bytes()).unwrap();
+    let mut other = setup_accounts(ctx, program_owner).await;
+    other.ntt.multi_token_mint = multi_token.then_some(other.mint);
+
+    initialize(
+        &other.ntt,
+        Initialize {
+            payer: ctx.payer.pubkey(),
+            deployer: other.program_owner.pubkey(),
+            mint: other.mint,
+        },
+        InitializeArgs {
+            chain_id: THIS_CHAIN,
+            limit: OUTBOUND_LIMIT,
+            mode: Mode::Locking,
+            multi_token,
+        },
+    )
+    .submit_with_signers(&[&other.program_owner], ctx)
+    .await
+    .unwrap_err()
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_single_token_config_rejected_in_multi_token_program() {
+    let (mut ctx, test_data_a, _) = setup_multi_token(Mode::Locking).await;
+
+    let err = initialize_other_mint(&mut ctx, &test_data_a, false).await;
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::MixedTokenModes.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_multi_token_config_rejected_in_single_token_program() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let err = initialize_other_mint(&mut ctx, &test_data, true).await;
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::MixedTokenModes.into())
+        )
+    );
+}
@@ -0,0 +1,289 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use example_native_token_transfers::{
+    config::Config, error::NTTError, instructions::SetOwnerSetArgs,
+};
+use ntt_messages::mode::Mode;
+use solana_program::instruction::{AccountMeta, Instruction, InstructionError};
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::TransactionError};
+
+use crate::{
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{setup, TestData},
+        submit::Submittable,
+    },
+    sdk::instructions::admin::{
+        cancel_ownership_transfer, claim_ownership, set_owner_set, set_paused, transfer_ownership,
+        CancelOwnershipTransfer, ClaimOwnership, SetOwnerSet, SetPaused, TransferOwnership,
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+fn with_additional_signers(mut ix: Instruction, signers: &[&Keypair]) -> Instruction {
+    ix.accounts.extend(
+        signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(signer.pubkey(), true)),
+    );
+    ix
+}
+
+async fn setup_owner_set(ctx: &mut ProgramTestContext, test_data: &TestData) -> [Keypair; 3] {
+    let owners = [Keypair::new(), Keypair::new(), Keypair::new()];
+
+    set_owner_set(
+        &test_data.ntt,
+        SetOwnerSet {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: None,
+        },
+        SetOwnerSetArgs {
+            keys: owners.iter().map(|owner| owner.pubkey()).collect(),
+            threshold: 2,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+
+    owners
+}
+
+#[tokio::test]
+async fn test_owner_set_threshold() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let owners = setup_owner_set(&mut ctx, &test_data).await;
+
+    // a single member is not enough
+    let err = set_paused(
+        &test_data.ntt,
+        SetPaused {
+            owner: owners[0].pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&owners[0]], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InsufficientOwnerSignatures.into())
+        )
+    );
+
+    // the same member passed twice only counts once
+    let err = with_additional_signers(
+        set_paused(
+            &test_data.ntt,
+            SetPaused {
+                owner: owners[0].pubkey(),
+            },
+            true,
+        ),
+        &[&owners[0]],
+    )
+    .submit_with_signers(&[&owners[0]], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InsufficientOwnerSignatures.into())
+        )
+    );
+
+    with_additional_signers(
+        set_paused(
+            &test_data.ntt,
+            SetPaused {
+                owner: owners[0].pubkey(),
+            },
+            true,
+        ),
+        &[&owners[2]],
+    )
+    .submit_with_signers(&[&owners[0], &owners[2]], &mut ctx)
+    .await
+    .unwrap();
+
+    let config: Config = ctx.get_account_data_anchor(test_data.ntt.config()).await;
+    assert!(config.paused);
+}
+
+#[tokio::test]
+async fn test_owner_set_replaces_single_owner() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    setup_owner_set(&mut ctx, &test_data).await;
+
+    let err = set_paused(
+        &test_data.ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidOwner.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_owner_set() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let owner = Keypair::new();
+    let err = set_owner_set(
+        &test_data.ntt,
+        SetOwnerSet {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: None,
+        },
+        SetOwnerSetArgs {
+            keys: vec![owner.pubkey(), owner.pubkey()],
+            threshold: 1,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidOwnerSet.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_owner_set_transfers_ownership() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let owners = setup_owner_set(&mut ctx, &test_data).await;
+    let new_owner = Keypair::new();
+
+    let transfer = || {
+        with_additional_signers(
+            transfer_ownership(
+                &test_data.ntt,
+                TransferOwnership {
+                    owner: owners[0].pubkey(),
+                    new_owner: new_owner.pubkey(),
+                },
+            ),
+            &[&owners[1]],
+        )
+    };
+
+    // the set doesn't include `config.owner`, which doesn't have to sign
+    transfer()
+        .submit_with_signers(&[&owners[0], &owners[1]], &mut ctx)
+        .await
+        .unwrap();
+
+    // with an owner set, the transfer can't be cancelled by claiming it
+    let err = claim_ownership(
+        &test_data.ntt,
+        ClaimOwnership {
+            new_owner: test_data.program_owner.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidPendingOwner.into())
+        )
+    );
+
+    with_additional_signers(
+        cancel_ownership_transfer(
+            &test_data.ntt,
+            CancelOwnershipTransfer {
+                owner: owners[1].pubkey(),
+            },
+        ),
+        &[&owners[2]],
+    )
+    .submit_with_signers(&[&owners[1], &owners[2]], &mut ctx)
+    .await
+    .unwrap();
+
+    let config: Config = ctx.get_account_data_anchor(test_data.ntt.config()).await;
+    assert_eq!(config.pending_owner, None);
+
+    transfer()
+        .submit_with_signers(&[&owners[0], &owners[1]], &mut ctx)
+        .await
+        .unwrap();
+
+    claim_ownership(
+        &test_data.ntt,
+        ClaimOwnership {
+            new_owner: new_owner.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&new_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // the new owner is the sole owner
+    let config: Config = ctx.get_account_data_anchor(test_data.ntt.config()).await;
+    assert_eq!(config.owner, new_owner.pubkey());
+    assert!(config.owner_set.keys.is_empty());
+
+    let err = with_additional_signers(
+        set_paused(
+            &test_data.ntt,
+            SetPaused {
+                owner: owners[0].pubkey(),
+            },
+            true,
+        ),
+        &[&owners[1]],
+    )
+    .submit_with_signers(&[&owners[0], &owners[1]], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidOwner.into())
+        )
+    );
+
+    set_paused(
+        &test_data.ntt,
+        SetPaused {
+            owner: new_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&new_owner], &mut ctx)
+    .await
+    .unwrap();
+}
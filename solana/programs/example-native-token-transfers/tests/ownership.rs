@@ -0,0 +1,119 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use anchor_lang::prelude::*;
+use example_native_token_transfers::{
+    config::Config,
+    error::NTTError,
+    instructions::{RenounceOwnershipArgs, RENOUNCE_OWNERSHIP_CONFIRMATION},
+};
+use ntt_messages::mode::Mode;
+use solana_program_test::*;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::{
+    common::{
+        query::GetAccountDataAnchor,
+        setup::setup,
+        submit::{assert_ntt_error, Submittable},
+    },
+    sdk::instructions::admin::{
+        cancel_ownership_transfer, renounce_ownership, set_paused, transfer_ownership,
+        CancelOwnershipTransfer, RenounceOwnership, SetPaused, TransferOwnership,
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+#[tokio::test]
+async fn test_cancel_ownership_transfer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let owner = test_data.program_owner.pubkey();
+
+    let cancel = || cancel_ownership_transfer(&test_data.ntt, CancelOwnershipTransfer { owner });
+
+    // nothing to cancel
+    let result = cancel()
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await;
+    assert_ntt_error(result, NTTError::NoPendingOwner);
+
+    transfer_ownership(
+        &test_data.ntt,
+        TransferOwnership {
+            owner,
+            new_owner: Keypair::new().pubkey(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let program_data: ProgramData = ctx
+        .get_account_data_anchor(test_data.ntt.program_data())
+        .await;
+    assert_eq!(
+        program_data.upgrade_authority_address,
+        Some(test_data.ntt.upgrade_lock())
+    );
+
+    cancel()
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let config: Config = ctx.get_account_data_anchor(test_data.ntt.config()).await;
+    assert_eq!(config.owner, owner);
+    assert_eq!(config.pending_owner, None);
+
+    let program_data: ProgramData = ctx
+        .get_account_data_anchor(test_data.ntt.program_data())
+        .await;
+    assert_eq!(program_data.upgrade_authority_address, Some(owner));
+}
+
+#[tokio::test]
+async fn test_renounce_ownership() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    let owner = test_data.program_owner.pubkey();
+
+    let renounce = |confirmation: &str| {
+        renounce_ownership(
+            &test_data.ntt,
+            RenounceOwnership {
+                owner,
+                pending_action: None,
+            },
+            RenounceOwnershipArgs {
+                confirmation: confirmation.to_string(),
+            },
+        )
+    };
+
+    let result = renounce("yes")
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await;
+    assert_ntt_error(result, NTTError::InvalidRenounceConfirmation);
+
+    renounce(RENOUNCE_OWNERSHIP_CONFIRMATION)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let config: Config = ctx.get_account_data_anchor(test_data.ntt.config()).await;
+    assert_eq!(config.owner, Pubkey::default());
+    assert_eq!(config.pending_owner, None);
+
+    // the program is immutable
+    let program_data: ProgramData = ctx
+        .get_account_data_anchor(test_data.ntt.program_data())
+        .await;
+    assert_eq!(program_data.upgrade_authority_address, None);
+
+    // the former owner can't execute admin instructions anymore
+    let result = set_paused(&test_data.ntt, SetPaused { owner }, true)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await;
+    assert_ntt_error(result, NTTError::InvalidOwner);
+}
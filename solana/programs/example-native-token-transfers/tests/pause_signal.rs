@@ -0,0 +1,264 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use anchor_lang::prelude::Pubkey;
+use example_native_token_transfers::{
+    error::NTTError, instructions::TransferArgs, peer::NttManagerPeer,
+    transceivers::accounts::peer::TransceiverPeer,
+};
+use ntt_messages::{
+    chain_id::ChainId, mode::Mode, ntt_manager::NttManagerMessage, pause::PauseSignal,
+    transceiver::TransceiverMessage, transceivers::wormhole::WormholeTransceiver,
+};
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::InstructionError, signature::Keypair, signer::Signer,
+    transaction::TransactionError,
+};
+use wormhole_anchor_sdk::wormhole::PostedVaa;
+use wormhole_sdk::{Address, Vaa};
+
+use crate::{
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{setup, TestData, OTHER_CHAIN, OTHER_MANAGER, OTHER_TRANSCEIVER, THIS_CHAIN},
+        submit::Submittable,
+    },
+    sdk::{
+        instructions::{
+            post_vaa::post_vaa,
+            transfer::{approve_token_authority, transfer, Transfer},
+        },
+        transceivers::wormhole::instructions::pause_signal::{
+            receive_pause_signal, send_pause_signal, ReceivePauseSignal, SendPauseSignal,
+        },
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+async fn post_pause_signal_vaa(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    source_ntt_manager: [u8; 32],
+    paused: bool,
+    sequence: u64,
+) -> Pubkey {
+    let transceiver_message: TransceiverMessage<WormholeTransceiver, PauseSignal> =
+        TransceiverMessage::new(
+            source_ntt_manager,
+            test_data.ntt.program.to_bytes(),
+            NttManagerMessage {
+                id: [sequence as u8; 32],
+                sender: [4u8; 32],
+                payload: PauseSignal {
+                    paused,
+                    to_chain: ChainId { id: THIS_CHAIN },
+                },
+            },
+            vec![],
+        );
+
+    let vaa = Vaa {
+        version: 1,
+        guardian_set_index: 0,
+        signatures: vec![],
+        timestamp: 123232,
+        nonce: 0,
+        emitter_chain: OTHER_CHAIN.into(),
+        emitter_address: Address(OTHER_TRANSCEIVER),
+        sequence,
+        consistency_level: 0,
+        payload: transceiver_message,
+    };
+
+    post_vaa(&test_data.ntt.wormhole, ctx, vaa).await
+}
+
+async fn try_transfer(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+) -> Result<(), BanksClientError> {
+    let outbox_item = Keypair::new();
+    let args = TransferArgs {
+        amount: 100,
+        recipient_chain: ChainId { id: OTHER_CHAIN },
+        recipient_address: [1u8; 32],
+        should_queue: false,
+    };
+
+    approve_token_authority(
+        &test_data.ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+
+    transfer(
+        &test_data.ntt,
+        Transfer {
+            payer: ctx.payer.pubkey(),
+            mint: test_data.mint,
+            from: test_data.user_token_account,
+            from_authority: test_data.user.pubkey(),
+            peer: test_data.ntt.peer(OTHER_CHAIN),
+            outbox_item: outbox_item.pubkey(),
+        },
+        args,
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&outbox_item], ctx)
+    .await
+}
+
+#[tokio::test]
+async fn test_send_pause_signal() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let wh_message = Keypair::new();
+    let err = send_pause_signal(
+        &test_data.ntt,
+        SendPauseSignal {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.user.pubkey(),
+            wormhole_message: wh_message.pubkey(),
+            chain_id: OTHER_CHAIN,
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.user, &wh_message], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidOwner.into())
+        )
+    );
+
+    send_pause_signal(
+        &test_data.ntt,
+        SendPauseSignal {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+            wormhole_message: wh_message.pubkey(),
+            chain_id: OTHER_CHAIN,
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner, &wh_message], &mut ctx)
+    .await
+    .unwrap();
+
+    let msg: PostedVaa<TransceiverMessage<WormholeTransceiver, PauseSignal>> = ctx
+        .get_account_data_anchor_unchecked(wh_message.pubkey())
+        .await;
+
+    assert_eq!(
+        msg.data().source_ntt_manager,
+        test_data.ntt.program.to_bytes()
+    );
+    assert_eq!(msg.data().recipient_ntt_manager, OTHER_MANAGER);
+    assert_eq!(
+        msg.data().ntt_manager_payload.payload,
+        PauseSignal {
+            paused: true,
+            to_chain: ChainId { id: OTHER_CHAIN },
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_receive_pause_signal() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let pause = post_pause_signal_vaa(&mut ctx, &test_data, OTHER_MANAGER, true, 5).await;
+    receive_pause_signal(
+        &test_data.ntt,
+        ReceivePauseSignal {
+            vaa: pause,
+            chain_id: OTHER_CHAIN,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let peer: NttManagerPeer = ctx
+        .get_account_data_anchor(test_data.ntt.peer(OTHER_CHAIN))
+        .await;
+    assert!(peer.outbound_paused);
+
+    let err = try_transfer(&mut ctx, &test_data).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::PeerOutboundPaused.into())
+        )
+    );
+
+    // an older signal can't undo a newer one
+    let stale = post_pause_signal_vaa(&mut ctx, &test_data, OTHER_MANAGER, false, 4).await;
+    let err = receive_pause_signal(
+        &test_data.ntt,
+        ReceivePauseSignal {
+            vaa: stale,
+            chain_id: OTHER_CHAIN,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::StalePauseSignal.into())
+        )
+    );
+
+    // signals have to come from the registered manager peer
+    let forged = post_pause_signal_vaa(&mut ctx, &test_data, [1u8; 32], false, 6).await;
+    let err = receive_pause_signal(
+        &test_data.ntt,
+        ReceivePauseSignal {
+            vaa: forged,
+            chain_id: OTHER_CHAIN,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidNttManagerPeer.into())
+        )
+    );
+
+    let unpause = post_pause_signal_vaa(&mut ctx, &test_data, OTHER_MANAGER, false, 6).await;
+    receive_pause_signal(
+        &test_data.ntt,
+        ReceivePauseSignal {
+            vaa: unpause,
+            chain_id: OTHER_CHAIN,
+        },
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let transceiver_peer: TransceiverPeer = ctx
+        .get_account_data_anchor(test_data.ntt.transceiver_peer(OTHER_CHAIN))
+        .await;
+    assert_eq!(transceiver_peer.pause_signal_sequence, Some(6));
+
+    try_transfer(&mut ctx, &test_data).await.unwrap();
+}
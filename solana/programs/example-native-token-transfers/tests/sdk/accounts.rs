@@ -1,12 +1,15 @@
 use anchor_lang::prelude::Pubkey;
 use example_native_token_transfers::{
-    config::Config,
+    config::{Config, TokenMode},
+    custody_ledger::CustodyLedger,
     instructions::TransferArgs,
     queue::{
         inbox::{InboxItem, InboxRateLimit},
         outbox::OutboxRateLimit,
     },
+    registered_receiver::{receiver_authority, RegisteredReceiver, RECEIVER_CALLER_SEED},
     registered_transceiver::RegisteredTransceiver,
+    timelock::{AdminAction, PendingAdminAction},
     transfer::Payload,
     SESSION_AUTHORITY_SEED, TOKEN_AUTHORITY_SEED,
 };
@@ -70,23 +73,59 @@ impl Governance {
 pub struct NTT {
     pub program: Pubkey,
     pub wormhole: Wormhole,
+    /// Set when the deployment is in multi-token mode, in which case the
+    /// per-token accounts are derived with this mint.
+    pub multi_token_mint: Option<Pubkey>,
 }
 
 impl NTT {
+    pub fn mint_seed(&self) -> &[u8] {
+        match &self.multi_token_mint {
+            Some(mint) => mint.as_ref(),
+            None => &[],
+        }
+    }
+
     pub fn config(&self) -> Pubkey {
+        let (config, _) =
+            Pubkey::find_program_address(&[Config::SEED_PREFIX, self.mint_seed()], &self.program);
+        config
+    }
+
+    pub fn single_token_config(&self) -> Pubkey {
         let (config, _) = Pubkey::find_program_address(&[Config::SEED_PREFIX], &self.program);
         config
     }
 
+    pub fn token_mode(&self) -> Pubkey {
+        let (token_mode, _) =
+            Pubkey::find_program_address(&[TokenMode::SEED_PREFIX], &self.program);
+        token_mode
+    }
+
     pub fn outbox_rate_limit(&self) -> Pubkey {
-        let (outbox_rate_limit, _) =
-            Pubkey::find_program_address(&[OutboxRateLimit::SEED_PREFIX], &self.program);
+        let (outbox_rate_limit, _) = Pubkey::find_program_address(
+            &[OutboxRateLimit::SEED_PREFIX, self.mint_seed()],
+            &self.program,
+        );
         outbox_rate_limit
     }
 
+    pub fn custody_ledger(&self) -> Pubkey {
+        let (custody_ledger, _) = Pubkey::find_program_address(
+            &[CustodyLedger::SEED_PREFIX, self.mint_seed()],
+            &self.program,
+        );
+        custody_ledger
+    }
+
     pub fn inbox_rate_limit(&self, chain: u16) -> Pubkey {
         let (inbox_rate_limit, _) = Pubkey::find_program_address(
-            &[InboxRateLimit::SEED_PREFIX, &chain.to_be_bytes()],
+            &[
+                InboxRateLimit::SEED_PREFIX,
+                &chain.to_be_bytes(),
+                self.mint_seed(),
+            ],
             &self.program,
         );
         inbox_rate_limit
@@ -122,31 +161,61 @@ impl NTT {
         chain: u16,
         ntt_manager_message: NttManagerMessage<NativeTokenTransfer<Payload>>,
     ) -> Pubkey {
-        let mut hasher = Keccak256::new();
-        hasher.update(chain.to_be_bytes());
-        hasher.update(&TypePrefixedPayload::to_vec_payload(&ntt_manager_message));
-
         let (inbox_item, _) = Pubkey::find_program_address(
-            &[InboxItem::SEED_PREFIX, &hasher.finalize()],
+            &[
+                InboxItem::SEED_PREFIX,
+                &message_hash(chain, &ntt_manager_message),
+                self.mint_seed(),
+            ],
             &self.program,
         );
         inbox_item
     }
 
     pub fn token_authority(&self) -> Pubkey {
-        let (token_authority, _) =
-            Pubkey::find_program_address(&[TOKEN_AUTHORITY_SEED.as_ref()], &self.program);
+        let (token_authority, _) = Pubkey::find_program_address(
+            &[TOKEN_AUTHORITY_SEED.as_ref(), self.mint_seed()],
+            &self.program,
+        );
         token_authority
     }
 
     pub fn registered_transceiver(&self, transceiver: &Pubkey) -> Pubkey {
         let (registered_transceiver, _) = Pubkey::find_program_address(
-            &[RegisteredTransceiver::SEED_PREFIX, transceiver.as_ref()],
+            &[
+                RegisteredTransceiver::SEED_PREFIX,
+                transceiver.as_ref(),
+                self.mint_seed(),
+            ],
             &self.program,
         );
         registered_transceiver
     }
 
+    /// The registered receiver account of the recipient `authority`.
+    /// Exists only if `authority` is the authority of a registered receiver.
+    pub fn registered_receiver(&self, authority: &Pubkey) -> Pubkey {
+        let (registered_receiver, _) = Pubkey::find_program_address(
+            &[
+                RegisteredReceiver::SEED_PREFIX,
+                authority.as_ref(),
+                self.mint_seed(),
+            ],
+            &self.program,
+        );
+        registered_receiver
+    }
+
+    pub fn receiver_authority(&self, receiver: &Pubkey) -> Pubkey {
+        receiver_authority(receiver)
+    }
+
+    pub fn receiver_caller(&self) -> Pubkey {
+        let (receiver_caller, _) =
+            Pubkey::find_program_address(&[RECEIVER_CALLER_SEED], &self.program);
+        receiver_caller
+    }
+
     pub fn emitter(&self) -> Pubkey {
         let (emitter, _) = Pubkey::find_program_address(&[b"emitter".as_ref()], &self.program);
         emitter
@@ -160,23 +229,70 @@ impl NTT {
         wormhole_message
     }
 
+    pub fn reusable_wormhole_message(&self, payer: &Pubkey, message_index: u16) -> Pubkey {
+        let (wormhole_message, _) = Pubkey::find_program_address(
+            &[
+                b"reusable_message".as_ref(),
+                payer.as_ref(),
+                &message_index.to_be_bytes(),
+            ],
+            &self.program,
+        );
+        wormhole_message
+    }
+
+    pub fn approved_relayer(&self, relayer: &Pubkey) -> Pubkey {
+        let (approved_relayer, _) = Pubkey::find_program_address(
+            &[
+                b"approved_relayer".as_ref(),
+                relayer.as_ref(),
+                self.mint_seed(),
+            ],
+            &self.program,
+        );
+        approved_relayer
+    }
+
     pub fn peer(&self, chain: u16) -> Pubkey {
-        let (peer, _) =
-            Pubkey::find_program_address(&[b"peer".as_ref(), &chain.to_be_bytes()], &self.program);
+        let (peer, _) = Pubkey::find_program_address(
+            &[b"peer".as_ref(), &chain.to_be_bytes(), self.mint_seed()],
+            &self.program,
+        );
         peer
     }
 
     pub fn transceiver_peer(&self, chain: u16) -> Pubkey {
         let (peer, _) = Pubkey::find_program_address(
-            &[b"transceiver_peer".as_ref(), &chain.to_be_bytes()],
+            &[
+                b"transceiver_peer".as_ref(),
+                &chain.to_be_bytes(),
+                self.mint_seed(),
+            ],
             &self.program,
         );
         peer
     }
 
+    pub fn pending_peer(&self, chain: u16, transceiver_address: &[u8; 32]) -> Pubkey {
+        let (pending_peer, _) = Pubkey::find_program_address(
+            &[
+                b"pending_peer".as_ref(),
+                &chain.to_be_bytes(),
+                transceiver_address,
+            ],
+            &self.program,
+        );
+        pending_peer
+    }
+
     pub fn transceiver_message(&self, chain: u16, id: [u8; 32]) -> Pubkey {
         let (transceiver_message, _) = Pubkey::find_program_address(
-            &[b"transceiver_message".as_ref(), &chain.to_be_bytes(), &id],
+            &[
+                b"transceiver_message".as_ref(),
+                &chain.to_be_bytes(),
+                &id,
+                self.mint_seed(),
+            ],
             &self.program,
         );
         transceiver_message
@@ -208,8 +324,53 @@ impl NTT {
         addr
     }
 
+    pub fn pending_admin_action(&self, action: &AdminAction) -> Pubkey {
+        let (pending_action, _) = Pubkey::find_program_address(
+            &[
+                PendingAdminAction::SEED_PREFIX,
+                &action.keccak256().0,
+                self.mint_seed(),
+            ],
+            &self.program,
+        );
+        pending_action
+    }
+
     pub fn upgrade_lock(&self) -> Pubkey {
         let (addr, _) = Pubkey::find_program_address(&[b"upgrade_lock"], &self.program);
         addr
     }
+
+    pub fn lut_authority(&self) -> Pubkey {
+        let (lut_authority, _) =
+            Pubkey::find_program_address(&[b"lut_authority", self.mint_seed()], &self.program);
+        lut_authority
+    }
+
+    pub fn lut(&self) -> Pubkey {
+        let (lut, _) = Pubkey::find_program_address(&[b"lut", self.mint_seed()], &self.program);
+        lut
+    }
+
+    /// The address of the lookup table created from `recent_slot`.
+    pub fn lut_address(&self, recent_slot: u64) -> Pubkey {
+        let (lut_address, _) =
+            solana_address_lookup_table_program::instruction::derive_lookup_table_address(
+                &self.lut_authority(),
+                recent_slot,
+            );
+        lut_address
+    }
+}
+
+/// The hash of an NTT manager message received from `chain`, which keys its
+/// inbox item.
+pub fn message_hash(
+    chain: u16,
+    ntt_manager_message: &NttManagerMessage<NativeTokenTransfer<Payload>>,
+) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(chain.to_be_bytes());
+    hasher.update(&TypePrefixedPayload::to_vec_payload(ntt_manager_message));
+    hasher.finalize().into()
 }
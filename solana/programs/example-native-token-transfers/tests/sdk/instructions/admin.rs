@@ -1,9 +1,114 @@
 use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
-use example_native_token_transfers::instructions::SetPeerArgs;
-use solana_sdk::instruction::Instruction;
+use example_native_token_transfers::{
+    instructions::{
+        RenounceOwnershipArgs, SetMaxQueueAgeArgs, SetMaxQueuedOutboundArgs, SetOutboundLimitArgs,
+        SetOutboundLimitRampArgs, SetOwnerSetArgs, SetPeerArgs, SetSettlementDelaysArgs,
+        SetTimelockDelayArgs,
+    },
+    timelock::AdminAction,
+};
+use solana_sdk::{instruction::Instruction, sysvar};
+use wormhole_solana_utils::cpi::bpf_loader_upgradeable;
 
 use crate::sdk::accounts::NTT;
 
+pub struct TransferOwnership {
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+pub fn transfer_ownership(ntt: &NTT, accounts: TransferOwnership) -> Instruction {
+    let data = example_native_token_transfers::instruction::TransferOwnership {};
+
+    let accounts = example_native_token_transfers::accounts::TransferOwnership {
+        config: ntt.config(),
+        owner: accounts.owner,
+        pending_action: None,
+        new_owner: accounts.new_owner,
+        upgrade_lock: ntt.upgrade_lock(),
+        program_data: ntt.program_data(),
+        bpf_loader_upgradeable_program: bpf_loader_upgradeable::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct CancelOwnershipTransfer {
+    pub owner: Pubkey,
+}
+
+pub fn cancel_ownership_transfer(ntt: &NTT, accounts: CancelOwnershipTransfer) -> Instruction {
+    let data = example_native_token_transfers::instruction::CancelOwnershipTransfer {};
+
+    let accounts = example_native_token_transfers::accounts::CancelOwnershipTransfer {
+        config: ntt.config(),
+        owner: accounts.owner,
+        upgrade_lock: ntt.upgrade_lock(),
+        program_data: ntt.program_data(),
+        bpf_loader_upgradeable_program: bpf_loader_upgradeable::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ClaimOwnership {
+    pub new_owner: Pubkey,
+}
+
+pub fn claim_ownership(ntt: &NTT, accounts: ClaimOwnership) -> Instruction {
+    let data = example_native_token_transfers::instruction::ClaimOwnership {};
+
+    let accounts = example_native_token_transfers::accounts::ClaimOwnership {
+        config: ntt.config(),
+        upgrade_lock: ntt.upgrade_lock(),
+        new_owner: accounts.new_owner,
+        program_data: ntt.program_data(),
+        bpf_loader_upgradeable_program: bpf_loader_upgradeable::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct RenounceOwnership {
+    pub owner: Pubkey,
+    pub pending_action: Option<Pubkey>,
+}
+
+pub fn renounce_ownership(
+    ntt: &NTT,
+    accounts: RenounceOwnership,
+    args: RenounceOwnershipArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::RenounceOwnership { args };
+
+    let accounts = example_native_token_transfers::accounts::RenounceOwnership {
+        config: ntt.config(),
+        owner: accounts.owner,
+        pending_action: accounts.pending_action,
+        upgrade_lock: ntt.upgrade_lock(),
+        program_data: ntt.program_data(),
+        bpf_loader_upgradeable_program: bpf_loader_upgradeable::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
 pub struct SetPeer {
     pub payer: Pubkey,
     pub owner: Pubkey,
@@ -18,6 +123,7 @@ pub fn set_peer(ntt: &NTT, accounts: SetPeer, args: SetPeerArgs) -> Instruction
         config: ntt.config(),
         owner: accounts.owner,
         payer: accounts.payer,
+        pending_action: None,
         peer: ntt.peer(chain_id),
         inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
         system_program: System::id(),
@@ -30,6 +136,33 @@ pub fn set_peer(ntt: &NTT, accounts: SetPeer, args: SetPeerArgs) -> Instruction
     }
 }
 
+pub struct SetSettlementDelays {
+    pub owner: Pubkey,
+    pub pending_action: Option<Pubkey>,
+}
+
+pub fn set_settlement_delays(
+    ntt: &NTT,
+    accounts: SetSettlementDelays,
+    args: SetSettlementDelaysArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetSettlementDelays { args };
+
+    let accounts = example_native_token_transfers::accounts::SetSettlementDelays {
+        owner: accounts.owner,
+        config: ntt.config(),
+        pending_action: accounts.pending_action,
+        peer: ntt.peer(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
 pub struct SetPaused {
     pub owner: Pubkey,
 }
@@ -49,6 +182,37 @@ pub fn set_paused(ntt: &NTT, accounts: SetPaused, pause: bool) -> Instruction {
     }
 }
 
+pub struct Upgrade {
+    pub owner: Pubkey,
+    pub buffer: Pubkey,
+    pub spill: Pubkey,
+    pub pending_action: Option<Pubkey>,
+}
+
+pub fn upgrade(ntt: &NTT, accounts: Upgrade) -> Instruction {
+    let data = example_native_token_transfers::instruction::Upgrade {};
+
+    let accounts = example_native_token_transfers::accounts::Upgrade {
+        config: ntt.config(),
+        owner: accounts.owner,
+        pending_action: accounts.pending_action,
+        upgrade_lock: ntt.upgrade_lock(),
+        program_data: ntt.program_data(),
+        program: ntt.program,
+        buffer: accounts.buffer,
+        spill: accounts.spill,
+        rent: sysvar::rent::id(),
+        clock: sysvar::clock::id(),
+        bpf_loader_upgradeable_program: bpf_loader_upgradeable::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
 pub struct RegisterTransceiver {
     pub payer: Pubkey,
     pub owner: Pubkey,
@@ -62,6 +226,7 @@ pub fn register_transceiver(ntt: &NTT, accounts: RegisterTransceiver) -> Instruc
         config: ntt.config(),
         owner: accounts.owner,
         payer: accounts.payer,
+        pending_action: None,
         transceiver: accounts.transceiver,
         registered_transceiver: ntt.registered_transceiver(&accounts.transceiver),
         system_program: System::id(),
@@ -73,3 +238,377 @@ pub fn register_transceiver(ntt: &NTT, accounts: RegisterTransceiver) -> Instruc
         data: data.data(),
     }
 }
+
+pub struct SetOutboundLimit {
+    pub owner: Pubkey,
+    pub pending_action: Option<Pubkey>,
+}
+
+pub fn set_outbound_limit(
+    ntt: &NTT,
+    accounts: SetOutboundLimit,
+    args: SetOutboundLimitArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetOutboundLimit { args };
+
+    let accounts = example_native_token_transfers::accounts::SetOutboundLimit {
+        config: ntt.config(),
+        owner: accounts.owner,
+        pending_action: accounts.pending_action,
+        rate_limit: ntt.outbox_rate_limit(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetOutboundLimitRamp {
+    pub owner: Pubkey,
+    pub pending_action: Option<Pubkey>,
+}
+
+pub fn set_outbound_limit_ramp(
+    ntt: &NTT,
+    accounts: SetOutboundLimitRamp,
+    args: SetOutboundLimitRampArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetOutboundLimitRamp { args };
+
+    let accounts = example_native_token_transfers::accounts::SetOutboundLimitRamp {
+        config: ntt.config(),
+        owner: accounts.owner,
+        pending_action: accounts.pending_action,
+        rate_limit: ntt.outbox_rate_limit(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn cancel_outbound_limit_ramp(ntt: &NTT, owner: Pubkey) -> Instruction {
+    let data = example_native_token_transfers::instruction::CancelOutboundLimitRamp {};
+
+    let accounts = example_native_token_transfers::accounts::CancelOutboundLimitRamp {
+        config: ntt.config(),
+        owner,
+        rate_limit: ntt.outbox_rate_limit(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetMaxQueuedOutbound {
+    pub owner: Pubkey,
+    pub pending_action: Option<Pubkey>,
+}
+
+pub fn set_max_queued_outbound(
+    ntt: &NTT,
+    accounts: SetMaxQueuedOutbound,
+    args: SetMaxQueuedOutboundArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetMaxQueuedOutbound { args };
+
+    let accounts = example_native_token_transfers::accounts::SetMaxQueuedOutbound {
+        config: ntt.config(),
+        owner: accounts.owner,
+        pending_action: accounts.pending_action,
+        rate_limit: ntt.outbox_rate_limit(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ProposeAdminAction {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn propose_admin_action(
+    ntt: &NTT,
+    accounts: ProposeAdminAction,
+    action: AdminAction,
+) -> Instruction {
+    let pending_action = ntt.pending_admin_action(&action);
+    let data = example_native_token_transfers::instruction::ProposeAdminAction { action };
+
+    let accounts = example_native_token_transfers::accounts::ProposeAdminAction {
+        payer: accounts.payer,
+        owner: accounts.owner,
+        config: ntt.config(),
+        pending_action,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct CancelAdminAction {
+    pub owner: Pubkey,
+    pub pending_action: Pubkey,
+}
+
+pub fn cancel_admin_action(ntt: &NTT, accounts: CancelAdminAction) -> Instruction {
+    let data = example_native_token_transfers::instruction::CancelAdminAction {};
+
+    let accounts = example_native_token_transfers::accounts::CancelAdminAction {
+        owner: accounts.owner,
+        config: ntt.config(),
+        pending_action: accounts.pending_action,
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetTimelockDelay {
+    pub owner: Pubkey,
+    pub pending_action: Option<Pubkey>,
+}
+
+pub fn set_timelock_delay(
+    ntt: &NTT,
+    accounts: SetTimelockDelay,
+    args: SetTimelockDelayArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetTimelockDelay { args };
+
+    let accounts = example_native_token_transfers::accounts::SetTimelockDelay {
+        owner: accounts.owner,
+        config: ntt.config(),
+        pending_action: accounts.pending_action,
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetOwnerSet {
+    pub owner: Pubkey,
+    pub pending_action: Option<Pubkey>,
+}
+
+pub fn set_owner_set(ntt: &NTT, accounts: SetOwnerSet, args: SetOwnerSetArgs) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetOwnerSet { args };
+
+    let accounts = example_native_token_transfers::accounts::SetOwnerSet {
+        owner: accounts.owner,
+        config: ntt.config(),
+        pending_action: accounts.pending_action,
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetMaxQueueAge {
+    pub owner: Pubkey,
+    pub pending_action: Option<Pubkey>,
+}
+
+pub fn set_max_queue_age(
+    ntt: &NTT,
+    accounts: SetMaxQueueAge,
+    args: SetMaxQueueAgeArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetMaxQueueAge { args };
+
+    let accounts = example_native_token_transfers::accounts::SetMaxQueueAge {
+        owner: accounts.owner,
+        config: ntt.config(),
+        pending_action: accounts.pending_action,
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetCapacityAwareRelease {
+    pub owner: Pubkey,
+    pub pending_action: Option<Pubkey>,
+}
+
+pub fn set_capacity_aware_release(
+    ntt: &NTT,
+    accounts: SetCapacityAwareRelease,
+    enabled: bool,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetCapacityAwareRelease { enabled };
+
+    let accounts = example_native_token_transfers::accounts::SetCapacityAwareRelease {
+        owner: accounts.owner,
+        config: ntt.config(),
+        pending_action: accounts.pending_action,
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetOperator {
+    pub owner: Pubkey,
+    pub pending_action: Option<Pubkey>,
+}
+
+pub fn set_operator(ntt: &NTT, accounts: SetOperator, operator: Option<Pubkey>) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetOperator { operator };
+
+    let accounts = example_native_token_transfers::accounts::SetOperator {
+        owner: accounts.owner,
+        config: ntt.config(),
+        pending_action: accounts.pending_action,
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct OverrideInboxItem {
+    pub authority: Pubkey,
+    pub inbox_item: Pubkey,
+}
+
+pub fn expedite_inbox_item(ntt: &NTT, accounts: OverrideInboxItem) -> Instruction {
+    let data = example_native_token_transfers::instruction::ExpediteInboxItem {};
+
+    let accounts = example_native_token_transfers::accounts::OverrideInboxItem {
+        authority: accounts.authority,
+        config: ntt.config(),
+        inbox_item: accounts.inbox_item,
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn set_inbox_item_frozen(ntt: &NTT, accounts: OverrideInboxItem, frozen: bool) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetInboxItemFrozen { frozen };
+
+    let accounts = example_native_token_transfers::accounts::OverrideInboxItem {
+        authority: accounts.authority,
+        config: ntt.config(),
+        inbox_item: accounts.inbox_item,
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct RegisterReceiver {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+    pub receiver: Pubkey,
+    pub pending_action: Option<Pubkey>,
+}
+
+pub fn register_receiver(ntt: &NTT, accounts: RegisterReceiver) -> Instruction {
+    let data = example_native_token_transfers::instruction::RegisterReceiver {};
+
+    let accounts = example_native_token_transfers::accounts::RegisterReceiver {
+        config: ntt.config(),
+        owner: accounts.owner,
+        payer: accounts.payer,
+        pending_action: accounts.pending_action,
+        receiver: accounts.receiver,
+        registered_receiver: ntt.registered_receiver(&ntt.receiver_authority(&accounts.receiver)),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct DeregisterReceiver {
+    pub owner: Pubkey,
+    pub receiver: Pubkey,
+    pub pending_action: Option<Pubkey>,
+}
+
+pub fn deregister_receiver(ntt: &NTT, accounts: DeregisterReceiver) -> Instruction {
+    let data = example_native_token_transfers::instruction::DeregisterReceiver {};
+
+    let accounts = example_native_token_transfers::accounts::DeregisterReceiver {
+        config: ntt.config(),
+        owner: accounts.owner,
+        pending_action: accounts.pending_action,
+        registered_receiver: ntt.registered_receiver(&ntt.receiver_authority(&accounts.receiver)),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct InitializeCustodyLedger {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub pending_action: Option<Pubkey>,
+}
+
+pub fn initialize_custody_ledger(ntt: &NTT, accounts: InitializeCustodyLedger) -> Instruction {
+    let data = example_native_token_transfers::instruction::InitializeCustodyLedger {};
+
+    let accounts = example_native_token_transfers::accounts::InitializeCustodyLedger {
+        payer: accounts.payer,
+        owner: accounts.owner,
+        config: ntt.config(),
+        pending_action: accounts.pending_action,
+        custody: ntt.custody(&accounts.mint),
+        custody_ledger: ntt.custody_ledger(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
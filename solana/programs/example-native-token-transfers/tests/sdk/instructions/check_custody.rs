@@ -0,0 +1,36 @@
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use example_native_token_transfers::instructions::CheckCustodyInvariantArgs;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+
+use crate::sdk::accounts::NTT;
+
+/// `inbox_items` are the approved but unreleased inbound transfers to take
+/// into account.
+pub fn check_custody_invariant(
+    ntt: &NTT,
+    mint: Pubkey,
+    inbox_items: &[Pubkey],
+    auto_pause: bool,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::CheckCustodyInvariant {
+        args: CheckCustodyInvariantArgs { auto_pause },
+    };
+
+    let mut accounts = example_native_token_transfers::accounts::CheckCustodyInvariant {
+        config: ntt.config(),
+        custody: ntt.custody(&mint),
+        custody_ledger: ntt.custody_ledger(),
+    }
+    .to_account_metas(None);
+    accounts.extend(
+        inbox_items
+            .iter()
+            .map(|inbox_item| AccountMeta::new_readonly(*inbox_item, false)),
+    );
+
+    Instruction {
+        program_id: ntt.program,
+        accounts,
+        data: data.data(),
+    }
+}
@@ -30,8 +30,11 @@ pub fn initialize_with_token_program_id(
         deployer: accounts.deployer,
         program_data: ntt.program_data(),
         config: ntt.config(),
+        token_mode: ntt.token_mode(),
+        single_token_config: ntt.multi_token_mint.map(|_| ntt.single_token_config()),
         mint: accounts.mint,
         rate_limit: ntt.outbox_rate_limit(),
+        custody_ledger: ntt.custody_ledger(),
         token_authority: ntt.token_authority(),
         custody: ntt.custody_with_token_program_id(&accounts.mint, token_program_id),
         token_program: *token_program_id,
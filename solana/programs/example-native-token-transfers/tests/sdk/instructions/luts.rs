@@ -0,0 +1,159 @@
+use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
+use anchor_spl::token::Token;
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::{accounts::NTT, transceivers::wormhole::accounts::wormhole::wormhole_accounts};
+
+pub struct InitializeLUT {
+    pub payer: Pubkey,
+    pub mint: Pubkey,
+}
+
+pub fn initialize_lut(ntt: &NTT, accounts: InitializeLUT, recent_slot: u64) -> Instruction {
+    initialize_lut_with_token_program_id(ntt, accounts, recent_slot, &Token::id())
+}
+
+pub fn initialize_lut_with_token_program_id(
+    ntt: &NTT,
+    accounts: InitializeLUT,
+    recent_slot: u64,
+    token_program_id: &Pubkey,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::InitializeLut { recent_slot };
+
+    let accounts = example_native_token_transfers::accounts::InitializeLUT {
+        payer: accounts.payer,
+        entries: example_native_token_transfers::accounts::Entries {
+            config: ntt.config(),
+            custody: ntt.custody_with_token_program_id(&accounts.mint, token_program_id),
+            token_program: *token_program_id,
+            mint: accounts.mint,
+            token_authority: ntt.token_authority(),
+            outbox_rate_limit: ntt.outbox_rate_limit(),
+            wormhole: wormhole_accounts(ntt),
+        },
+        authority: ntt.lut_authority(),
+        lut_address: ntt.lut_address(recent_slot),
+        lut: ntt.lut(),
+        lut_program: solana_address_lookup_table_program::id(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ExtendLUT {
+    pub payer: Pubkey,
+    /// The current LUT. See [`NTT::lut`].
+    pub lut_address: Pubkey,
+}
+
+fn extend_lut_accounts(
+    ntt: &NTT,
+    accounts: ExtendLUT,
+) -> example_native_token_transfers::accounts::ExtendLUT {
+    example_native_token_transfers::accounts::ExtendLUT {
+        payer: accounts.payer,
+        config: ntt.config(),
+        authority: ntt.lut_authority(),
+        lut: ntt.lut(),
+        lut_address: accounts.lut_address,
+        lut_program: solana_address_lookup_table_program::id(),
+        system_program: System::id(),
+    }
+}
+
+pub fn extend_lut_with_peer(ntt: &NTT, accounts: ExtendLUT, chain_id: u16) -> Instruction {
+    let data = example_native_token_transfers::instruction::ExtendLutWithPeer { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::ExtendLUTWithPeer {
+        common: extend_lut_accounts(ntt, accounts),
+        peer: ntt.peer(chain_id),
+        inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+        transceiver_peer: ntt.transceiver_peer(chain_id),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn extend_lut_with_transceiver(
+    ntt: &NTT,
+    accounts: ExtendLUT,
+    transceiver: &Pubkey,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ExtendLutWithTransceiver {};
+
+    let accounts = example_native_token_transfers::accounts::ExtendLUTWithTransceiver {
+        common: extend_lut_accounts(ntt, accounts),
+        registered_transceiver: ntt.registered_transceiver(transceiver),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn extend_lut_with_transfer_hook(ntt: &NTT, accounts: ExtendLUT, mint: Pubkey) -> Instruction {
+    let data = example_native_token_transfers::instruction::ExtendLutWithTransferHook {};
+
+    let accounts = example_native_token_transfers::accounts::ExtendLUTWithTransferHook {
+        common: extend_lut_accounts(ntt, accounts),
+        mint,
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct RetireLUT {
+    pub owner: Pubkey,
+    /// An old LUT, which can't be the current one.
+    pub lut_address: Pubkey,
+}
+
+fn retire_lut_accounts(
+    ntt: &NTT,
+    accounts: RetireLUT,
+) -> example_native_token_transfers::accounts::RetireLUT {
+    example_native_token_transfers::accounts::RetireLUT {
+        config: ntt.config(),
+        owner: accounts.owner,
+        authority: ntt.lut_authority(),
+        lut: ntt.lut(),
+        lut_address: accounts.lut_address,
+        lut_program: solana_address_lookup_table_program::id(),
+    }
+}
+
+pub fn deactivate_lut(ntt: &NTT, accounts: RetireLUT) -> Instruction {
+    let data = example_native_token_transfers::instruction::DeactivateLut {};
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: retire_lut_accounts(ntt, accounts).to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn close_lut(ntt: &NTT, accounts: RetireLUT) -> Instruction {
+    let data = example_native_token_transfers::instruction::CloseLut {};
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: retire_lut_accounts(ntt, accounts).to_account_metas(None),
+        data: data.data(),
+    }
+}
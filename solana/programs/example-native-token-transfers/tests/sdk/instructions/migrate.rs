@@ -0,0 +1,120 @@
+use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
+use example_native_token_transfers::instructions::MigrateInboxItemArgs;
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub fn migrate_config(ntt: &NTT, payer: Pubkey) -> Instruction {
+    let data = example_native_token_transfers::instruction::MigrateConfig {};
+
+    let accounts = example_native_token_transfers::accounts::MigrateConfig {
+        payer,
+        config: ntt.config(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn migrate_outbox_rate_limit(ntt: &NTT, payer: Pubkey) -> Instruction {
+    let data = example_native_token_transfers::instruction::MigrateOutboxRateLimit {};
+
+    let accounts = example_native_token_transfers::accounts::MigrateOutboxRateLimit {
+        payer,
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn migrate_inbox_rate_limit(ntt: &NTT, payer: Pubkey, chain_id: u16) -> Instruction {
+    let data = example_native_token_transfers::instruction::MigrateInboxRateLimit { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::MigrateInboxRateLimit {
+        payer,
+        inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn migrate_peer(ntt: &NTT, payer: Pubkey, chain_id: u16) -> Instruction {
+    let data = example_native_token_transfers::instruction::MigratePeer { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::MigratePeer {
+        payer,
+        peer: ntt.peer(chain_id),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn migrate_wormhole_peer(ntt: &NTT, payer: Pubkey, chain_id: u16) -> Instruction {
+    let data = example_native_token_transfers::instruction::MigrateWormholePeer { chain_id };
+
+    let accounts = example_native_token_transfers::accounts::MigrateTransceiverPeer {
+        payer,
+        peer: ntt.transceiver_peer(chain_id),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn migrate_inbox_item(ntt: &NTT, payer: Pubkey, args: MigrateInboxItemArgs) -> Instruction {
+    let inbox_item = ntt.inbox_item(args.from_chain.id, args.message());
+    let data = example_native_token_transfers::instruction::MigrateInboxItem { args };
+
+    let accounts = example_native_token_transfers::accounts::MigrateInboxItem {
+        payer,
+        config: ntt.config(),
+        inbox_item,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn migrate_outbox_item(ntt: &NTT, payer: Pubkey, outbox_item: Pubkey) -> Instruction {
+    let data = example_native_token_transfers::instruction::MigrateOutboxItem {};
+
+    let accounts = example_native_token_transfers::accounts::MigrateOutboxItem {
+        payer,
+        config: ntt.config(),
+        outbox_item,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
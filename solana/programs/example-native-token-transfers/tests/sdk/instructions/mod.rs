@@ -1,5 +1,11 @@
 pub mod admin;
+pub mod check_custody;
 pub mod initialize;
+pub mod luts;
+pub mod migrate;
 pub mod post_vaa;
+pub mod reclaim_outbox_item;
 pub mod redeem;
+pub mod release_inbound;
 pub mod transfer;
+pub mod views;
@@ -55,6 +55,61 @@ pub async fn post_vaa<A: AnchorSerialize + Clone>(
     posted_vaa
 }
 
+/// Anchor discriminator of the core bridge's `EncodedVaa` accounts.
+const ENCODED_VAA_DISCRIMINATOR: [u8; 8] = [226, 101, 163, 4, 133, 160, 84, 245];
+
+/// Writes `vaa` into a new `EncodedVaa` account, as if it had been created
+/// with `init_encoded_vaa` and `write_encoded_vaa`, and (when `verified` is
+/// set) verified with `verify_encoded_vaa_v1`.
+///
+/// The account is injected directly, because the bundled core bridge binary
+/// predates encoded VAAs.
+pub fn write_encoded_vaa<A: AnchorSerialize>(
+    ctx: &mut ProgramTestContext,
+    vaa: Vaa<A>,
+    verified: bool,
+) -> Pubkey {
+    let mut buf = vec![vaa.version];
+    buf.extend_from_slice(&vaa.guardian_set_index.to_be_bytes());
+    buf.push(vaa.signatures.len() as u8);
+    for signature in &vaa.signatures {
+        buf.push(signature.index);
+        buf.extend_from_slice(&signature.signature);
+    }
+    buf.extend_from_slice(&vaa.timestamp.to_be_bytes());
+    buf.extend_from_slice(&vaa.nonce.to_be_bytes());
+    buf.extend_from_slice(&u16::from(vaa.emitter_chain).to_be_bytes());
+    buf.extend_from_slice(&vaa.emitter_address.0);
+    buf.extend_from_slice(&vaa.sequence.to_be_bytes());
+    buf.push(vaa.consistency_level);
+    buf.extend_from_slice(&vaa.payload.try_to_vec().unwrap());
+
+    let mut data = ENCODED_VAA_DISCRIMINATOR.to_vec();
+    // status: 1 = Writing, 2 = Verified
+    data.push(if verified { 2 } else { 1 });
+    // write authority
+    data.extend_from_slice(&ctx.payer.pubkey().to_bytes());
+    // version
+    data.push(1);
+    data.extend_from_slice(&(buf.len() as u32).to_le_bytes());
+    data.extend_from_slice(&buf);
+
+    let encoded_vaa = Keypair::new().pubkey();
+    ctx.set_account(
+        &encoded_vaa,
+        &solana_sdk::account::Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: wormhole_anchor_sdk::wormhole::program::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+        .into(),
+    );
+
+    encoded_vaa
+}
+
 pub fn verify_signatures<A: AnchorSerialize + Clone>(
     wh: &Wormhole,
     accounts: VerifySignatures,
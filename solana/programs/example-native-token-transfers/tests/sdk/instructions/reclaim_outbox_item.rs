@@ -0,0 +1,68 @@
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use anchor_spl::{associated_token::get_associated_token_address, token::Token};
+use example_native_token_transfers::accounts::NotPausedConfig;
+use ntt_messages::mode::Mode;
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct ReclaimOutboxItem {
+    pub mint: Pubkey,
+    pub outbox_item: Pubkey,
+    pub sender: Pubkey,
+}
+
+pub fn reclaim_outbox_item(ntt: &NTT, accounts: ReclaimOutboxItem, mode: Mode) -> Instruction {
+    match mode {
+        Mode::Burning => reclaim_outbox_item_mint(ntt, accounts),
+        Mode::Locking => reclaim_outbox_item_unlock(ntt, accounts),
+    }
+}
+
+pub fn reclaim_outbox_item_mint(ntt: &NTT, accounts: ReclaimOutboxItem) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReclaimOutboxItemMint {};
+
+    let accounts = example_native_token_transfers::accounts::ReclaimOutboxItemMint {
+        common: common(ntt, &accounts),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn reclaim_outbox_item_unlock(ntt: &NTT, accounts: ReclaimOutboxItem) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReclaimOutboxItemUnlock {};
+
+    let accounts = example_native_token_transfers::accounts::ReclaimOutboxItemUnlock {
+        common: common(ntt, &accounts),
+        custody_ledger: ntt.custody_ledger(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn common(
+    ntt: &NTT,
+    accounts: &ReclaimOutboxItem,
+) -> example_native_token_transfers::accounts::ReclaimOutboxItem {
+    example_native_token_transfers::accounts::ReclaimOutboxItem {
+        config: NotPausedConfig {
+            config: ntt.config(),
+        },
+        outbox_item: accounts.outbox_item,
+        sender: accounts.sender,
+        sender_token_account: get_associated_token_address(&accounts.sender, &accounts.mint),
+        token_authority: ntt.token_authority(),
+        mint: accounts.mint,
+        token_program: Token::id(),
+        custody: ntt.custody(&accounts.mint),
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+    }
+}
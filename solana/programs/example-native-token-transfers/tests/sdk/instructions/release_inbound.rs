@@ -0,0 +1,124 @@
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use anchor_spl::{associated_token::get_associated_token_address, token::Token};
+use example_native_token_transfers::{accounts::NotPausedConfig, instructions::ReleaseInboundArgs};
+use ntt_messages::mode::Mode;
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+pub struct ReleaseInbound {
+    pub payer: Pubkey,
+    pub inbox_item: Pubkey,
+    pub from_chain: u16,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+}
+
+pub fn release_inbound(
+    ntt: &NTT,
+    accounts: ReleaseInbound,
+    args: ReleaseInboundArgs,
+    mode: Mode,
+) -> Instruction {
+    match mode {
+        Mode::Burning => release_inbound_mint(ntt, accounts, args),
+        Mode::Locking => release_inbound_unlock(ntt, accounts, args),
+    }
+}
+
+pub fn release_inbound_mint(
+    ntt: &NTT,
+    accounts: ReleaseInbound,
+    args: ReleaseInboundArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReleaseInboundMint { args };
+
+    let accounts = example_native_token_transfers::accounts::ReleaseInboundMint {
+        common: common(ntt, &accounts),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn release_inbound_unlock(
+    ntt: &NTT,
+    accounts: ReleaseInbound,
+    args: ReleaseInboundArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReleaseInboundUnlock { args };
+
+    let accounts = example_native_token_transfers::accounts::ReleaseInboundUnlock {
+        common: common(ntt, &accounts),
+        custody_ledger: ntt.custody_ledger(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Release an inbound transfer to a registered receiver. `accounts.recipient`
+/// is the receiver's authority.
+pub fn release_inbound_and_call(
+    ntt: &NTT,
+    accounts: ReleaseInbound,
+    receiver_program: Pubkey,
+    args: ReleaseInboundArgs,
+    mode: Mode,
+) -> Instruction {
+    let call = example_native_token_transfers::accounts::ReleaseInboundAndCall {
+        common: common(ntt, &accounts),
+        receiver_program,
+        receiver_caller: ntt.receiver_caller(),
+    };
+
+    let (accounts, data) = match mode {
+        Mode::Burning => (
+            example_native_token_transfers::accounts::ReleaseInboundAndCallMint { call }
+                .to_account_metas(None),
+            example_native_token_transfers::instruction::ReleaseInboundAndCallMint { args }.data(),
+        ),
+        Mode::Locking => (
+            example_native_token_transfers::accounts::ReleaseInboundAndCallUnlock {
+                call,
+                custody_ledger: ntt.custody_ledger(),
+            }
+            .to_account_metas(None),
+            example_native_token_transfers::instruction::ReleaseInboundAndCallUnlock { args }
+                .data(),
+        ),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts,
+        data,
+    }
+}
+
+fn common(
+    ntt: &NTT,
+    accounts: &ReleaseInbound,
+) -> example_native_token_transfers::accounts::ReleaseInbound {
+    example_native_token_transfers::accounts::ReleaseInbound {
+        payer: accounts.payer,
+        config: NotPausedConfig {
+            config: ntt.config(),
+        },
+        inbox_item: accounts.inbox_item,
+        inbox_rate_limit: ntt.inbox_rate_limit(accounts.from_chain),
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+        recipient: get_associated_token_address(&accounts.recipient, &accounts.mint),
+        registered_receiver: ntt.registered_receiver(&accounts.recipient),
+        token_authority: ntt.token_authority(),
+        mint: accounts.mint,
+        token_program: Token::id(),
+        custody: ntt.custody(&accounts.mint),
+    }
+}
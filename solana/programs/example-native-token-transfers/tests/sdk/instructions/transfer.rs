@@ -81,6 +81,7 @@ pub fn transfer_lock_with_token_program_id(
         inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
         peer: transfer.peer,
         session_authority,
+        custody_ledger: ntt.custody_ledger(),
     };
     Instruction {
         program_id: example_native_token_transfers::ID,
@@ -89,6 +90,49 @@ pub fn transfer_lock_with_token_program_id(
     }
 }
 
+/// Builds a transfer where `transfer.from_authority` signs directly, instead
+/// of approving the session authority first.
+pub fn transfer_direct(
+    ntt: &NTT,
+    transfer: Transfer,
+    args: TransferArgs,
+    mode: Mode,
+) -> Instruction {
+    let chain_id = args.recipient_chain.id;
+    let common = common_with_token_program_id(ntt, &transfer, &Token::id());
+
+    let (accounts, data) = match mode {
+        Mode::Burning => (
+            example_native_token_transfers::accounts::TransferBurnDirect {
+                common,
+                inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+                peer: transfer.peer,
+                from_authority: transfer.from_authority,
+                token_authority: ntt.token_authority(),
+            }
+            .to_account_metas(None),
+            example_native_token_transfers::instruction::TransferBurnDirect { args }.data(),
+        ),
+        Mode::Locking => (
+            example_native_token_transfers::accounts::TransferLockDirect {
+                common,
+                inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+                peer: transfer.peer,
+                from_authority: transfer.from_authority,
+                custody_ledger: ntt.custody_ledger(),
+            }
+            .to_account_metas(None),
+            example_native_token_transfers::instruction::TransferLockDirect { args }.data(),
+        ),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts,
+        data,
+    }
+}
+
 pub fn approve_token_authority(
     ntt: &NTT,
     user_token_account: &Pubkey,
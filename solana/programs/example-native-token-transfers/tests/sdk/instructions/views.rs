@@ -0,0 +1,99 @@
+use anchor_lang::{prelude::Pubkey, InstructionData, ToAccountMetas};
+use example_native_token_transfers::instructions::{
+    GetInboundCapacityArgs, GetTransferHookAccountsArgs,
+};
+use ntt_messages::chain_id::ChainId;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+
+use crate::sdk::accounts::NTT;
+
+pub fn get_outbound_capacity(ntt: &NTT) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetOutboundCapacity {};
+
+    let accounts = example_native_token_transfers::accounts::GetOutboundCapacity {
+        config: ntt.config(),
+        rate_limit: ntt.outbox_rate_limit(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn get_inbound_capacity(ntt: &NTT, chain_id: u16) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetInboundCapacity {
+        args: GetInboundCapacityArgs {
+            chain_id: ChainId { id: chain_id },
+        },
+    };
+
+    let accounts = example_native_token_transfers::accounts::GetInboundCapacity {
+        config: ntt.config(),
+        rate_limit: ntt.inbox_rate_limit(chain_id),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn get_outbox_item_status(ntt: &NTT, outbox_item: Pubkey) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetOutboxItemStatus {};
+
+    let accounts = example_native_token_transfers::accounts::GetOutboxItemStatus {
+        config: ntt.config(),
+        outbox_item,
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+    };
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct GetTransferHookAccounts {
+    pub mint: Pubkey,
+    pub source: Pubkey,
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+    /// The transfer hook's validation account, and any extra accounts that
+    /// other extra accounts are derived from.
+    pub extra_accounts: Vec<Pubkey>,
+}
+
+pub fn get_transfer_hook_accounts(
+    ntt: &NTT,
+    accounts: GetTransferHookAccounts,
+    amount: u64,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::GetTransferHookAccounts {
+        args: GetTransferHookAccountsArgs { amount },
+    };
+
+    let mut account_metas = example_native_token_transfers::accounts::GetTransferHookAccounts {
+        config: ntt.config(),
+        mint: accounts.mint,
+        source: accounts.source,
+        destination: accounts.destination,
+        authority: accounts.authority,
+    }
+    .to_account_metas(None);
+    account_metas.extend(
+        accounts
+            .extra_accounts
+            .iter()
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false)),
+    );
+
+    Instruction {
+        program_id: ntt.program,
+        accounts: account_metas,
+        data: data.data(),
+    }
+}
@@ -1,5 +1,8 @@
 use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
-use example_native_token_transfers::transceivers::wormhole::SetTransceiverPeerArgs;
+use example_native_token_transfers::transceivers::wormhole::{
+    AcceptPendingPeerArgs, ApproveRelayerArgs, SetTransceiverPeerArgs,
+    SetTransceiverPeerFinalityArgs, SetWormholeFinalityArgs,
+};
 use solana_sdk::instruction::Instruction;
 
 use crate::sdk::accounts::NTT;
@@ -21,6 +24,7 @@ pub fn set_transceiver_peer(
         config: ntt.config(),
         owner: accounts.owner,
         payer: accounts.payer,
+        pending_action: None,
         peer: ntt.transceiver_peer(chain_id),
         system_program: System::id(),
     };
@@ -31,3 +35,137 @@ pub fn set_transceiver_peer(
         data: data.data(),
     }
 }
+
+pub struct SetTransceiverPeerFinality {
+    pub owner: Pubkey,
+}
+
+pub fn set_transceiver_peer_finality(
+    ntt: &NTT,
+    accounts: SetTransceiverPeerFinality,
+    args: SetTransceiverPeerFinalityArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let data = example_native_token_transfers::instruction::SetWormholePeerFinality { args };
+
+    let accounts = example_native_token_transfers::accounts::SetTransceiverPeerFinality {
+        config: ntt.config(),
+        owner: accounts.owner,
+        pending_action: None,
+        peer: ntt.transceiver_peer(chain_id),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct SetWormholeFinality {
+    pub owner: Pubkey,
+}
+
+pub fn set_wormhole_finality(
+    ntt: &NTT,
+    accounts: SetWormholeFinality,
+    args: SetWormholeFinalityArgs,
+) -> Instruction {
+    let data = example_native_token_transfers::instruction::SetWormholeFinality { args };
+
+    let accounts = example_native_token_transfers::accounts::SetWormholeFinality {
+        config: ntt.config(),
+        owner: accounts.owner,
+        pending_action: None,
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct AcceptPendingPeer {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn accept_pending_peer(
+    ntt: &NTT,
+    accounts: AcceptPendingPeer,
+    args: AcceptPendingPeerArgs,
+) -> Instruction {
+    let chain_id = args.chain_id.id;
+    let pending_peer = ntt.pending_peer(chain_id, &args.transceiver_address);
+    let data = example_native_token_transfers::instruction::AcceptWormholePendingPeer { args };
+
+    let accounts = example_native_token_transfers::accounts::AcceptPendingPeer {
+        payer: accounts.payer,
+        owner: accounts.owner,
+        config: ntt.config(),
+        pending_action: None,
+        pending_peer,
+        peer: ntt.peer(chain_id),
+        inbox_rate_limit: ntt.inbox_rate_limit(chain_id),
+        transceiver_peer: ntt.transceiver_peer(chain_id),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ApproveRelayer {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn approve_relayer(
+    ntt: &NTT,
+    accounts: ApproveRelayer,
+    args: ApproveRelayerArgs,
+) -> Instruction {
+    let approved_relayer = ntt.approved_relayer(&args.relayer);
+    let data = example_native_token_transfers::instruction::ApproveWormholeRelayer { args };
+
+    let accounts = example_native_token_transfers::accounts::ApproveRelayer {
+        payer: accounts.payer,
+        owner: accounts.owner,
+        config: ntt.config(),
+        pending_action: None,
+        approved_relayer,
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct RevokeRelayer {
+    pub owner: Pubkey,
+    pub relayer: Pubkey,
+}
+
+pub fn revoke_relayer(ntt: &NTT, accounts: RevokeRelayer) -> Instruction {
+    let data = example_native_token_transfers::instruction::RevokeWormholeRelayer {};
+
+    let accounts = example_native_token_transfers::accounts::RevokeRelayer {
+        owner: accounts.owner,
+        config: ntt.config(),
+        approved_relayer: ntt.approved_relayer(&accounts.relayer),
+        relayer: accounts.relayer,
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
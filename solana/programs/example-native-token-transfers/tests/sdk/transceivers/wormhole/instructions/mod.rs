@@ -1,5 +1,8 @@
 pub mod admin;
 pub mod broadcast_id;
 pub mod broadcast_peer;
+pub mod pause_signal;
+pub mod receive_and_redeem;
+pub mod receive_broadcast;
 pub mod receive_message;
 pub mod release_outbound;
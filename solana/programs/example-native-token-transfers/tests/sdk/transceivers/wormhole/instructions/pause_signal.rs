@@ -0,0 +1,68 @@
+use anchor_lang::{prelude::*, InstructionData};
+use example_native_token_transfers::transceivers::wormhole::{
+    ReceivePauseSignalArgs, SendPauseSignalArgs,
+};
+use ntt_messages::chain_id::ChainId;
+use solana_program::instruction::Instruction;
+
+use crate::sdk::{accounts::NTT, transceivers::wormhole::accounts::wormhole::wormhole_accounts};
+
+pub struct SendPauseSignal {
+    pub payer: Pubkey,
+    pub owner: Pubkey,
+    pub wormhole_message: Pubkey,
+    pub chain_id: u16,
+}
+
+pub fn send_pause_signal(ntt: &NTT, accs: SendPauseSignal, paused: bool) -> Instruction {
+    let data = example_native_token_transfers::instruction::SendWormholePauseSignal {
+        args: SendPauseSignalArgs {
+            chain_id: ChainId { id: accs.chain_id },
+            paused,
+        },
+    };
+
+    let accounts = example_native_token_transfers::accounts::SendPauseSignal {
+        payer: accs.payer,
+        owner: accs.owner,
+        config: ntt.config(),
+        peer: ntt.peer(accs.chain_id),
+        transceiver_peer: ntt.transceiver_peer(accs.chain_id),
+        wormhole_message: accs.wormhole_message,
+        emitter: ntt.emitter(),
+        wormhole: wormhole_accounts(ntt),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct ReceivePauseSignal {
+    pub vaa: Pubkey,
+    pub chain_id: u16,
+}
+
+pub fn receive_pause_signal(ntt: &NTT, accs: ReceivePauseSignal) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReceiveWormholePauseSignal {
+        args: ReceivePauseSignalArgs {
+            chain_id: ChainId { id: accs.chain_id },
+        },
+    };
+
+    let accounts = example_native_token_transfers::accounts::ReceivePauseSignal {
+        config: ntt.config(),
+        vaa: accs.vaa,
+        transceiver_peer: ntt.transceiver_peer(accs.chain_id),
+        peer: ntt.peer(accs.chain_id),
+        transceiver: ntt.registered_transceiver(&ntt.program),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
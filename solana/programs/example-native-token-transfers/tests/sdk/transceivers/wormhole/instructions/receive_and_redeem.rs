@@ -0,0 +1,52 @@
+use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
+use anchor_spl::{associated_token::get_associated_token_address, token::Token};
+use example_native_token_transfers::transceivers::wormhole::ReceiveAndRedeemArgs;
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+#[derive(Debug, Clone)]
+pub struct ReceiveAndRedeem {
+    pub payer: Pubkey,
+    pub vaa: Pubkey,
+    pub chain_id: u16,
+    pub inbox_item: Pubkey,
+    /// The owner of the recipient token account.
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+}
+
+pub fn receive_and_redeem(
+    ntt: &NTT,
+    accs: ReceiveAndRedeem,
+    args: ReceiveAndRedeemArgs,
+) -> Instruction {
+    let data =
+        example_native_token_transfers::instruction::ReceiveAndRedeemWormholeMessage { args };
+
+    let accounts = example_native_token_transfers::accounts::ReceiveAndRedeem {
+        payer: accs.payer,
+        config: ntt.config(),
+        vaa: accs.vaa,
+        transceiver_peer: ntt.transceiver_peer(accs.chain_id),
+        peer: ntt.peer(accs.chain_id),
+        transceiver: ntt.registered_transceiver(&ntt.program),
+        mint: accs.mint,
+        inbox_item: accs.inbox_item,
+        inbox_rate_limit: ntt.inbox_rate_limit(accs.chain_id),
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+        recipient: get_associated_token_address(&accs.recipient, &accs.mint),
+        registered_receiver: ntt.registered_receiver(&accs.recipient),
+        token_authority: ntt.token_authority(),
+        token_program: Token::id(),
+        custody: ntt.custody(&accs.mint),
+        custody_ledger: ntt.custody_ledger(),
+        system_program: System::id(),
+    };
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
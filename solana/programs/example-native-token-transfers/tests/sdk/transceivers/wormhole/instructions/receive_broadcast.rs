@@ -0,0 +1,48 @@
+use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+
+use crate::sdk::accounts::NTT;
+
+#[derive(Debug, Clone)]
+pub struct ReceiveBroadcast {
+    pub payer: Pubkey,
+    pub vaa: Pubkey,
+    pub chain_id: u16,
+    pub transceiver_address: [u8; 32],
+}
+
+pub fn receive_transceiver_info(ntt: &NTT, accs: ReceiveBroadcast) -> Instruction {
+    let data = example_native_token_transfers::instruction::ReceiveWormholeTransceiverInfo {};
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: receive_broadcast_accounts(ntt, accs),
+        data: data.data(),
+    }
+}
+
+pub fn receive_transceiver_registration(ntt: &NTT, accs: ReceiveBroadcast) -> Instruction {
+    let data =
+        example_native_token_transfers::instruction::ReceiveWormholeTransceiverRegistration {};
+
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: receive_broadcast_accounts(ntt, accs),
+        data: data.data(),
+    }
+}
+
+fn receive_broadcast_accounts(
+    ntt: &NTT,
+    accs: ReceiveBroadcast,
+) -> Vec<solana_sdk::instruction::AccountMeta> {
+    example_native_token_transfers::accounts::ReceiveBroadcast {
+        payer: accs.payer,
+        config: ntt.config(),
+        vaa: accs.vaa,
+        pending_peer: ntt.pending_peer(accs.chain_id, &accs.transceiver_address),
+        emitter: ntt.emitter(),
+        system_program: System::id(),
+    }
+    .to_account_metas(None)
+}
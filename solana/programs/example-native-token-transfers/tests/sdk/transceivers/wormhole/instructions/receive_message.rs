@@ -1,4 +1,6 @@
 use anchor_lang::{prelude::Pubkey, system_program::System, Id, InstructionData, ToAccountMetas};
+use example_native_token_transfers::transceivers::wormhole::ReceiveMessageArgs;
+use ntt_messages::chain_id::ChainId;
 use solana_sdk::instruction::Instruction;
 
 use crate::sdk::accounts::NTT;
@@ -10,10 +12,17 @@ pub struct ReceiveMessage {
     pub vaa: Pubkey,
     pub chain_id: u16,
     pub id: [u8; 32],
+    pub additional_payload_len: u16,
 }
 
 pub fn receive_message(ntt: &NTT, accs: ReceiveMessage) -> Instruction {
-    let data = example_native_token_transfers::instruction::ReceiveWormholeMessage {};
+    let data = example_native_token_transfers::instruction::ReceiveWormholeMessage {
+        args: ReceiveMessageArgs {
+            chain_id: ChainId { id: accs.chain_id },
+            message_id: accs.id,
+            additional_payload_len: accs.additional_payload_len,
+        },
+    };
 
     let accounts = example_native_token_transfers::accounts::ReceiveMessage {
         payer: accs.payer,
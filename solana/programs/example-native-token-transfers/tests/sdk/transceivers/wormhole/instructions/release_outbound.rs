@@ -1,6 +1,7 @@
 use anchor_lang::{prelude::*, InstructionData};
 use example_native_token_transfers::{
-    accounts::NotPausedConfig, transceivers::wormhole::ReleaseOutboundArgs,
+    accounts::NotPausedConfig,
+    transceivers::wormhole::{ReleaseOutboundArgs, ReleaseOutboundReusableArgs},
 };
 use solana_sdk::instruction::Instruction;
 
@@ -9,6 +10,7 @@ use crate::sdk::{accounts::NTT, transceivers::wormhole::accounts::wormhole::worm
 pub struct ReleaseOutbound {
     pub payer: Pubkey,
     pub outbox_item: Pubkey,
+    pub recipient_chain: u16,
 }
 
 pub fn release_outbound(
@@ -23,9 +25,12 @@ pub fn release_outbound(
             config: ntt.config(),
         },
         outbox_item: release_outbound.outbox_item,
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+        inbox_rate_limit: ntt.inbox_rate_limit(release_outbound.recipient_chain),
         wormhole_message: ntt.wormhole_message(&release_outbound.outbox_item),
         emitter: ntt.emitter(),
         transceiver: ntt.registered_transceiver(&ntt.program),
+        peer: ntt.transceiver_peer(release_outbound.recipient_chain),
         wormhole: wormhole_accounts(ntt),
     };
     Instruction {
@@ -34,3 +39,45 @@ pub fn release_outbound(
         data: data.data(),
     }
 }
+
+pub struct ReleaseOutboundReusable {
+    pub payer: Pubkey,
+    pub outbox_item: Pubkey,
+    pub recipient_chain: u16,
+    /// Whether the payer is an approved relayer. Not needed when the payer is
+    /// the sender of the outbox item.
+    pub approved_relayer: bool,
+}
+
+pub fn release_outbound_reusable(
+    ntt: &NTT,
+    release_outbound: ReleaseOutboundReusable,
+    args: ReleaseOutboundReusableArgs,
+) -> Instruction {
+    let wormhole_message =
+        ntt.reusable_wormhole_message(&release_outbound.payer, args.message_index);
+    let data =
+        example_native_token_transfers::instruction::ReleaseWormholeOutboundReusable { args };
+    let accounts = example_native_token_transfers::accounts::ReleaseOutboundReusable {
+        payer: release_outbound.payer,
+        config: NotPausedConfig {
+            config: ntt.config(),
+        },
+        outbox_item: release_outbound.outbox_item,
+        outbox_rate_limit: ntt.outbox_rate_limit(),
+        inbox_rate_limit: ntt.inbox_rate_limit(release_outbound.recipient_chain),
+        wormhole_message,
+        emitter: ntt.emitter(),
+        transceiver: ntt.registered_transceiver(&ntt.program),
+        peer: ntt.transceiver_peer(release_outbound.recipient_chain),
+        wormhole: wormhole_accounts(ntt),
+        approved_relayer: release_outbound
+            .approved_relayer
+            .then(|| ntt.approved_relayer(&release_outbound.payer)),
+    };
+    Instruction {
+        program_id: example_native_token_transfers::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
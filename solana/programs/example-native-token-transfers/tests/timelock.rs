@@ -0,0 +1,246 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use anchor_lang::prelude::Clock;
+use example_native_token_transfers::{
+    error::NTTError,
+    instructions::{SetOutboundLimitArgs, SetTimelockDelayArgs},
+    queue::outbox::OutboxRateLimit,
+    timelock::AdminAction,
+};
+use ntt_messages::mode::Mode;
+use solana_program::instruction::InstructionError;
+use solana_program_test::*;
+use solana_sdk::{signer::Signer, transaction::TransactionError};
+
+use crate::{
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{setup, TestData, OUTBOUND_LIMIT},
+        submit::Submittable,
+    },
+    sdk::instructions::admin::{
+        cancel_admin_action, propose_admin_action, set_outbound_limit, set_paused,
+        set_timelock_delay, CancelAdminAction, ProposeAdminAction, SetOutboundLimit, SetPaused,
+        SetTimelockDelay,
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+const TIMELOCK_DELAY: i64 = 3600;
+
+async fn enable_timelock(ctx: &mut ProgramTestContext, test_data: &TestData) {
+    set_timelock_delay(
+        &test_data.ntt,
+        SetTimelockDelay {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: None,
+        },
+        SetTimelockDelayArgs {
+            delay: TIMELOCK_DELAY,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+}
+
+async fn propose(ctx: &mut ProgramTestContext, test_data: &TestData, action: AdminAction) {
+    propose_admin_action(
+        &test_data.ntt,
+        ProposeAdminAction {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        action,
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+}
+
+async fn warp_forward(ctx: &mut ProgramTestContext, seconds: i64) {
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: clock.unix_timestamp + seconds,
+        ..clock
+    });
+    // avoid deduplication of identical transactions submitted before the warp
+    ctx.get_new_latest_blockhash().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_timelocked_action() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    enable_timelock(&mut ctx, &test_data).await;
+
+    let action = AdminAction::SetOutboundLimit { limit: 1 };
+    let pending_action = test_data.ntt.pending_admin_action(&action);
+
+    // can't execute without a proposal
+    let err = set_outbound_limit(
+        &test_data.ntt,
+        SetOutboundLimit {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: None,
+        },
+        SetOutboundLimitArgs { limit: 1 },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::AdminActionNotProposed.into())
+        )
+    );
+
+    propose(&mut ctx, &test_data, action).await;
+
+    // can't execute before the delay has passed
+    let err = set_outbound_limit(
+        &test_data.ntt,
+        SetOutboundLimit {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: Some(pending_action),
+        },
+        SetOutboundLimitArgs { limit: 1 },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::TimelockNotExpired.into())
+        )
+    );
+
+    warp_forward(&mut ctx, TIMELOCK_DELAY).await;
+
+    // can't execute a different action with the proposal
+    let err = set_outbound_limit(
+        &test_data.ntt,
+        SetOutboundLimit {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: Some(pending_action),
+        },
+        SetOutboundLimitArgs { limit: 2 },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::AdminActionMismatch.into())
+        )
+    );
+
+    set_outbound_limit(
+        &test_data.ntt,
+        SetOutboundLimit {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: Some(pending_action),
+        },
+        SetOutboundLimitArgs { limit: 1 },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let rate_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(test_data.ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(rate_limit.rate_limit.limit, 1);
+
+    // the proposal is consumed
+    assert!(ctx
+        .banks_client
+        .get_account(pending_action)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_cancel_admin_action() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    enable_timelock(&mut ctx, &test_data).await;
+
+    let action = AdminAction::SetOutboundLimit { limit: 1 };
+    let pending_action = test_data.ntt.pending_admin_action(&action);
+
+    propose(&mut ctx, &test_data, action).await;
+
+    cancel_admin_action(
+        &test_data.ntt,
+        CancelAdminAction {
+            owner: test_data.program_owner.pubkey(),
+            pending_action,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert!(ctx
+        .banks_client
+        .get_account(pending_action)
+        .await
+        .unwrap()
+        .is_none());
+
+    warp_forward(&mut ctx, TIMELOCK_DELAY).await;
+
+    let err = set_outbound_limit(
+        &test_data.ntt,
+        SetOutboundLimit {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: None,
+        },
+        SetOutboundLimitArgs { limit: 1 },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::AdminActionNotProposed.into())
+        )
+    );
+
+    let rate_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(test_data.ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(rate_limit.rate_limit.limit, OUTBOUND_LIMIT);
+}
+
+#[tokio::test]
+async fn test_pause_is_not_timelocked() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    enable_timelock(&mut ctx, &test_data).await;
+
+    set_paused(
+        &test_data.ntt,
+        SetPaused {
+            owner: test_data.program_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+}
@@ -1,15 +1,29 @@
 #![cfg(feature = "test-sbf")]
 #![feature(type_changing_struct_update)]
 
-use anchor_lang::prelude::{Clock, Pubkey};
+use anchor_lang::{
+    prelude::{Clock, Pubkey},
+    AccountSerialize,
+};
 use anchor_spl::token::{Mint, TokenAccount};
 use common::setup::{TestData, OTHER_CHAIN};
 use example_native_token_transfers::{
     bitmap::Bitmap,
+    config::Config,
     error::NTTError,
-    instructions::TransferArgs,
-    queue::outbox::{OutboxItem, OutboxRateLimit},
-    transceivers::wormhole::ReleaseOutboundArgs,
+    instructions::{
+        OutboxItemStatus, SetMaxQueueAgeArgs, SetMaxQueuedOutboundArgs, SetOutboundLimitRampArgs,
+        TransferArgs,
+    },
+    queue::{
+        inbox::InboxRateLimit,
+        outbox::{OutboxItem, OutboxRateLimit},
+        rate_limit::RateLimitState,
+    },
+    transceivers::wormhole::{
+        accounts::WormholeFinality, ApproveRelayerArgs, ReleaseOutboundArgs,
+        ReleaseOutboundReusableArgs, SetTransceiverPeerFinalityArgs, SetWormholeFinalityArgs,
+    },
     transfer::Payload,
 };
 use ntt_messages::{
@@ -25,8 +39,21 @@ use solana_sdk::{
 use wormhole_anchor_sdk::wormhole::PostedVaa;
 
 use crate::{
-    common::{query::GetAccountDataAnchor, setup::OUTBOUND_LIMIT},
-    sdk::instructions::transfer::Transfer,
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{INBOUND_LIMIT, OUTBOUND_LIMIT},
+        submit::Simulatable,
+    },
+    sdk::instructions::{
+        admin::{
+            cancel_outbound_limit_ramp, set_capacity_aware_release, set_max_queue_age,
+            set_max_queued_outbound, set_outbound_limit_ramp, SetCapacityAwareRelease,
+            SetMaxQueueAge, SetMaxQueuedOutbound, SetOutboundLimitRamp,
+        },
+        reclaim_outbox_item::{reclaim_outbox_item, ReclaimOutboxItem},
+        transfer::Transfer,
+        views::{get_inbound_capacity, get_outbound_capacity, get_outbox_item_status},
+    },
 };
 use crate::{
     common::{setup::OTHER_MANAGER, submit::Submittable},
@@ -35,11 +62,19 @@ use crate::{
             admin::{set_paused, SetPaused},
             transfer::{
                 approve_token_authority, approve_token_authority_with_token_program_id, transfer,
-                transfer_with_token_program_id,
+                transfer_direct, transfer_with_token_program_id,
             },
         },
-        transceivers::wormhole::instructions::release_outbound::{
-            release_outbound, ReleaseOutbound,
+        transceivers::wormhole::instructions::{
+            admin::{
+                approve_relayer, revoke_relayer, set_transceiver_peer_finality,
+                set_wormhole_finality, ApproveRelayer, RevokeRelayer, SetTransceiverPeerFinality,
+                SetWormholeFinality,
+            },
+            release_outbound::{
+                release_outbound, release_outbound_reusable, ReleaseOutbound,
+                ReleaseOutboundReusable,
+            },
         },
     },
 };
@@ -47,6 +82,8 @@ use crate::{
 pub mod common;
 pub mod sdk;
 
+const RATE_LIMIT_DURATION: i64 = RateLimitState::RATE_LIMIT_DURATION;
+
 use crate::common::setup::{setup, setup_with_transfer_fee};
 
 // TODO: some more tests
@@ -159,6 +196,8 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
             recipient_address: [1u8; 32],
             release_timestamp: clock.unix_timestamp,
             released: Bitmap::new(),
+            deferred_capacity: 0,
+            mint: test_data.mint,
         }
     );
 
@@ -167,6 +206,7 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -216,7 +256,7 @@ async fn test_transfer(ctx: &mut ProgramTestContext, test_data: &TestData, mode:
                     source_token: test_data.mint.to_bytes(),
                     to: [1u8; 32],
                     to_chain: ChainId { id: 2 },
-                    additional_payload: Payload {}
+                    additional_payload: Payload::default()
                 }
             },
             vec![]
@@ -394,6 +434,132 @@ async fn test_rate_limit() {
     );
 }
 
+#[tokio::test]
+async fn test_outbound_limit_ramp() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_outbound_limit_ramp(
+        &test_data.ntt,
+        SetOutboundLimitRamp {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: None,
+        },
+        SetOutboundLimitRampArgs {
+            limit: 2 * OUTBOUND_LIMIT,
+            duration: RATE_LIMIT_DURATION,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // unlike `set_outbound_limit`, the increase isn't available at once
+    let capacity: u64 = get_outbound_capacity(&test_data.ntt)
+        .simulate(&mut ctx)
+        .await;
+    assert_eq!(capacity, OUTBOUND_LIMIT);
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: clock.unix_timestamp + RATE_LIMIT_DURATION / 2,
+        ..clock
+    });
+    ctx.get_new_latest_blockhash().await.unwrap();
+
+    let capacity: u64 = get_outbound_capacity(&test_data.ntt)
+        .simulate(&mut ctx)
+        .await;
+    assert_eq!(capacity, OUTBOUND_LIMIT + OUTBOUND_LIMIT / 2);
+
+    // cancelling keeps the limit where the ramp got to
+    cancel_outbound_limit_ramp(&test_data.ntt, test_data.program_owner.pubkey())
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let outbound_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(test_data.ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(outbound_limit.limit, OUTBOUND_LIMIT + OUTBOUND_LIMIT / 2);
+    assert_eq!(outbound_limit.ramp, None);
+
+    let err = set_outbound_limit_ramp(
+        &test_data.ntt,
+        SetOutboundLimitRamp {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: None,
+        },
+        SetOutboundLimitRampArgs {
+            limit: 2 * OUTBOUND_LIMIT,
+            duration: 0,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidRampDuration.into())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_views() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+
+    let (accs, args) = init_accs_args(&mut ctx, &test_data, outbox_item.pubkey(), 100, false);
+
+    let outbound_capacity_before: u64 = get_outbound_capacity(&test_data.ntt)
+        .simulate(&mut ctx)
+        .await;
+    let inbound_capacity_before: u64 = get_inbound_capacity(&test_data.ntt, OTHER_CHAIN)
+        .simulate(&mut ctx)
+        .await;
+
+    assert_eq!(outbound_capacity_before, OUTBOUND_LIMIT);
+    assert_eq!(inbound_capacity_before, INBOUND_LIMIT);
+
+    approve_token_authority(
+        &test_data.ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&test_data.ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let outbound_capacity_after: u64 = get_outbound_capacity(&test_data.ntt)
+        .simulate(&mut ctx)
+        .await;
+
+    assert_eq!(outbound_capacity_before - 100, outbound_capacity_after);
+
+    let status: OutboxItemStatus = get_outbox_item_status(&test_data.ntt, outbox_item.pubkey())
+        .simulate(&mut ctx)
+        .await;
+
+    assert_eq!(
+        status,
+        OutboxItemStatus {
+            release_timestamp: clock.unix_timestamp,
+            releasable: true,
+            released: Bitmap::new(),
+            deferred_capacity: 0,
+        }
+    );
+}
+
 #[tokio::test]
 async fn test_transfer_wrong_mode() {
     let (mut ctx, test_data) = setup(Mode::Burning).await;
@@ -475,7 +641,90 @@ async fn test_large_tx_queue() {
     assert_queued(&mut ctx, outbox_item.pubkey()).await;
 
     // queued transfers don't change the rate limit
-    assert_eq!(outbound_limit_before, outbound_limit_after);
+    assert_eq!(
+        outbound_limit_before.rate_limit,
+        outbound_limit_after.rate_limit
+    );
+    // the queued amount is untrimmed (in the mint's native units), like the
+    // rate limit and the deferred capacity that's dequeued on release
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(outbound_limit_after.queued_amount, too_much);
+    assert_eq!(outbox_item_account.deferred_capacity, too_much);
+}
+
+#[tokio::test]
+async fn test_max_queued_outbound() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let too_much = OUTBOUND_LIMIT + 1000;
+
+    set_max_queued_outbound(
+        &test_data.ntt,
+        SetMaxQueuedOutbound {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: None,
+        },
+        SetMaxQueuedOutboundArgs {
+            max_queued_amount: Some(2 * too_much),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let mut results = vec![];
+    for _ in 0..3 {
+        let outbox_item = Keypair::new();
+        let (accs, args) =
+            init_accs_args(&mut ctx, &test_data, outbox_item.pubkey(), too_much, true);
+        approve_token_authority(
+            &test_data.ntt,
+            &test_data.user_token_account,
+            &test_data.user.pubkey(),
+            &args,
+        )
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+        results.push(
+            transfer(&test_data.ntt, accs, args, Mode::Locking)
+                .submit_with_signers(&[&outbox_item], &mut ctx)
+                .await,
+        );
+    }
+
+    // the first two fill up the cap, the third one is rejected
+    assert!(results[0].is_ok());
+    assert!(results[1].is_ok());
+    assert_eq!(
+        results.pop().unwrap().unwrap_err().unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::QueuedAmountExceedsCap.into())
+        )
+    );
+
+    let outbound_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(test_data.ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(outbound_limit.queued_amount, 2 * too_much);
+
+    // transfers within the limit are not affected by the cap
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(&mut ctx, &test_data, outbox_item.pubkey(), 100, true);
+    approve_token_authority(
+        &test_data.ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&test_data.ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
 }
 
 #[tokio::test]
@@ -613,6 +862,7 @@ async fn test_cant_release_queued() {
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -636,6 +886,7 @@ async fn test_cant_release_queued() {
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: false,
@@ -684,6 +935,7 @@ async fn test_cant_release_twice() {
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -699,6 +951,7 @@ async fn test_cant_release_twice() {
         ReleaseOutbound {
             payer: ctx.payer.pubkey(),
             outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
         },
         ReleaseOutboundArgs {
             revert_on_delay: true,
@@ -716,3 +969,635 @@ async fn test_cant_release_twice() {
         )
     );
 }
+
+#[tokio::test]
+async fn test_reclaim_expired_outbox_item_locking() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+    test_reclaim_expired_outbox_item(&mut ctx, &test_data, Mode::Locking).await;
+}
+
+#[tokio::test]
+async fn test_reclaim_expired_outbox_item_burning() {
+    let (mut ctx, test_data) = setup(Mode::Burning).await;
+    test_reclaim_expired_outbox_item(&mut ctx, &test_data, Mode::Burning).await;
+}
+
+async fn test_reclaim_expired_outbox_item(
+    ctx: &mut ProgramTestContext,
+    test_data: &TestData,
+    mode: Mode,
+) {
+    const MAX_QUEUE_AGE: i64 = 3600;
+
+    set_max_queue_age(
+        &test_data.ntt,
+        SetMaxQueueAge {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: None,
+        },
+        SetMaxQueueAgeArgs {
+            max_queue_age: MAX_QUEUE_AGE,
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], ctx)
+    .await
+    .unwrap();
+
+    let outbox_item = Keypair::new();
+
+    let (accs, args) = init_accs_args(
+        ctx,
+        test_data,
+        outbox_item.pubkey(),
+        OUTBOUND_LIMIT + 1000,
+        true,
+    );
+
+    let token_account_before: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+
+    approve_token_authority(
+        &test_data.ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+    transfer(&test_data.ntt, accs, args, mode)
+        .submit_with_signers(&[&outbox_item], ctx)
+        .await
+        .unwrap();
+
+    assert_queued(ctx, outbox_item.pubkey()).await;
+
+    let reclaim = || {
+        reclaim_outbox_item(
+            &test_data.ntt,
+            ReclaimOutboxItem {
+                mint: test_data.mint,
+                outbox_item: outbox_item.pubkey(),
+                sender: test_data.user.pubkey(),
+            },
+            mode,
+        )
+    };
+
+    let err = reclaim().submit(ctx).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::OutboxItemNotExpired.into())
+        )
+    );
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: outbox_item_account.release_timestamp + MAX_QUEUE_AGE,
+        ..clock
+    });
+    ctx.get_new_latest_blockhash().await.unwrap();
+
+    // anyone can crank the reclaim, the tokens go back to the sender
+    reclaim().submit(ctx).await.unwrap();
+
+    // and the transfer no longer counts as queued
+    let outbound_limit: OutboxRateLimit = ctx
+        .get_account_data_anchor(test_data.ntt.outbox_rate_limit())
+        .await;
+    assert_eq!(outbound_limit.queued_amount, 0);
+
+    let token_account_after: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+    assert_eq!(token_account_before.amount, token_account_after.amount);
+
+    assert!(ctx
+        .banks_client
+        .get_account(outbox_item.pubkey())
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_capacity_aware_release() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    set_capacity_aware_release(
+        &test_data.ntt,
+        SetCapacityAwareRelease {
+            owner: test_data.program_owner.pubkey(),
+            pending_action: None,
+        },
+        true,
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let send = |ctx: &mut ProgramTestContext, outbox_item: &Keypair, amount: u64| {
+        let (accs, args) = init_accs_args(ctx, &test_data, outbox_item.pubkey(), amount, true);
+        (
+            approve_token_authority(
+                &test_data.ntt,
+                &test_data.user_token_account,
+                &test_data.user.pubkey(),
+                &args,
+            ),
+            transfer(&test_data.ntt, accs, args, Mode::Locking),
+        )
+    };
+
+    let release = |ctx: &mut ProgramTestContext, outbox_item: &Keypair| {
+        release_outbound(
+            &test_data.ntt,
+            ReleaseOutbound {
+                payer: ctx.payer.pubkey(),
+                outbox_item: outbox_item.pubkey(),
+                recipient_chain: OTHER_CHAIN,
+            },
+            ReleaseOutboundArgs {
+                revert_on_delay: false,
+            },
+        )
+    };
+
+    // use up the whole outbound capacity, then queue two more transfers: one
+    // that fits in the limit, and one that never will
+    let full = Keypair::new();
+    let fits = Keypair::new();
+    let too_much = Keypair::new();
+    for (outbox_item, amount) in [
+        (&full, OUTBOUND_LIMIT),
+        (&fits, 1000),
+        (&too_much, OUTBOUND_LIMIT + 1000),
+    ] {
+        let (approve, transfer) = send(&mut ctx, outbox_item, amount);
+        approve
+            .submit_with_signers(&[&test_data.user], &mut ctx)
+            .await
+            .unwrap();
+        transfer
+            .submit_with_signers(&[outbox_item], &mut ctx)
+            .await
+            .unwrap();
+    }
+
+    assert_queued(&mut ctx, fits.pubkey()).await;
+    assert_queued(&mut ctx, too_much.pubkey()).await;
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(fits.pubkey()).await;
+    assert_eq!(outbox_item_account.deferred_capacity, 1000);
+
+    // warp to when the capacity has fully refilled
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    ctx.set_sysvar(&Clock {
+        unix_timestamp: outbox_item_account.release_timestamp,
+        ..clock
+    });
+    ctx.get_new_latest_blockhash().await.unwrap();
+
+    // both transfers are due, but only one fits in the capacity
+    for (outbox_item, amount, releasable) in [
+        (&fits, 1000, true),
+        (&too_much, OUTBOUND_LIMIT + 1000, false),
+    ] {
+        let status: OutboxItemStatus = get_outbox_item_status(&test_data.ntt, outbox_item.pubkey())
+            .simulate(&mut ctx)
+            .await;
+        assert_eq!(status.releasable, releasable);
+        assert_eq!(status.deferred_capacity, amount);
+    }
+
+    // drain the inbound capacity, so that the backflow isn't capped by the
+    // limit
+    let inbox_rate_limit = test_data.ntt.inbox_rate_limit(OTHER_CHAIN);
+    let mut account = ctx
+        .banks_client
+        .get_account(inbox_rate_limit)
+        .await
+        .unwrap()
+        .unwrap();
+    let mut inbox_rate_limit_account: InboxRateLimit =
+        ctx.get_account_data_anchor(inbox_rate_limit).await;
+    inbox_rate_limit_account.rate_limit.capacity_at_last_tx = 0;
+    inbox_rate_limit_account.rate_limit.last_tx_timestamp = outbox_item_account.release_timestamp;
+    inbox_rate_limit_account
+        .try_serialize(&mut &mut account.data[..])
+        .unwrap();
+    ctx.set_account(&inbox_rate_limit, &account.into());
+
+    let inbound_capacity_before: u64 = get_inbound_capacity(&test_data.ntt, OTHER_CHAIN)
+        .simulate(&mut ctx)
+        .await;
+
+    // releasing the transfer consumes its capacity, and refills the inbound
+    // capacity like an immediate transfer would
+    release(&mut ctx, &fits).submit(&mut ctx).await.unwrap();
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(fits.pubkey()).await;
+    assert!(outbox_item_account.released.get(0).unwrap());
+    assert_eq!(outbox_item_account.deferred_capacity, 0);
+
+    let outbound_capacity: u64 = get_outbound_capacity(&test_data.ntt)
+        .simulate(&mut ctx)
+        .await;
+    assert_eq!(outbound_capacity, OUTBOUND_LIMIT - 1000);
+
+    let inbound_capacity: u64 = get_inbound_capacity(&test_data.ntt, OTHER_CHAIN)
+        .simulate(&mut ctx)
+        .await;
+    assert_eq!(inbound_capacity, inbound_capacity_before + 1000);
+
+    // the transfer over the limit is delayed again instead of being released
+    release(&mut ctx, &too_much).submit(&mut ctx).await.unwrap();
+
+    assert_queued(&mut ctx, too_much.pubkey()).await;
+
+    let outbound_capacity_after: u64 = get_outbound_capacity(&test_data.ntt)
+        .simulate(&mut ctx)
+        .await;
+    assert_eq!(outbound_capacity, outbound_capacity_after);
+}
+
+/// Sends and releases a transfer, and returns the consistency level of the
+/// posted message.
+async fn release_consistency_level(ctx: &mut ProgramTestContext, test_data: &TestData) -> u8 {
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(ctx, test_data, outbox_item.pubkey(), 100, false);
+
+    approve_token_authority(
+        &test_data.ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], ctx)
+    .await
+    .unwrap();
+    transfer(&test_data.ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], ctx)
+        .await
+        .unwrap();
+
+    release_outbound(
+        &test_data.ntt,
+        ReleaseOutbound {
+            payer: ctx.payer.pubkey(),
+            outbox_item: outbox_item.pubkey(),
+            recipient_chain: OTHER_CHAIN,
+        },
+        ReleaseOutboundArgs {
+            revert_on_delay: true,
+        },
+    )
+    .submit(ctx)
+    .await
+    .unwrap();
+
+    let wh_message = ctx
+        .banks_client
+        .get_account(test_data.ntt.wormhole_message(&outbox_item.pubkey()))
+        .await
+        .unwrap()
+        .unwrap();
+
+    // the posted message starts with the "msg" prefix and the VAA version,
+    // followed by the consistency level
+    wh_message.data[4]
+}
+
+#[tokio::test]
+async fn test_peer_finality() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let mut consistency_levels = vec![];
+    for finality in [WormholeFinality::Confirmed, WormholeFinality::Finalized] {
+        set_transceiver_peer_finality(
+            &test_data.ntt,
+            SetTransceiverPeerFinality {
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetTransceiverPeerFinalityArgs {
+                chain_id: ChainId { id: OTHER_CHAIN },
+                finality: Some(finality),
+            },
+        )
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+        consistency_levels.push(release_consistency_level(&mut ctx, &test_data).await);
+    }
+
+    assert_ne!(consistency_levels[0], consistency_levels[1]);
+}
+
+#[tokio::test]
+async fn test_default_finality() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let set_default_finality = |finality| {
+        set_wormhole_finality(
+            &test_data.ntt,
+            SetWormholeFinality {
+                owner: test_data.program_owner.pubkey(),
+            },
+            SetWormholeFinalityArgs { finality },
+        )
+    };
+
+    let mut consistency_levels = vec![];
+    for finality in [WormholeFinality::Confirmed, WormholeFinality::Finalized] {
+        set_default_finality(finality)
+            .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+            .await
+            .unwrap();
+
+        let config: Config = ctx.get_account_data_anchor(test_data.ntt.config()).await;
+        assert_eq!(config.wormhole_finality, finality);
+
+        consistency_levels.push(release_consistency_level(&mut ctx, &test_data).await);
+    }
+    assert_ne!(consistency_levels[0], consistency_levels[1]);
+
+    // the finality of the peer takes precedence over the default
+    set_transceiver_peer_finality(
+        &test_data.ntt,
+        SetTransceiverPeerFinality {
+            owner: test_data.program_owner.pubkey(),
+        },
+        SetTransceiverPeerFinalityArgs {
+            chain_id: ChainId { id: OTHER_CHAIN },
+            finality: Some(WormholeFinality::Confirmed),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert_eq!(
+        release_consistency_level(&mut ctx, &test_data).await,
+        consistency_levels[0]
+    );
+}
+
+#[tokio::test]
+async fn test_release_outbound_reusable() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let wh_message = test_data
+        .ntt
+        .reusable_wormhole_message(&ctx.payer.pubkey(), 0);
+
+    // the payer releases the user's transfers, so it has to be approved
+    approve_relayer(
+        &test_data.ntt,
+        ApproveRelayer {
+            payer: ctx.payer.pubkey(),
+            owner: test_data.program_owner.pubkey(),
+        },
+        ApproveRelayerArgs {
+            relayer: ctx.payer.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    let mut rent = None;
+    for amount in [100, 200] {
+        let outbox_item = Keypair::new();
+        let (accs, args) =
+            init_accs_args(&mut ctx, &test_data, outbox_item.pubkey(), amount, false);
+
+        approve_token_authority(
+            &test_data.ntt,
+            &test_data.user_token_account,
+            &test_data.user.pubkey(),
+            &args,
+        )
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+        transfer(&test_data.ntt, accs, args, Mode::Locking)
+            .submit_with_signers(&[&outbox_item], &mut ctx)
+            .await
+            .unwrap();
+
+        release_outbound_reusable(
+            &test_data.ntt,
+            ReleaseOutboundReusable {
+                payer: ctx.payer.pubkey(),
+                outbox_item: outbox_item.pubkey(),
+                recipient_chain: OTHER_CHAIN,
+                approved_relayer: true,
+            },
+            ReleaseOutboundReusableArgs {
+                revert_on_delay: true,
+                message_index: 0,
+            },
+        )
+        .submit(&mut ctx)
+        .await
+        .unwrap();
+
+        // the same account is overwritten, so rent is only paid once
+        let account = ctx
+            .banks_client
+            .get_account(wh_message)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(*rent.get_or_insert(account.lamports), account.lamports);
+
+        // NOTE: see [`test_transfer`] on parsing the message as a PostedVaa
+        let msg: PostedVaa<TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>>> =
+            ctx.get_account_data_anchor_unchecked(wh_message).await;
+
+        assert_eq!(msg.emitter_address(), &test_data.ntt.emitter().to_bytes());
+        assert_eq!(
+            msg.data().ntt_manager_payload.id,
+            outbox_item.pubkey().to_bytes()
+        );
+        assert_eq!(
+            msg.data().ntt_manager_payload.payload.amount,
+            TrimmedAmount {
+                amount: amount / 100,
+                decimals: 7
+            }
+        );
+    }
+
+    revoke_relayer(
+        &test_data.ntt,
+        RevokeRelayer {
+            owner: test_data.program_owner.pubkey(),
+            relayer: ctx.payer.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    assert!(ctx
+        .banks_client
+        .get_account(test_data.ntt.approved_relayer(&ctx.payer.pubkey()))
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_release_outbound_reusable_unapproved_relayer() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(&mut ctx, &test_data, outbox_item.pubkey(), 100, false);
+
+    approve_token_authority(
+        &test_data.ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+    transfer(&test_data.ntt, accs, args, Mode::Locking)
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let release = |payer: Pubkey| {
+        release_outbound_reusable(
+            &test_data.ntt,
+            ReleaseOutboundReusable {
+                payer,
+                outbox_item: outbox_item.pubkey(),
+                recipient_chain: OTHER_CHAIN,
+                approved_relayer: false,
+            },
+            ReleaseOutboundReusableArgs {
+                revert_on_delay: true,
+                message_index: 0,
+            },
+        )
+    };
+
+    // a third party could overwrite its reusable message before the guardians
+    // observe it, losing the release, so it's rejected
+    let err = release(ctx.payer.pubkey())
+        .submit(&mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::UnapprovedRelayer.into())
+        )
+    );
+
+    // the transfer is still releasable
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(outbox_item_account.released, Bitmap::new());
+
+    // the sender can release its own transfer into its reusable message
+    solana_sdk::system_instruction::transfer(
+        &ctx.payer.pubkey(),
+        &test_data.user.pubkey(),
+        1_000_000_000,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    release(test_data.user.pubkey())
+        .submit_with_signers(&[&test_data.user], &mut ctx)
+        .await
+        .unwrap();
+
+    let wh_message = test_data
+        .ntt
+        .reusable_wormhole_message(&test_data.user.pubkey(), 0);
+    let msg: PostedVaa<TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>>> =
+        ctx.get_account_data_anchor_unchecked(wh_message).await;
+    assert_eq!(
+        msg.data().ntt_manager_payload.id,
+        outbox_item.pubkey().to_bytes()
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_direct_locking() {
+    test_transfer_direct(Mode::Locking).await;
+}
+
+#[tokio::test]
+async fn test_transfer_direct_burning() {
+    test_transfer_direct(Mode::Burning).await;
+}
+
+async fn test_transfer_direct(mode: Mode) {
+    let (mut ctx, test_data) = setup(mode).await;
+
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(&mut ctx, &test_data, outbox_item.pubkey(), 100, false);
+
+    let token_account_before: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+
+    // no approval: the owner of the token account signs the transfer itself
+    transfer_direct(&test_data.ntt, accs, args, mode)
+        .submit_with_signers(&[&outbox_item, &test_data.user], &mut ctx)
+        .await
+        .unwrap();
+
+    let token_account_after: TokenAccount = ctx
+        .get_account_data_anchor(test_data.user_token_account)
+        .await;
+    assert_eq!(
+        token_account_before.amount - 100,
+        token_account_after.amount
+    );
+
+    let outbox_item_account: OutboxItem = ctx.get_account_data_anchor(outbox_item.pubkey()).await;
+    assert_eq!(outbox_item_account.sender, test_data.user.pubkey());
+}
+
+#[tokio::test]
+async fn test_transfer_direct_wrong_authority() {
+    let (mut ctx, test_data) = setup(Mode::Locking).await;
+
+    let outbox_item = Keypair::new();
+    let (accs, args) = init_accs_args(&mut ctx, &test_data, outbox_item.pubkey(), 100, false);
+
+    let impostor = Keypair::new();
+    let err = transfer_direct(
+        &test_data.ntt,
+        Transfer {
+            from_authority: impostor.pubkey(),
+            ..accs
+        },
+        args,
+        Mode::Locking,
+    )
+    .submit_with_signers(&[&outbox_item, &impostor], &mut ctx)
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidFromAuthority.into())
+        )
+    );
+}
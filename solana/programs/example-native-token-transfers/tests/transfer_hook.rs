@@ -0,0 +1,372 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use anchor_lang::{prelude::*, InstructionData};
+use anchor_spl::associated_token::AssociatedToken;
+use example_native_token_transfers::{
+    error::NTTError, instructions::TransferArgs, transfer_hook::TransferHookAccount,
+};
+use ntt_messages::{chain_id::ChainId, mode::Mode};
+use solana_program::{
+    address_lookup_table::state::AddressLookupTable,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    slot_hashes::SlotHashes,
+};
+use solana_program_test::*;
+use solana_sdk::{
+    signature::Keypair,
+    signer::Signer,
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::extension::ExtensionType;
+
+use crate::{
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{
+            add_program_upgradeable, setup_ntt_with_token_program_id, setup_programs, TestData,
+            MINT_AMOUNT, OTHER_CHAIN,
+        },
+        submit::{Simulatable, Submittable},
+    },
+    sdk::{
+        accounts::{Governance, Wormhole, NTT},
+        instructions::{
+            luts::{
+                extend_lut_with_transfer_hook, initialize_lut_with_token_program_id, ExtendLUT,
+                InitializeLUT,
+            },
+            transfer::{
+                approve_token_authority_with_token_program_id, transfer_lock_with_token_program_id,
+                Transfer,
+            },
+            views::{get_transfer_hook_accounts, GetTransferHookAccounts},
+        },
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+/// Sets up NTT in locking mode with a token-2022 mint whose transfer hook is
+/// [`dummy_transfer_hook`].
+async fn setup_with_transfer_hook() -> (ProgramTestContext, TestData) {
+    let program_owner = Keypair::new();
+    let mut program_test = setup_programs(program_owner.pubkey()).await.unwrap();
+    add_program_upgradeable(
+        &mut program_test,
+        "dummy_transfer_hook",
+        dummy_transfer_hook::ID,
+        None,
+    );
+    let mut ctx = program_test.start_with_context().await;
+
+    let mint = Keypair::new();
+    let mint_authority = Keypair::new();
+    let user = Keypair::new();
+
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::TransferHook,
+    ])
+    .unwrap();
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+
+    Transaction::new_signed_with_payer(
+        &[
+            system_instruction::create_account(
+                &ctx.payer.pubkey(),
+                &mint.pubkey(),
+                rent.minimum_balance(space),
+                space as u64,
+                &spl_token_2022::id(),
+            ),
+            spl_token_2022::extension::transfer_hook::instruction::initialize(
+                &spl_token_2022::id(),
+                &mint.pubkey(),
+                None,
+                Some(dummy_transfer_hook::ID),
+            )
+            .unwrap(),
+            spl_token_2022::instruction::initialize_mint2(
+                &spl_token_2022::id(),
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                None,
+                9,
+            )
+            .unwrap(),
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &mint],
+        blockhash,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    Instruction {
+        program_id: dummy_transfer_hook::ID,
+        accounts: dummy_transfer_hook::accounts::InitializeExtraAccountMetaList {
+            payer: ctx.payer.pubkey(),
+            extra_account_meta_list: validation_account(&mint.pubkey()),
+            mint: mint.pubkey(),
+            token_program: spl_token_2022::id(),
+            associated_token_program: AssociatedToken::id(),
+            counter: counter(),
+            system_program: System::id(),
+        }
+        .to_account_metas(None),
+        data: dummy_transfer_hook::instruction::InitializeExtraAccountMetaList {}.data(),
+    }
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let user_token_account = get_associated_token_address_with_program_id(
+        &user.pubkey(),
+        &mint.pubkey(),
+        &spl_token_2022::id(),
+    );
+
+    spl_associated_token_account::instruction::create_associated_token_account(
+        &ctx.payer.pubkey(),
+        &user.pubkey(),
+        &mint.pubkey(),
+        &spl_token_2022::id(),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    spl_token_2022::instruction::mint_to(
+        &spl_token_2022::id(),
+        &mint.pubkey(),
+        &user_token_account,
+        &mint_authority.pubkey(),
+        &[],
+        MINT_AMOUNT,
+    )
+    .unwrap()
+    .submit_with_signers(&[&mint_authority], &mut ctx)
+    .await
+    .unwrap();
+
+    let test_data = TestData {
+        ntt: NTT {
+            program: example_native_token_transfers::ID,
+            wormhole: Wormhole {
+                program: wormhole_anchor_sdk::wormhole::program::ID,
+            },
+            multi_token_mint: None,
+        },
+        governance: Governance {
+            program: wormhole_governance::ID,
+        },
+        program_owner,
+        mint_authority,
+        mint: mint.pubkey(),
+        user,
+        user_token_account,
+    };
+
+    setup_ntt_with_token_program_id(&mut ctx, &test_data, Mode::Locking, &spl_token_2022::id())
+        .await;
+
+    (ctx, test_data)
+}
+
+fn validation_account(mint: &Pubkey) -> Pubkey {
+    spl_transfer_hook_interface::get_extra_account_metas_address(mint, &dummy_transfer_hook::ID)
+}
+
+fn counter() -> Pubkey {
+    Pubkey::find_program_address(&[b"counter"], &dummy_transfer_hook::ID).0
+}
+
+#[tokio::test]
+async fn test_transfer_hook_accounts() {
+    let (mut ctx, test_data) = setup_with_transfer_hook().await;
+
+    let outbox_item = Keypair::new();
+    let accs = Transfer {
+        payer: ctx.payer.pubkey(),
+        peer: test_data.ntt.peer(OTHER_CHAIN),
+        mint: test_data.mint,
+        from: test_data.user_token_account,
+        from_authority: test_data.user.pubkey(),
+        outbox_item: outbox_item.pubkey(),
+    };
+    let args = TransferArgs {
+        amount: 100,
+        recipient_chain: ChainId { id: OTHER_CHAIN },
+        recipient_address: [1u8; 32],
+        should_queue: false,
+    };
+
+    let hook_accounts: Vec<TransferHookAccount> = get_transfer_hook_accounts(
+        &test_data.ntt,
+        GetTransferHookAccounts {
+            mint: test_data.mint,
+            source: test_data.user_token_account,
+            destination: test_data
+                .ntt
+                .custody_with_token_program_id(&test_data.mint, &spl_token_2022::id()),
+            authority: test_data
+                .ntt
+                .session_authority(&test_data.user.pubkey(), &args),
+            extra_accounts: vec![validation_account(&test_data.mint)],
+        },
+        args.amount,
+    )
+    .simulate(&mut ctx)
+    .await;
+
+    let dummy_account = Pubkey::find_program_address(
+        &[b"dummy_account", test_data.user.pubkey().as_ref()],
+        &dummy_transfer_hook::ID,
+    )
+    .0;
+    assert_eq!(
+        hook_accounts,
+        vec![
+            TransferHookAccount {
+                pubkey: dummy_account,
+                is_signer: false,
+                is_writable: false,
+            },
+            TransferHookAccount {
+                pubkey: counter(),
+                is_signer: false,
+                is_writable: true,
+            },
+            TransferHookAccount {
+                pubkey: validation_account(&test_data.mint),
+                is_signer: false,
+                is_writable: false,
+            },
+            TransferHookAccount {
+                pubkey: dummy_transfer_hook::ID,
+                is_signer: false,
+                is_writable: false,
+            },
+        ]
+    );
+
+    approve_token_authority_with_token_program_id(
+        &test_data.ntt,
+        &test_data.user_token_account,
+        &test_data.user.pubkey(),
+        &args,
+        &spl_token_2022::id(),
+    )
+    .submit_with_signers(&[&test_data.user], &mut ctx)
+    .await
+    .unwrap();
+
+    let transfer = || {
+        transfer_lock_with_token_program_id(
+            &test_data.ntt,
+            accs.clone(),
+            args.clone(),
+            &spl_token_2022::id(),
+        )
+    };
+
+    // the counter is not writable
+    let mut ix = transfer();
+    ix.accounts.extend(
+        hook_accounts
+            .iter()
+            .map(|account| AccountMeta::new_readonly(account.pubkey, false)),
+    );
+    let err = ix
+        .submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(NTTError::InvalidTransferHookAccounts.into())
+        )
+    );
+
+    let mut ix = transfer();
+    ix.accounts
+        .extend(hook_accounts.iter().map(|account| AccountMeta {
+            pubkey: account.pubkey,
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        }));
+    ix.submit_with_signers(&[&outbox_item], &mut ctx)
+        .await
+        .unwrap();
+
+    let counter_account: dummy_transfer_hook::Counter =
+        ctx.get_account_data_anchor(counter()).await;
+    assert_eq!(counter_account.count, 1);
+}
+
+#[tokio::test]
+async fn test_extend_lut_with_transfer_hook() {
+    let (mut ctx, test_data) = setup_with_transfer_hook().await;
+    let ntt = &test_data.ntt;
+
+    // LUTs are derived from a slot that has to be in the slot hashes
+    let recent_slot = 1;
+    ctx.set_sysvar(&SlotHashes::new(&[(recent_slot, Hash::new_unique())]));
+    initialize_lut_with_token_program_id(
+        ntt,
+        InitializeLUT {
+            payer: ctx.payer.pubkey(),
+            mint: test_data.mint,
+        },
+        recent_slot,
+        &spl_token_2022::id(),
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let lut_address = ntt.lut_address(recent_slot);
+    let lut_entries = |data: &[u8]| {
+        AddressLookupTable::deserialize(data)
+            .unwrap()
+            .addresses
+            .to_vec()
+    };
+    let account = ctx
+        .banks_client
+        .get_account(lut_address)
+        .await
+        .unwrap()
+        .unwrap();
+    let entries = lut_entries(&account.data);
+
+    extend_lut_with_transfer_hook(
+        ntt,
+        ExtendLUT {
+            payer: ctx.payer.pubkey(),
+            lut_address,
+        },
+        test_data.mint,
+    )
+    .submit(&mut ctx)
+    .await
+    .unwrap();
+
+    let account = ctx
+        .banks_client
+        .get_account(lut_address)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        lut_entries(&account.data)[entries.len()..],
+        [dummy_transfer_hook::ID, validation_account(&test_data.mint)]
+    );
+}
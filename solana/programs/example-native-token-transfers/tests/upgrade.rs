@@ -0,0 +1,222 @@
+#![cfg(feature = "test-sbf")]
+#![feature(type_changing_struct_update)]
+
+use anchor_lang::prelude::*;
+use example_native_token_transfers::{config::Config, error::NTTError};
+use ntt_messages::mode::Mode;
+use solana_program::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use solana_program_test::*;
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer};
+
+use crate::{
+    common::{
+        query::GetAccountDataAnchor,
+        setup::{setup_with_extra_accounts, TestData},
+        submit::{assert_ntt_error, Submittable},
+    },
+    sdk::instructions::admin::{
+        claim_ownership, set_paused, transfer_ownership, upgrade, ClaimOwnership, SetPaused,
+        TransferOwnership, Upgrade,
+    },
+};
+
+pub mod common;
+pub mod sdk;
+
+/// Sets up NTT with a buffer holding the program's own code, whose authority
+/// is the upgrade lock.
+async fn setup_with_buffer() -> (ProgramTestContext, TestData, Pubkey) {
+    let buffer = Keypair::new().pubkey();
+    let upgrade_lock =
+        Pubkey::find_program_address(&[b"upgrade_lock"], &example_native_token_transfers::ID).0;
+
+    let mut data = bincode::serialize(&UpgradeableLoaderState::Buffer {
+        authority_address: Some(upgrade_lock),
+    })
+    .unwrap();
+    data.extend_from_slice(&read_file(
+        find_file("example_native_token_transfers.so").unwrap(),
+    ));
+
+    let (ctx, test_data) = setup_with_extra_accounts(
+        Mode::Locking,
+        &[(
+            buffer,
+            Account {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: bpf_loader_upgradeable::id(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        )],
+    )
+    .await;
+
+    (ctx, test_data, buffer)
+}
+
+#[tokio::test]
+async fn test_upgrade() {
+    let (mut ctx, test_data, buffer) = setup_with_buffer().await;
+
+    let upgrade_ix = |ctx: &ProgramTestContext| {
+        upgrade(
+            &test_data.ntt,
+            Upgrade {
+                owner: test_data.program_owner.pubkey(),
+                buffer,
+                spill: ctx.payer.pubkey(),
+                pending_action: None,
+            },
+        )
+    };
+    let pause = |paused| {
+        set_paused(
+            &test_data.ntt,
+            SetPaused {
+                owner: test_data.program_owner.pubkey(),
+            },
+            paused,
+        )
+    };
+
+    // the program has to be paused
+    let result = upgrade_ix(&ctx)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await;
+    assert_ntt_error(result, NTTError::NotPaused);
+
+    pause(true)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    // the owner still holds the upgrade authority
+    let result = upgrade_ix(&ctx)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await;
+    assert_ntt_error(result, NTTError::InvalidUpgradeAuthority);
+
+    bpf_loader_upgradeable::set_upgrade_authority(
+        &test_data.ntt.program,
+        &test_data.program_owner.pubkey(),
+        Some(&test_data.ntt.upgrade_lock()),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // only the owner can upgrade
+    let result = upgrade(
+        &test_data.ntt,
+        Upgrade {
+            owner: ctx.payer.pubkey(),
+            buffer,
+            spill: ctx.payer.pubkey(),
+            pending_action: None,
+        },
+    )
+    .submit(&mut ctx)
+    .await;
+    assert_ntt_error(result, NTTError::InvalidOwner);
+
+    upgrade_ix(&ctx)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    // the buffer is closed
+    assert!(ctx
+        .banks_client
+        .get_account(buffer)
+        .await
+        .unwrap()
+        .is_none());
+
+    // the upgraded program is only visible from the next slot
+    let slot = ctx.banks_client.get_root_slot().await.unwrap();
+    ctx.warp_to_slot(slot + 2).unwrap();
+
+    // the config layout is unchanged, so the program can be unpaused
+    pause(false)
+        .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+        .await
+        .unwrap();
+
+    let config: Config = ctx.get_account_data_anchor(test_data.ntt.config()).await;
+    assert!(!config.paused);
+    assert_eq!(config.layout_version, Config::LAYOUT_VERSION);
+}
+
+#[tokio::test]
+async fn test_upgrade_after_ownership_transfer() {
+    let (mut ctx, test_data, buffer) = setup_with_buffer().await;
+    let new_owner = Keypair::new();
+
+    bpf_loader_upgradeable::set_upgrade_authority(
+        &test_data.ntt.program,
+        &test_data.program_owner.pubkey(),
+        Some(&test_data.ntt.upgrade_lock()),
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    transfer_ownership(
+        &test_data.ntt,
+        TransferOwnership {
+            owner: test_data.program_owner.pubkey(),
+            new_owner: new_owner.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&test_data.program_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    claim_ownership(
+        &test_data.ntt,
+        ClaimOwnership {
+            new_owner: new_owner.pubkey(),
+        },
+    )
+    .submit_with_signers(&[&new_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    // the upgrade lock keeps the authority it didn't hold for the transfer
+    let config: Config = ctx.get_account_data_anchor(test_data.ntt.config()).await;
+    assert_eq!(config.owner, new_owner.pubkey());
+    assert!(!config.upgrade_authority_escrowed);
+    let program_data: ProgramData = ctx
+        .get_account_data_anchor(test_data.ntt.program_data())
+        .await;
+    assert_eq!(
+        program_data.upgrade_authority_address,
+        Some(test_data.ntt.upgrade_lock())
+    );
+
+    set_paused(
+        &test_data.ntt,
+        SetPaused {
+            owner: new_owner.pubkey(),
+        },
+        true,
+    )
+    .submit_with_signers(&[&new_owner], &mut ctx)
+    .await
+    .unwrap();
+
+    upgrade(
+        &test_data.ntt,
+        Upgrade {
+            owner: new_owner.pubkey(),
+            buffer,
+            spill: ctx.payer.pubkey(),
+            pending_action: None,
+        },
+    )
+    .submit_with_signers(&[&new_owner], &mut ctx)
+    .await
+    .unwrap();
+}
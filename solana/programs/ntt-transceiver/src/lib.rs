@@ -25,8 +25,25 @@ pub mod ntt_transceiver {
         set_transceiver_peer(ctx, args)
     }
 
-    pub fn receive_wormhole_message(ctx: Context<ReceiveMessage>) -> Result<()> {
-        wormhole::instructions::receive_message(ctx)
+    pub fn set_wormhole_peer_finality(
+        ctx: Context<SetTransceiverPeerFinality>,
+        args: SetTransceiverPeerFinalityArgs,
+    ) -> Result<()> {
+        set_transceiver_peer_finality(ctx, args)
+    }
+
+    pub fn migrate_wormhole_peer(
+        ctx: Context<MigrateTransceiverPeer>,
+        chain_id: u16,
+    ) -> Result<()> {
+        migrate_transceiver_peer(ctx, chain_id)
+    }
+
+    pub fn receive_wormhole_message(
+        ctx: Context<ReceiveMessage>,
+        args: ReceiveMessageArgs,
+    ) -> Result<()> {
+        wormhole::instructions::receive_message(ctx, args)
     }
 
     pub fn release_wormhole_outbound(
@@ -36,6 +53,13 @@ pub mod ntt_transceiver {
         wormhole::instructions::release_outbound(ctx, args)
     }
 
+    pub fn release_wormhole_outbound_reusable(
+        ctx: Context<ReleaseOutboundReusable>,
+        args: ReleaseOutboundReusableArgs,
+    ) -> Result<()> {
+        wormhole::instructions::release_outbound_reusable(ctx, args)
+    }
+
     pub fn broadcast_wormhole_id(ctx: Context<BroadcastId>) -> Result<()> {
         wormhole::instructions::broadcast_id(ctx)
     }
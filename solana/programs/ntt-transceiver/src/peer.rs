@@ -1,11 +1,18 @@
 use anchor_lang::prelude::*;
+use example_native_token_transfers::transceivers::wormhole::accounts::WormholeFinality;
 
 #[account]
 #[derive(InitSpace)]
-/// A peer on another chain. Stored in a PDA seeded by the chain id.
+/// A peer on another chain. Stored in a PDA seeded by the chain id (and the
+/// mint in multi-token mode, see [`example_native_token_transfers::config::mint_seed`]).
 pub struct TransceiverPeer {
     pub bump: u8,
     pub address: [u8; 32],
+    /// The finality of messages sent to this chain. When not set, the
+    /// manager's
+    /// [`example_native_token_transfers::config::Config::wormhole_finality`]
+    /// is used.
+    pub finality: Option<WormholeFinality>,
 }
 
 impl TransceiverPeer {
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use example_native_token_transfers::transceivers::wormhole::accounts::WormholeFinality;
 use wormhole_anchor_sdk::wormhole;
 use wormhole_io::TypePrefixedPayload;
 
@@ -34,6 +35,7 @@ pub fn post_message<'info, A: TypePrefixedPayload>(
     emitter: AccountInfo<'info>,
     emitter_bump: u8,
     payload: &A,
+    finality: WormholeFinality,
     additional_seeds: &[&[&[u8]]],
 ) -> Result<()> {
     let batch_id = 0;
@@ -61,12 +63,104 @@ pub fn post_message<'info, A: TypePrefixedPayload>(
         CpiContext::new_with_signer(wormhole.program.to_account_info(), ix, &seeds.concat()),
         batch_id,
         TypePrefixedPayload::to_vec_payload(payload),
-        wormhole::Finality::Finalized,
+        finality.into(),
     )?;
 
     Ok(())
 }
 
+/// Like [`post_message`], but posts an unreliable message. Unlike regular
+/// messages, the core bridge allows unreliable message accounts to be
+/// overwritten by the same emitter, so `message` can be reused across
+/// transfers, and its rent is only paid once.
+///
+/// The catch is that the message has to be observed by the guardians before
+/// the account is overwritten, otherwise it's lost (and has to be reobserved).
+/// The account also can't be resized, so the payload has to be the same
+/// length every time it's reused.
+///
+/// The emitted VAA is indistinguishable from one emitted by [`post_message`].
+pub fn post_message_unreliable<'info, A: TypePrefixedPayload>(
+    wormhole: &WormholeAccounts<'info>,
+    payer: AccountInfo<'info>,
+    message: AccountInfo<'info>,
+    emitter: AccountInfo<'info>,
+    emitter_bump: u8,
+    payload: &A,
+    finality: WormholeFinality,
+    additional_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let batch_id = 0;
+
+    pay_wormhole_fee(wormhole, &payer)?;
+
+    // NOTE: the sdk doesn't expose a CPI helper for unreliable messages, so
+    // we build the instruction by hand. The accounts are the same as for
+    // regular messages.
+    let ix = solana_program::instruction::Instruction {
+        program_id: wormhole.program.key(),
+        accounts: vec![
+            AccountMeta::new(wormhole.bridge.key(), false),
+            AccountMeta::new(message.key(), true),
+            AccountMeta::new_readonly(emitter.key(), true),
+            AccountMeta::new(wormhole.sequence.key(), false),
+            AccountMeta::new(payer.key(), true),
+            AccountMeta::new(wormhole.fee_collector.key(), false),
+            AccountMeta::new_readonly(wormhole.clock.key(), false),
+            AccountMeta::new_readonly(wormhole.system_program.key(), false),
+            AccountMeta::new_readonly(wormhole.rent.key(), false),
+        ],
+        data: PostMessageUnreliableData {
+            batch_id,
+            payload: TypePrefixedPayload::to_vec_payload(payload),
+            finality: finality.into(),
+        }
+        .data(),
+    };
+
+    let seeds: &[&[&[&[u8]]]] = &[
+        &[&[b"emitter".as_slice(), &[emitter_bump]]],
+        additional_seeds,
+    ];
+
+    solana_program::program::invoke_signed(
+        &ix,
+        &[
+            wormhole.bridge.to_account_info(),
+            message,
+            emitter,
+            wormhole.sequence.to_account_info(),
+            payer,
+            wormhole.fee_collector.to_account_info(),
+            wormhole.clock.to_account_info(),
+            wormhole.system_program.to_account_info(),
+            wormhole.rent.to_account_info(),
+        ],
+        &seeds.concat(),
+    )?;
+
+    Ok(())
+}
+
+/// Instruction data of the core bridge's `PostMessageUnreliable` instruction.
+#[derive(AnchorSerialize)]
+struct PostMessageUnreliableData {
+    batch_id: u32,
+    payload: Vec<u8>,
+    finality: wormhole::Finality,
+}
+
+impl PostMessageUnreliableData {
+    /// The index of `PostMessageUnreliable` in the core bridge's instruction enum.
+    const DISCRIMINANT: u8 = 8;
+
+    fn data(&self) -> Vec<u8> {
+        let mut data = vec![Self::DISCRIMINANT];
+        data.extend(self.try_to_vec().unwrap());
+        data
+    }
+}
+
 fn pay_wormhole_fee<'info>(
     wormhole: &WormholeAccounts<'info>,
     payer: &AccountInfo<'info>,
@@ -1,18 +1,28 @@
 use crate::peer::TransceiverPeer;
 use anchor_lang::prelude::*;
-use example_native_token_transfers::config::Config;
+use example_native_token_transfers::{
+    config::Config,
+    program::ExampleNativeTokenTransfers,
+    timelock::{AdminAction, PendingAdminAction},
+    transceivers::wormhole::accounts::WormholeFinality,
+};
 use ntt_messages::chain_id::ChainId;
 
 #[derive(Accounts)]
 #[instruction(args: SetTransceiverPeerArgs)]
 pub struct SetTransceiverPeer<'info> {
-    #[account(
-        has_one = owner,
-    )]
     pub config: Account<'info, Config>,
 
+    #[account(mut)]
     pub owner: Signer<'info>,
 
+    /// Required when the timelock is enabled. See
+    /// [`example_native_token_transfers::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
+    pub manager: Program<'info, ExampleNativeTokenTransfers>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -20,7 +30,7 @@ pub struct SetTransceiverPeer<'info> {
         init,
         space = 8 + TransceiverPeer::INIT_SPACE,
         payer = payer,
-        seeds = [TransceiverPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref()],
+        seeds = [TransceiverPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref(), config.mint_seed()],
         bump
     )]
     pub peer: Account<'info, TransceiverPeer>,
@@ -38,10 +48,102 @@ pub fn set_transceiver_peer(
     ctx: Context<SetTransceiverPeer>,
     args: SetTransceiverPeerArgs,
 ) -> Result<()> {
+    execute_admin_action(
+        &ctx.accounts.manager,
+        &ctx.accounts.config,
+        &ctx.accounts.owner,
+        &ctx.accounts.pending_action,
+        ctx.remaining_accounts,
+        AdminAction::SetWormholePeer {
+            chain_id: args.chain_id,
+            address: args.address,
+        },
+    )?;
+
     ctx.accounts.peer.set_inner(TransceiverPeer {
         bump: ctx.bumps.peer,
         address: args.address,
+        finality: None,
     });
 
     Ok(())
 }
+
+#[derive(Accounts)]
+#[instruction(args: SetTransceiverPeerFinalityArgs)]
+pub struct SetTransceiverPeerFinality<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Required when the timelock is enabled. See
+    /// [`example_native_token_transfers::timelock`].
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAdminAction>>,
+
+    pub manager: Program<'info, ExampleNativeTokenTransfers>,
+
+    #[account(
+        mut,
+        seeds = [TransceiverPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref(), config.mint_seed()],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, TransceiverPeer>,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetTransceiverPeerFinalityArgs {
+    pub chain_id: ChainId,
+    /// `None` reverts to the default finality.
+    pub finality: Option<WormholeFinality>,
+}
+
+pub fn set_transceiver_peer_finality(
+    ctx: Context<SetTransceiverPeerFinality>,
+    args: SetTransceiverPeerFinalityArgs,
+) -> Result<()> {
+    execute_admin_action(
+        &ctx.accounts.manager,
+        &ctx.accounts.config,
+        &ctx.accounts.owner,
+        &ctx.accounts.pending_action,
+        ctx.remaining_accounts,
+        AdminAction::SetWormholePeerFinality {
+            chain_id: args.chain_id,
+            finality: args.finality,
+        },
+    )?;
+
+    ctx.accounts.peer.finality = args.finality;
+
+    Ok(())
+}
+
+/// Authorizes `owner` and enforces the manager's timelock for `action`.
+///
+/// The proposal is owned by the manager program, so it's checked and closed by
+/// the manager (see [`example_native_token_transfers::instructions::execute_admin_action`]).
+/// The owner's signature, and the signatures of any additional owner set
+/// members in `remaining_accounts`, are forwarded to it.
+fn execute_admin_action<'info>(
+    manager: &Program<'info, ExampleNativeTokenTransfers>,
+    config: &Account<'info, Config>,
+    owner: &Signer<'info>,
+    pending_action: &Option<Account<'info, PendingAdminAction>>,
+    remaining_accounts: &[AccountInfo<'info>],
+    action: AdminAction,
+) -> Result<()> {
+    example_native_token_transfers::cpi::execute_admin_action(
+        CpiContext::new(
+            manager.to_account_info(),
+            example_native_token_transfers::cpi::accounts::ExecuteAdminAction {
+                owner: owner.to_account_info(),
+                config: config.to_account_info(),
+                pending_action: pending_action.as_ref().map(|a| a.to_account_info()),
+            },
+        )
+        .with_remaining_accounts(remaining_accounts.to_vec()),
+        action,
+    )
+}
@@ -0,0 +1,46 @@
+use crate::peer::TransceiverPeer;
+use anchor_lang::prelude::*;
+use example_native_token_transfers::legacy::{self, LegacyTransceiverPeer};
+
+/// Migrates a peer created before per-peer finality. Permissionless, like the
+/// manager's migrations (see [`example_native_token_transfers::legacy`]).
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct MigrateTransceiverPeer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TransceiverPeer::SEED_PREFIX, chain_id.to_be_bytes().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    /// CHECK: checked by [`legacy::read`].
+    pub peer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_transceiver_peer(
+    ctx: Context<MigrateTransceiverPeer>,
+    _chain_id: u16,
+) -> Result<()> {
+    let legacy = legacy::read::<TransceiverPeer, LegacyTransceiverPeer>(
+        &ctx.accounts.peer.try_borrow_data()?,
+    )?;
+
+    let peer = TransceiverPeer {
+        bump: legacy.bump,
+        address: legacy.address,
+        finality: None,
+    };
+
+    legacy::rewrite(
+        &ctx.accounts.peer,
+        &peer,
+        8 + TransceiverPeer::INIT_SPACE,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )
+}
@@ -3,17 +3,15 @@ use anchor_lang::prelude::*;
 use example_native_token_transfers::{
     config::{anchor_reexports::*, *},
     error::NTTError,
+    transceivers::wormhole::vaa_account::VaaAccount,
     transfer::Payload,
 };
 use ntt_messages::{
-    chain_id::ChainId,
-    ntt::NativeTokenTransfer,
-    transceiver::{TransceiverMessage, TransceiverMessageData},
-    transceivers::wormhole::WormholeTransceiver,
+    chain_id::ChainId, ntt::NativeTokenTransfer, transceiver::TransceiverMessageData,
 };
-use wormhole_anchor_sdk::wormhole::PostedVaa;
 
 #[derive(Accounts)]
+#[instruction(args: ReceiveMessageArgs)]
 pub struct ReceiveMessage<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -21,34 +19,27 @@ pub struct ReceiveMessage<'info> {
     pub config: NotPausedConfig<'info>,
 
     #[account(
-        seeds = [TransceiverPeer::SEED_PREFIX, vaa.emitter_chain().to_be_bytes().as_ref()],
-        constraint = peer.address == *vaa.emitter_address() @ NTTError::InvalidTransceiverPeer,
+        seeds = [TransceiverPeer::SEED_PREFIX, args.chain_id.id.to_be_bytes().as_ref(), config.mint_seed()],
         bump = peer.bump,
     )]
     pub peer: Account<'info, TransceiverPeer>,
 
-    // TODO: Consider using VaaAccount from wormhole-solana-vaa crate. Using a zero-copy reader
-    // will allow this instruction to be generic (instead of strictly specifying NativeTokenTransfer
-    // as the message type).
-    #[account(
-        // check that the messages is targeted to this chain
-        constraint = vaa.message().ntt_manager_payload.payload.to_chain == config.chain_id @ NTTError::InvalidChainId,
-        // NOTE: we don't replay protect VAAs. Instead, we replay protect
-        // executing the messages themselves with the [`released`] flag.
-    )]
-    pub vaa: Account<
-        'info,
-        PostedVaa<TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>>>,
-    >,
+    // NOTE: we don't replay protect VAAs. Instead, we replay protect
+    // executing the messages themselves with the [`released`] flag.
+    /// CHECK: either a legacy posted VAA or a verified encoded VAA. The owner
+    /// (the core bridge) and the layout are checked by [`VaaAccount::load`],
+    /// and the emitter by [`VaaAccount::transceiver_message`].
+    pub vaa: UncheckedAccount<'info>,
 
     #[account(
         init,
         payer = payer,
-        space = 8 + ValidatedTransceiverMessage::<TransceiverMessageData<NativeTokenTransfer<Payload>>>::INIT_SPACE,
+        space = 8 + ValidatedTransceiverMessage::<TransceiverMessageData<NativeTokenTransfer<Payload>>>::INIT_SPACE + usize::from(args.additional_payload_len),
         seeds = [
             ValidatedTransceiverMessage::<TransceiverMessageData<NativeTokenTransfer<Payload>>>::SEED_PREFIX,
-            vaa.emitter_chain().to_be_bytes().as_ref(),
-            vaa.message().ntt_manager_payload.id.as_ref(),
+            args.chain_id.id.to_be_bytes().as_ref(),
+            args.message_id.as_ref(),
+            config.mint_seed(),
         ],
         bump,
     )]
@@ -62,14 +53,49 @@ pub struct ReceiveMessage<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn receive_message(ctx: Context<ReceiveMessage>) -> Result<()> {
-    let message = ctx.accounts.vaa.message().message_data.clone();
-    let chain_id = ctx.accounts.vaa.emitter_chain();
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ReceiveMessageArgs {
+    /// The emitter chain of the VAA.
+    pub chain_id: ChainId,
+    /// The id of the NTT manager message in the VAA.
+    pub message_id: [u8; 32],
+    /// The length of the additional payload of the transfer, which the
+    /// message account is allocated with room for.
+    pub additional_payload_len: u16,
+}
+
+pub fn receive_message(ctx: Context<ReceiveMessage>, args: ReceiveMessageArgs) -> Result<()> {
+    let message = VaaAccount::load(&ctx.accounts.vaa)?
+        .transceiver_message::<NativeTokenTransfer<Payload>>(
+            args.chain_id,
+            ctx.accounts.peer.address,
+        )?;
+
+    if message.ntt_manager_payload.id != args.message_id {
+        return Err(ErrorCode::ConstraintSeeds.into());
+    }
+
+    if message
+        .ntt_manager_payload
+        .payload
+        .additional_payload
+        .bytes()
+        .len()
+        != usize::from(args.additional_payload_len)
+    {
+        return Err(ErrorCode::ConstraintSpace.into());
+    }
+
+    // check that the message is targeted to this chain
+    if message.ntt_manager_payload.payload.to_chain != ctx.accounts.config.chain_id {
+        return Err(NTTError::InvalidChainId.into());
+    }
+
     ctx.accounts
         .transceiver_message
         .set_inner(ValidatedTransceiverMessage {
-            from_chain: ChainId { id: chain_id },
-            message,
+            from_chain: args.chain_id,
+            message: message.message_data,
         });
 
     Ok(())
@@ -1,12 +1,16 @@
-use crate::wormhole::accounts::*;
+use crate::{peer::TransceiverPeer, wormhole::accounts::*};
 use anchor_lang::prelude::*;
 use example_native_token_transfers::{
     config::{anchor_reexports::*, *},
     error::NTTError,
     instructions::OUTBOX_ITEM_SIGNER_SEED,
     program::ExampleNativeTokenTransfers,
-    queue::outbox::OutboxItem,
+    queue::{
+        inbox::InboxRateLimit,
+        outbox::{OutboxItem, OutboxRateLimit},
+    },
     registered_transceiver::RegisteredTransceiver,
+    transceivers::accounts::approved_relayer::ApprovedRelayer,
     transfer::Payload,
 };
 use ntt_messages::{
@@ -24,15 +28,49 @@ pub struct ReleaseOutbound<'info> {
     #[account(
         mut,
         constraint = !outbox_item.released.get(transceiver.id)? @ NTTError::MessageAlreadySent,
+        constraint = outbox_item.mint == config.mint @ NTTError::InvalidMint,
     )]
     pub outbox_item: Account<'info, OutboxItem>,
 
     #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+        seeds::program = manager,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            outbox_item.recipient_chain.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = inbox_rate_limit.bump,
+        seeds::program = manager,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [RegisteredTransceiver::SEED_PREFIX, transceiver.transceiver_address.as_ref(), config.mint_seed()],
+        bump = transceiver.bump,
+        seeds::program = manager,
         constraint = transceiver.transceiver_address == crate::ID,
         constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
     )]
     pub transceiver: Account<'info, RegisteredTransceiver>,
 
+    #[account(
+        seeds = [
+            TransceiverPeer::SEED_PREFIX,
+            outbox_item.recipient_chain.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, TransceiverPeer>,
+
     #[account(
         mut,
         seeds = [b"message", outbox_item.key().as_ref()],
@@ -65,22 +103,16 @@ pub struct ReleaseOutbound<'info> {
 
 impl<'info> ReleaseOutbound<'info> {
     pub fn mark_outbox_item_as_released(&self, bump_seed: u8) -> Result<bool> {
-        let result = example_native_token_transfers::cpi::mark_outbox_item_as_released(
-            CpiContext::new_with_signer(
-                self.manager.to_account_info(),
-                example_native_token_transfers::cpi::accounts::MarkOutboxItemAsReleased {
-                    signer: self.outbox_item_signer.to_account_info(),
-                    config: example_native_token_transfers::cpi::accounts::NotPausedConfig {
-                        config: self.config.config.to_account_info(),
-                    },
-                    outbox_item: self.outbox_item.to_account_info(),
-                    transceiver: self.transceiver.to_account_info(),
-                },
-                // signer seeds
-                &[&[OUTBOX_ITEM_SIGNER_SEED, &[bump_seed]]],
-            ),
-        )?;
-        Ok(result.get())
+        mark_outbox_item_as_released(
+            &self.manager,
+            &self.outbox_item_signer,
+            &self.config,
+            &self.outbox_item,
+            &self.outbox_rate_limit,
+            &self.inbox_rate_limit,
+            &self.transceiver,
+            bump_seed,
+        )
     }
 }
 
@@ -104,32 +136,14 @@ pub fn release_outbound(ctx: Context<ReleaseOutbound>, args: ReleaseOutboundArgs
     accs.outbox_item.reload()?;
     assert!(accs.outbox_item.released.get(accs.transceiver.id)?);
 
-    let message: TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>> =
-        TransceiverMessage::new(
-            // TODO: should we just put the ntt id here statically?
-            accs.outbox_item.to_account_info().owner.to_bytes(),
-            accs.outbox_item.recipient_ntt_manager,
-            NttManagerMessage {
-                id: accs.outbox_item.key().to_bytes(),
-                sender: accs.outbox_item.sender.to_bytes(),
-                payload: NativeTokenTransfer {
-                    amount: accs.outbox_item.amount,
-                    source_token: accs.config.mint.to_bytes(),
-                    to: accs.outbox_item.recipient_address,
-                    to_chain: accs.outbox_item.recipient_chain,
-                    additional_payload: Payload {},
-                },
-            },
-            vec![],
-        );
-
     post_message(
         &accs.wormhole,
         accs.payer.to_account_info(),
         accs.wormhole_message.to_account_info(),
         accs.emitter.to_account_info(),
         ctx.bumps.emitter,
-        &message,
+        &transceiver_message(&accs.config, &accs.outbox_item),
+        accs.peer.finality.unwrap_or(accs.config.wormhole_finality),
         &[&[
             b"message",
             accs.outbox_item.key().as_ref(),
@@ -139,3 +153,226 @@ pub fn release_outbound(ctx: Context<ReleaseOutbound>, args: ReleaseOutboundArgs
 
     Ok(())
 }
+
+pub const REUSABLE_MESSAGE_SEED: &[u8] = b"reusable_message";
+
+/// Same as [`ReleaseOutbound`], except that the message is posted into a
+/// reusable account owned by the payer, instead of a fresh one per outbox
+/// item. See [`post_message_unreliable`] for the trade-offs.
+///
+/// SECURITY: the payer can overwrite the message before the guardians observe
+/// it, after which the transfer can neither be released again nor reclaimed.
+/// So only the sender of the transfer, or a relayer approved by the owner of
+/// the manager (see [`ApprovedRelayer`]), may release it this way.
+#[derive(Accounts)]
+#[instruction(args: ReleaseOutboundReusableArgs)]
+pub struct ReleaseOutboundReusable<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub config: NotPausedConfig<'info>,
+
+    #[account(
+        mut,
+        constraint = !outbox_item.released.get(transceiver.id)? @ NTTError::MessageAlreadySent,
+        constraint = outbox_item.mint == config.mint @ NTTError::InvalidMint,
+    )]
+    pub outbox_item: Account<'info, OutboxItem>,
+
+    #[account(
+        mut,
+        seeds = [OutboxRateLimit::SEED_PREFIX, config.mint_seed()],
+        bump,
+        seeds::program = manager,
+    )]
+    pub outbox_rate_limit: Account<'info, OutboxRateLimit>,
+
+    #[account(
+        mut,
+        seeds = [
+            InboxRateLimit::SEED_PREFIX,
+            outbox_item.recipient_chain.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = inbox_rate_limit.bump,
+        seeds::program = manager,
+    )]
+    pub inbox_rate_limit: Account<'info, InboxRateLimit>,
+
+    #[account(
+        seeds = [RegisteredTransceiver::SEED_PREFIX, transceiver.transceiver_address.as_ref(), config.mint_seed()],
+        bump = transceiver.bump,
+        seeds::program = manager,
+        constraint = transceiver.transceiver_address == crate::ID,
+        constraint = config.enabled_transceivers.get(transceiver.id)? @ NTTError::DisabledTransceiver
+    )]
+    pub transceiver: Account<'info, RegisteredTransceiver>,
+
+    #[account(
+        seeds = [
+            TransceiverPeer::SEED_PREFIX,
+            outbox_item.recipient_chain.id.to_be_bytes().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = peer.bump,
+    )]
+    pub peer: Account<'info, TransceiverPeer>,
+
+    #[account(
+        mut,
+        seeds = [
+            REUSABLE_MESSAGE_SEED,
+            payer.key().as_ref(),
+            args.message_index.to_be_bytes().as_ref()
+        ],
+        bump,
+    )]
+    /// CHECK: initialized and (re)written to by wormhole core bridge
+    pub wormhole_message: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"emitter"],
+        bump
+    )]
+    /// CHECK: wormhole uses this as the emitter address
+    pub emitter: UncheckedAccount<'info>,
+
+    pub wormhole: WormholeAccounts<'info>,
+
+    // NOTE: we put `manager` and `outbox_item_signer` at the end so that the generated
+    // IDL does not clash with the baked-in transceiver IDL in the manager
+    pub manager: Program<'info, ExampleNativeTokenTransfers>,
+
+    #[account(
+        seeds = [OUTBOX_ITEM_SIGNER_SEED],
+        bump
+    )]
+    /// CHECK: this PDA is used to sign the CPI into NTT manager program
+    pub outbox_item_signer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            ApprovedRelayer::SEED_PREFIX,
+            payer.key().as_ref(),
+            config.mint_seed(),
+        ],
+        bump = approved_relayer.bump,
+        seeds::program = manager,
+    )]
+    /// Required unless the payer is the sender of the outbox item.
+    pub approved_relayer: Option<Account<'info, ApprovedRelayer>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReleaseOutboundReusableArgs {
+    pub revert_on_delay: bool,
+    /// Selects which of the payer's reusable message accounts to post into.
+    /// Relayers that release several transfers before the guardians observe
+    /// them should rotate through different indices.
+    pub message_index: u16,
+}
+
+pub fn release_outbound_reusable(
+    ctx: Context<ReleaseOutboundReusable>,
+    args: ReleaseOutboundReusableArgs,
+) -> Result<()> {
+    let accs = ctx.accounts;
+
+    if accs.payer.key() != accs.outbox_item.sender && accs.approved_relayer.is_none() {
+        return Err(NTTError::UnapprovedRelayer.into());
+    }
+
+    let released = mark_outbox_item_as_released(
+        &accs.manager,
+        &accs.outbox_item_signer,
+        &accs.config,
+        &accs.outbox_item,
+        &accs.outbox_rate_limit,
+        &accs.inbox_rate_limit,
+        &accs.transceiver,
+        ctx.bumps.outbox_item_signer,
+    )?;
+
+    if !released {
+        if args.revert_on_delay {
+            return Err(NTTError::CantReleaseYet.into());
+        } else {
+            return Ok(());
+        }
+    }
+
+    accs.outbox_item.reload()?;
+    assert!(accs.outbox_item.released.get(accs.transceiver.id)?);
+
+    post_message_unreliable(
+        &accs.wormhole,
+        accs.payer.to_account_info(),
+        accs.wormhole_message.to_account_info(),
+        accs.emitter.to_account_info(),
+        ctx.bumps.emitter,
+        &transceiver_message(&accs.config, &accs.outbox_item),
+        accs.peer.finality.unwrap_or(accs.config.wormhole_finality),
+        &[&[
+            REUSABLE_MESSAGE_SEED,
+            accs.payer.key().as_ref(),
+            args.message_index.to_be_bytes().as_ref(),
+            &[ctx.bumps.wormhole_message],
+        ]],
+    )?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn mark_outbox_item_as_released<'info>(
+    manager: &Program<'info, ExampleNativeTokenTransfers>,
+    outbox_item_signer: &UncheckedAccount<'info>,
+    config: &NotPausedConfig<'info>,
+    outbox_item: &Account<'info, OutboxItem>,
+    outbox_rate_limit: &Account<'info, OutboxRateLimit>,
+    inbox_rate_limit: &Account<'info, InboxRateLimit>,
+    transceiver: &Account<'info, RegisteredTransceiver>,
+    bump_seed: u8,
+) -> Result<bool> {
+    let result = example_native_token_transfers::cpi::mark_outbox_item_as_released(
+        CpiContext::new_with_signer(
+            manager.to_account_info(),
+            example_native_token_transfers::cpi::accounts::MarkOutboxItemAsReleased {
+                signer: outbox_item_signer.to_account_info(),
+                config: example_native_token_transfers::cpi::accounts::NotPausedConfig {
+                    config: config.config.to_account_info(),
+                },
+                outbox_item: outbox_item.to_account_info(),
+                outbox_rate_limit: outbox_rate_limit.to_account_info(),
+                inbox_rate_limit: inbox_rate_limit.to_account_info(),
+                transceiver: transceiver.to_account_info(),
+            },
+            // signer seeds
+            &[&[OUTBOX_ITEM_SIGNER_SEED, &[bump_seed]]],
+        ),
+    )?;
+    Ok(result.get())
+}
+
+fn transceiver_message(
+    config: &NotPausedConfig,
+    outbox_item: &Account<OutboxItem>,
+) -> TransceiverMessage<WormholeTransceiver, NativeTokenTransfer<Payload>> {
+    TransceiverMessage::new(
+        // TODO: should we just put the ntt id here statically?
+        outbox_item.to_account_info().owner.to_bytes(),
+        outbox_item.recipient_ntt_manager,
+        NttManagerMessage {
+            id: outbox_item.key().to_bytes(),
+            sender: outbox_item.sender.to_bytes(),
+            payload: NativeTokenTransfer {
+                amount: outbox_item.amount,
+                source_token: config.mint.to_bytes(),
+                to: outbox_item.recipient_address,
+                to_chain: outbox_item.recipient_chain,
+                additional_payload: Payload::default(),
+            },
+        },
+        vec![],
+    )
+}
@@ -37,7 +37,7 @@ import { SolanaNtt } from "../ts/sdk/index.js";
 
 const solanaRootDir = `${__dirname}/../`;
 
-const VERSION: IdlVersion = "3.0.0";
+const VERSION: IdlVersion = "4.0.0";
 const GUARDIAN_KEY =
   "cfb12303a19cde580bb4dd771639b0d26bc68353645571a8cff516ab2ee113a0";
 const CORE_BRIDGE_ADDRESS = contracts.coreBridge("Mainnet", "Solana");
@@ -74,6 +74,10 @@ const remoteMgr: ChainAddress = {
     encoding.bytes.encode("nttManager".padStart(32, "\0"))
   ),
 };
+const remoteToken: ChainAddress = {
+  chain: "Ethereum",
+  address: new UniversalAddress("FAFA".padStart(64, "0")),
+};
 
 const payerSecretKey = Uint8Array.from(
   JSON.parse(
@@ -266,7 +270,13 @@ describe("example-native-token-transfers", () => {
         await signSendWait(ctx, setXcvrPeerTxs, signer);
 
         // Set manager peer
-        const setPeerTxs = ntt.setPeer(remoteMgr, 18, 1000000n, sender);
+        const setPeerTxs = ntt.setPeer(
+          remoteMgr,
+          18,
+          1000000n,
+          sender,
+          remoteToken
+        );
         await signSendWait(ctx, setPeerTxs, signer);
       } catch (e) {
         console.error("Failed to setup peer: ", e);
@@ -375,7 +385,7 @@ describe("example-native-token-transfers", () => {
               amount: 10000n,
               decimals: 8,
             },
-            sourceToken: new UniversalAddress("FAFA".padStart(64, "0")),
+            sourceToken: remoteToken.address as UniversalAddress,
             recipientAddress: new UniversalAddress(payer.publicKey.toBytes()),
             recipientChain: "Solana",
             additionalPayload: new Uint8Array(),
@@ -444,7 +454,7 @@ describe("example-native-token-transfers", () => {
           { ntt: overrides["Solana"] },
           new SolanaAddress(payer.publicKey.toBase58())
         );
-        expect(version).toBe("3.0.0");
+        expect(version).toBe("4.0.0");
       });
 
       test("It initializes using `emitterAccount` as transceiver address", async function () {
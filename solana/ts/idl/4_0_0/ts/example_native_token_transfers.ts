@@ -0,0 +1,13975 @@
+export type ExampleNativeTokenTransfers = {
+  "version": "4.0.0",
+  "name": "example_native_token_transfers",
+  "instructions": [
+    {
+      "name": "initialize",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "deployer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMode",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "singleTokenConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "mode. Deployments predating [`TokenMode`] only have this to go by.",
+            "Required in multi-token mode."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "In any case, this function is used to set the Config and initialize the program so we",
+            "assume the caller of this function will have total control over the program.",
+            "",
+            "TODO: Using `UncheckedAccount` here leads to \"Access violation in stack frame ...\".",
+            "Could refactor code to use `Box<_>` to reduce stack size."
+          ]
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The custody account that holds tokens in locking mode and temporarily",
+            "holds tokens in burning mode.",
+            "function if  the token account has already been created."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "associated token account for the given mint."
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeLut",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "entries",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormhole",
+              "accounts": [
+                {
+                  "name": "bridge",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "feeCollector",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "sequence",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "program",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "systemProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "clock",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "rent",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lutAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lut",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lutProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "recentSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "extendLutWithPeer",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "authority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lut",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lutAddress",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lutProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverPeer",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "extendLutWithTransceiver",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "authority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lut",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lutAddress",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lutProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "extendLutWithTransferHook",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "authority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lut",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lutAddress",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lutProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "deactivateLut",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lut",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lutAddress",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "`authority`."
+          ]
+        },
+        {
+          "name": "lutProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeLut",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lut",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lutAddress",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "`authority`."
+          ]
+        },
+        {
+          "name": "lutProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "version",
+      "accounts": [],
+      "args": [],
+      "returns": "string"
+    },
+    {
+      "name": "transferBurn",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "which may not be initialized. See [`CustodyLedger::record_if_initialized`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferBurnDirect",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "fromAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLockDirect",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "fromAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "which may not be initialized. See [`CustodyLedger::record_if_initialized`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "redeem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "`Account<T>` and `owner` constraints are mutually-exclusive"
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: This account is content-addressed (PDA seeded by the message hash).",
+            "This is because in a multi-transceiver configuration, the different",
+            "transceivers \"vote\" on messages (by delivering them). By making the inbox",
+            "items content-addressed, we can ensure that disagreeing votes don't",
+            "interfere with each other.",
+            "On the first call to [`redeem()`], [`InboxItem`] will be allocated and initialized with",
+            "default values.",
+            "On subsequent calls, we want to modify the `InboxItem` by \"voting\" on it. Therefore the",
+            "program should not fail which would occur when using the `init` constraint.",
+            "The [`InboxItem::init`] field is used to guard against malicious or accidental modification",
+            "InboxItem fields that should remain constant."
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RedeemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundMint",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "inboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Refilled (backflow) when a delayed transfer consumes its deferred",
+                "capacity. See [`InboxItem::try_release`]."
+              ]
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "registeredReceiver",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "This account only exists if the recipient is a registered receiver, in",
+                "which case the transfer has to be released with one of the `_and_call`",
+                "instructions. See [`crate::registered_receiver`]."
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "CHECK The seeds constraint ensures that this is the correct address"
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundUnlock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "inboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Refilled (backflow) when a delayed transfer consumes its deferred",
+                "capacity. See [`InboxItem::try_release`]."
+              ]
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "registeredReceiver",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "This account only exists if the recipient is a registered receiver, in",
+                "which case the transfer has to be released with one of the `_and_call`",
+                "instructions. See [`crate::registered_receiver`]."
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "CHECK The seeds constraint ensures that this is the correct address"
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "which may not be initialized. See [`CustodyLedger::record_if_initialized`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundAndCallMint",
+      "accounts": [
+        {
+          "name": "call",
+          "accounts": [
+            {
+              "name": "common",
+              "accounts": [
+                {
+                  "name": "payer",
+                  "isMut": true,
+                  "isSigner": true
+                },
+                {
+                  "name": "config",
+                  "accounts": [
+                    {
+                      "name": "config",
+                      "isMut": false,
+                      "isSigner": false
+                    }
+                  ]
+                },
+                {
+                  "name": "inboxItem",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "inboxRateLimit",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "outboxRateLimit",
+                  "isMut": true,
+                  "isSigner": false,
+                  "docs": [
+                    "Refilled (backflow) when a delayed transfer consumes its deferred",
+                    "capacity. See [`InboxItem::try_release`]."
+                  ]
+                },
+                {
+                  "name": "recipient",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "registeredReceiver",
+                  "isMut": false,
+                  "isSigner": false,
+                  "docs": [
+                    "This account only exists if the recipient is a registered receiver, in",
+                    "which case the transfer has to be released with one of the `_and_call`",
+                    "instructions. See [`crate::registered_receiver`]."
+                  ]
+                },
+                {
+                  "name": "tokenAuthority",
+                  "isMut": false,
+                  "isSigner": false,
+                  "docs": [
+                    "CHECK The seeds constraint ensures that this is the correct address"
+                  ]
+                },
+                {
+                  "name": "mint",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "tokenProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "custody",
+                  "isMut": true,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "receiverProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "receiverCaller",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundAndCallUnlock",
+      "accounts": [
+        {
+          "name": "call",
+          "accounts": [
+            {
+              "name": "common",
+              "accounts": [
+                {
+                  "name": "payer",
+                  "isMut": true,
+                  "isSigner": true
+                },
+                {
+                  "name": "config",
+                  "accounts": [
+                    {
+                      "name": "config",
+                      "isMut": false,
+                      "isSigner": false
+                    }
+                  ]
+                },
+                {
+                  "name": "inboxItem",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "inboxRateLimit",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "outboxRateLimit",
+                  "isMut": true,
+                  "isSigner": false,
+                  "docs": [
+                    "Refilled (backflow) when a delayed transfer consumes its deferred",
+                    "capacity. See [`InboxItem::try_release`]."
+                  ]
+                },
+                {
+                  "name": "recipient",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "registeredReceiver",
+                  "isMut": false,
+                  "isSigner": false,
+                  "docs": [
+                    "This account only exists if the recipient is a registered receiver, in",
+                    "which case the transfer has to be released with one of the `_and_call`",
+                    "instructions. See [`crate::registered_receiver`]."
+                  ]
+                },
+                {
+                  "name": "tokenAuthority",
+                  "isMut": false,
+                  "isSigner": false,
+                  "docs": [
+                    "CHECK The seeds constraint ensures that this is the correct address"
+                  ]
+                },
+                {
+                  "name": "mint",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "tokenProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "custody",
+                  "isMut": true,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "receiverProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "receiverCaller",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "which may not be initialized. See [`CustodyLedger::record_if_initialized`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferOwnershipOneStepUnchecked",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelOwnershipTransfer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "renounceOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RenounceOwnershipArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "pause",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "upgrade",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buffer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "`upgrade_lock`."
+          ]
+        },
+        {
+          "name": "spill",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateConfig",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`Config`]. Checked by [`legacy::read`]."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateOutboxRateLimit",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateInboxRateLimit",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "migratePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "migrateWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "migrateInboxItem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MigrateInboxItemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateOutboxItem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeCustodyLedger",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "custody",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "checkCustodyInvariant",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CheckCustodyInvariantArgs"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "CustodyInvariant"
+      }
+    },
+    {
+      "name": "setPeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setSettlementDelays",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetSettlementDelaysArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "registerTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "used here that wraps the Transceiver account type."
+          ]
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerReceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "`receive_ntt_transfer` handler. See [`crate::registered_receiver`]."
+          ]
+        },
+        {
+          "name": "registeredReceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "deregisterReceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "registeredReceiver",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setOutboundLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMaxQueuedOutbound",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetMaxQueuedOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setInboundLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOutboundLimitRamp",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundLimitRampArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setInboundLimitRamp",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundLimitRampArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelOutboundLimitRamp",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelInboundLimitRamp",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CancelInboundLimitRampArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOwnerSet",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOwnerSetArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAdminAction",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "action",
+          "type": {
+            "defined": "AdminAction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelAdminAction",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeAdminAction",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "action",
+          "type": {
+            "defined": "AdminAction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setTimelockDelay",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTimelockDelayArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMaxQueueAge",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetMaxQueueAgeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCapacityAwareRelease",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setOperator",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "operator",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "expediteInboxItem",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner or the operator."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setInboxItemFrozen",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner or the operator."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "frozen",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "reclaimOutboxItemMint",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sender",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "outbox item is returned to this account."
+              ]
+            },
+            {
+              "name": "senderTokenAccount",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The tokens are always refunded to the sender's associated token account,",
+                "so that anyone can crank the reclaim on behalf of the sender."
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "CHECK The seeds constraint ensures that this is the correct address"
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "reclaimOutboxItemUnlock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sender",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "outbox item is returned to this account."
+              ]
+            },
+            {
+              "name": "senderTokenAccount",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The tokens are always refunded to the sender's associated token account,",
+                "so that anyone can crank the reclaim on behalf of the sender."
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "CHECK The seeds constraint ensures that this is the correct address"
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "which may not be initialized. See [`CustodyLedger::record_if_initialized`]."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "markOutboxItemAsReleased",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Refilled (backflow) when a queued transfer consumes its deferred",
+            "capacity. See [`OutboxItem::try_release`]."
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "bool"
+    },
+    {
+      "name": "getOutboundCapacity",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "u64"
+    },
+    {
+      "name": "getInboundCapacity",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "GetInboundCapacityArgs"
+          }
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "getOutboxItemStatus",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItem",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "OutboxItemStatus"
+      }
+    },
+    {
+      "name": "getInboxItemStatus",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "InboxItemStatus"
+      }
+    },
+    {
+      "name": "getTransferHookAccounts",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "GetTransferHookAccountsArgs"
+          }
+        }
+      ],
+      "returns": {
+        "vec": {
+          "defined": "TransferHookAccount"
+        }
+      }
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setWormholePeerFinality",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerFinalityArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setWormholeFinality",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetWormholeFinalityArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(the core bridge) and the layout are checked by [`VaaAccount::load`],",
+            "and the emitter by [`VaaAccount::transceiver_message`]."
+          ]
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReceiveMessageArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveAndRedeemWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(the core bridge) and the layout are checked by [`VaaAccount::load`],",
+            "and the emitter by [`VaaAccount::transceiver_message`]."
+          ]
+        },
+        {
+          "name": "transceiverPeer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: the same inbox item as [`crate::instructions::Redeem::inbox_item`],",
+            "so votes recorded by either instruction are counted together."
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: checked against the recipient of the inbox item before releasing,",
+            "as the inbox item may be initialised by this very instruction."
+          ]
+        },
+        {
+          "name": "registeredReceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "releasing. See [`crate::instructions::ReleaseInbound::registered_receiver`]."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "CHECK The seeds constraint ensures that this is the correct address"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "which may not be initialized. See [`CustodyLedger::record_if_initialized`]."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReceiveAndRedeemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeTransceiverInfo",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(the core bridge) and the layout are checked by [`VaaAccount::load`]."
+          ]
+        },
+        {
+          "name": "pendingPeer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "receiveWormholeTransceiverRegistration",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(the core bridge) and the layout are checked by [`VaaAccount::load`]."
+          ]
+        },
+        {
+          "name": "pendingPeer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptWormholePendingPeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "pendingPeer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverPeer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AcceptPendingPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Refilled (backflow) when a queued transfer consumes its deferred",
+            "capacity. See [`OutboxItem::try_release`]."
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveWormholeRelayer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "approvedRelayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ApproveRelayerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "revokeWormholeRelayer",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "approvedRelayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "relayer",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseWormholeOutboundReusable",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Refilled (backflow) when a queued transfer consumes its deferred",
+            "capacity. See [`OutboxItem::try_release`]."
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "approvedRelayer",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required unless the payer is the sender of the outbox item."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundReusableArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sendWormholePauseSignal",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverPeer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SendPauseSignalArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholePauseSignal",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(the core bridge) and the layout are checked by [`VaaAccount::load`],",
+            "and the emitter by [`VaaAccount::transceiver_message`]."
+          ]
+        },
+        {
+          "name": "transceiverPeer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReceivePauseSignalArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Pause the program. This is useful for upgrades and other maintenance."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "timelockDelay",
+            "docs": [
+              "Minimum number of seconds between proposing an admin action and",
+              "executing it. Zero disables the timelock.",
+              "See [`crate::timelock`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "ownerSet",
+            "docs": [
+              "Optional m-of-n owner set. When empty, [`Config::owner`] alone",
+              "authorizes owner instructions."
+            ],
+            "type": {
+              "defined": "OwnerSet"
+            }
+          },
+          {
+            "name": "maxQueueAge",
+            "docs": [
+              "Number of seconds after its release timestamp after which an",
+              "unreleased outbound transfer can be reclaimed by the sender.",
+              "Zero means outbound transfers never expire."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "capacityAwareRelease",
+            "docs": [
+              "When enabled, releasing a queued transfer (in either direction) also",
+              "consumes rate limit capacity, and the transfer is delayed again if there",
+              "isn't enough. Like an immediate transfer, it then refills the rate limit",
+              "of the opposite direction (backflow). This bounds the total throughput",
+              "in any 24 hour window, at the cost of transfers larger than the limit",
+              "never being released."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "operator",
+            "docs": [
+              "Optional operator that, in addition to the owner, can expedite or",
+              "freeze queued inbound transfers.",
+              "See [`crate::instructions::expedite_inbox_item`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mintExtensions",
+            "docs": [
+              "The token-2022 extensions of the mint, detected at initialize.",
+              "See [`crate::mint_extensions`]."
+            ],
+            "type": {
+              "defined": "MintExtensions"
+            }
+          },
+          {
+            "name": "layoutVersion",
+            "docs": [
+              "The version of this account's layout. Set to [`Config::LAYOUT_VERSION`]",
+              "at initialize (or by [`crate::instructions::migrate_config`]), and",
+              "checked when unpausing after an upgrade.",
+              "See [`crate::instructions::upgrade`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "multiToken",
+            "docs": [
+              "Whether this config is one of many managed by this program. The",
+              "accounts of a multi-token config (including the config itself) are",
+              "keyed by its mint. See [`Config::mint_seed`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "wormholeFinality",
+            "docs": [
+              "The finality of Wormhole messages sent to peers that don't override it",
+              "(see [`crate::transceivers::accounts::peer::TransceiverPeer::finality`]),",
+              "and of messages that aren't sent to a specific chain (e.g. broadcasts).",
+              "Initialized to the network's",
+              "[`crate::transceivers::wormhole::accounts::DEFAULT_FINALITY`]."
+            ],
+            "type": {
+              "defined": "WormholeFinality"
+            }
+          },
+          {
+            "name": "upgradeAuthorityEscrowed",
+            "docs": [
+              "Whether the `upgrade_lock` only holds the program's upgrade authority",
+              "for the duration of an ownership transfer, after which it's handed to",
+              "the new owner (or back to the owner). Otherwise, an authority held by",
+              "the `upgrade_lock` stays there, see [`crate::instructions::upgrade`]."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tokenMode",
+      "docs": [
+        "Whether the program manages a single token or multiple tokens (see",
+        "[`mint_seed`]). Set by the first [`crate::instructions::initialize`] and",
+        "checked by later ones, so that the two modes can't be mixed in one program."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "initialized",
+            "docs": [
+              "Whether [`TokenMode::multi_token`] has been set. False only while the",
+              "account is being created by the first initialize."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "multiToken",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "custodyLedger",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "totalOutbound",
+            "docs": [
+              "Total amount locked into custody by outbound transfers."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "totalInbound",
+            "docs": [
+              "Total amount unlocked from custody."
+            ],
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LUT",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nttManagerPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "tokenAddress",
+            "docs": [
+              "The address of the token on the peer chain. Inbound transfers have to",
+              "carry this as their `source_token`."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "settlementDelays",
+            "docs": [
+              "Minimum delay before inbound transfers from this peer can be released,",
+              "regardless of the rate limit. See [`NttManagerPeer::settlement_delay`]."
+            ],
+            "type": {
+              "vec": {
+                "defined": "SettlementDelayTier"
+              }
+            }
+          },
+          {
+            "name": "outboundPaused",
+            "docs": [
+              "Set by the peer with a [`ntt_messages::pause::PauseSignal`]. Outbound",
+              "transfers to the peer are rejected while this is set."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "init",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "votes",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "releaseStatus",
+            "type": {
+              "defined": "ReleaseStatus"
+            }
+          },
+          {
+            "name": "fromChain",
+            "docs": [
+              "The chain the transfer was sent from."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "deferredCapacity",
+            "docs": [
+              "The inbound rate limit capacity that the transfer did not consume when",
+              "it was delayed. Zero once the transfer has been released.",
+              "See [`crate::config::Config::capacity_aware_release`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "frozen",
+            "docs": [
+              "Frozen transfers can't be released until they are unfrozen.",
+              "See [`crate::instructions::set_inbox_item_frozen`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The sender on the source chain. Passed to registered receivers.",
+              "See [`crate::registered_receiver`]."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the transferred token. Checked against the config by",
+              "instructions that take the inbox item."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "additionalPayload",
+            "docs": [
+              "The additional payload of the transfer. Passed to registered receivers.",
+              "NOTE: the inbox item is allocated with room for it, see",
+              "[`InboxItem::space`]."
+            ],
+            "type": {
+              "defined": "RawPayload"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxRateLimit",
+      "docs": [
+        "Inbound rate limit per chain.",
+        "SECURITY: must check the PDA (since there are multiple PDAs, namely one for each chain.)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "deferredCapacity",
+            "docs": [
+              "The outbound rate limit capacity that the transfer did not consume when",
+              "it was queued. Zero once the transfer has been released.",
+              "Like the rate limit, this is in the mint's native (untrimmed) units,",
+              "and it's also what the transfer adds to [`OutboxRateLimit::queued_amount`].",
+              "See [`crate::config::Config::capacity_aware_release`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the transferred token. Checked against the config by",
+              "instructions that take the outbox item, as it is not keyed by the mint."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxRateLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          },
+          {
+            "name": "queuedAmount",
+            "docs": [
+              "The total amount of the outbound transfers that are queued, i.e. that",
+              "were delayed by the rate limit and haven't been released (or",
+              "reclaimed) yet. Their tokens have already left the sender.",
+              "NOTE: like the rate limit, this is in the mint's native (untrimmed)",
+              "units, which (unlike trimmed amounts) don't depend on the recipient",
+              "chain."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxQueuedAmount",
+            "docs": [
+              "The maximum of [`OutboxRateLimit::queued_amount`], in the same units.",
+              "Transfers that would be queued beyond it are rejected. `None` means no",
+              "cap."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredReceiver",
+      "docs": [
+        "Stored in a PDA seeded by the receiver's authority, since that's what",
+        "inbound transfers name as their recipient."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "program",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingAdminAction",
+      "docs": [
+        "A proposed admin action. Stored in a PDA seeded by the hash of the action."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": "AdminAction"
+            }
+          },
+          {
+            "name": "earliestExecution",
+            "docs": [
+              "The timestamp from which the action may be executed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the config the action was proposed for. See",
+              "[`crate::config::mint_seed`]."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "approvedRelayer",
+      "docs": [
+        "A relayer that the owner allows to release other senders' outbound",
+        "transfers into its reusable message accounts. Stored in a PDA seeded by the",
+        "relayer (and the mint in multi-token mode).",
+        "",
+        "Reusable message accounts can be overwritten by the relayer before the",
+        "guardians observe them, so releasing into one means trusting the relayer",
+        "not to lose the release.",
+        "See [`crate::transceivers::wormhole::instructions::release_outbound_reusable`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "finality",
+            "docs": [
+              "The finality of messages sent to this chain. When not set,",
+              "[`crate::config::Config::wormhole_finality`] is used."
+            ],
+            "type": {
+              "option": {
+                "defined": "WormholeFinality"
+              }
+            }
+          },
+          {
+            "name": "pauseSignalSequence",
+            "docs": [
+              "The sequence of the last pause signal received from this peer.",
+              "Sequences are per emitter, so this is reset when the address changes."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingPeer",
+      "docs": [
+        "A peer transceiver, as claimed by its own broadcasts. Stored in a PDA seeded",
+        "by the chain id and the address of the transceiver (the emitter of the",
+        "broadcasts).",
+        "",
+        "Anyone can post broadcasts from any address, so nothing in here is trusted",
+        "until the owner accepts it with",
+        "[`crate::transceivers::wormhole::instructions::accept_pending_peer`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "transceiverAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "info",
+            "docs": [
+              "Set by a `WormholeTransceiverInfo` broadcast."
+            ],
+            "type": {
+              "option": {
+                "defined": "PendingPeerInfo"
+              }
+            }
+          },
+          {
+            "name": "registered",
+            "docs": [
+              "Whether the peer has broadcast a `WormholeTransceiverRegistration` of",
+              "this transceiver."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OwnerSet",
+      "docs": [
+        "A set of owner keys, any `threshold` of which can authorize owner",
+        "instructions together."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "keys",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelInboundLimitRampArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RenounceOwnershipArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "confirmation",
+            "docs": [
+              "Must be [`RENOUNCE_OWNERSHIP_CONFIRMATION`]."
+            ],
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInboundLimitRampArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "duration",
+            "docs": [
+              "The duration of the ramp, in seconds."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetMaxQueueAgeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxQueueAge",
+            "docs": [
+              "Seconds after the release timestamp after which unreleased outbound",
+              "transfers can be reclaimed. Zero disables expiry."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetMaxQueuedOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxQueuedAmount",
+            "docs": [
+              "In the mint's native (untrimmed) units, like the rate limits.",
+              "`None` removes the cap."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundLimitRampArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "duration",
+            "docs": [
+              "The duration of the ramp, in seconds."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOwnerSetArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "keys",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "tokenDecimals",
+            "docs": [
+              "The token decimals on the peer chain."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "tokenAddress",
+            "docs": [
+              "The token address on the peer chain."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetSettlementDelaysArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "tiers",
+            "type": {
+              "vec": {
+                "defined": "SettlementDelayTier"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTimelockDelayArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "delay",
+            "docs": [
+              "The new delay in seconds. Zero disables the timelock."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CheckCustodyInvariantArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "autoPause",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CustodyInvariant",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "custody",
+            "docs": [
+              "The balance of the custody account."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "recorded",
+            "docs": [
+              "The balance recorded by the custody ledger."
+            ],
+            "type": "i128"
+          },
+          {
+            "name": "pendingInbound",
+            "docs": [
+              "The total amount of the inbox items that were passed in."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "discrepancy",
+            "docs": [
+              "`custody` minus the larger of `recorded` and `pending_inbound`.",
+              "Negative when the invariant is broken."
+            ],
+            "type": "i128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "multiToken",
+            "docs": [
+              "Initialize one of many tokens managed by this program, keying all its",
+              "accounts by the mint. See [`crate::config::mint_seed`].",
+              "Single-token deployments can't be converted later, and a program can't",
+              "have configs of both kinds (see [`TokenMode`])."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MigrateInboxItemArgs",
+      "docs": [
+        "The message of the transfer, which the inbox item is keyed by. Provides the",
+        "fields that the legacy layout doesn't have. The message is passed as its",
+        "fields, as clients can't encode the generic message type.",
+        "",
+        "NOTE: earlier versions didn't support additional payloads, so the",
+        "message's additional payload is omitted (see",
+        "[`ntt_messages::ntt::RawPayload`])."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fromChain",
+            "docs": [
+              "The chain the transfer was sent from."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "id",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "sender",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sourceToken",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "toChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "to",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RedeemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "ReleaseInboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "shouldQueue",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GetInboundCapacityArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "GetTransferHookAccountsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxItemStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "releaseStatus",
+            "type": {
+              "defined": "ReleaseStatus"
+            }
+          },
+          {
+            "name": "votes",
+            "docs": [
+              "Bitmap of the transceivers that have attested to the transfer."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "approved",
+            "docs": [
+              "Whether enough enabled transceivers have attested to the transfer to",
+              "meet the threshold (`isMessageApproved` on EVM)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "executed",
+            "docs": [
+              "Whether the transfer has been released to the recipient",
+              "(`isMessageExecuted` on EVM)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "releasable",
+            "docs": [
+              "Whether the release timestamp has been reached, i.e. the transfer can",
+              "be released right now."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "frozen",
+            "docs": [
+              "Whether the transfer has been frozen by the owner or operator."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutboxItemStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "releaseTimestamp",
+            "docs": [
+              "The timestamp after which the transfer may be released."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "releasable",
+            "docs": [
+              "Whether the transfer can be released by the transceivers right now:",
+              "the program is not paused, `release_timestamp` has been reached and,",
+              "under [`Config::capacity_aware_release`], there is enough outbound",
+              "capacity for `deferred_capacity`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "released",
+            "docs": [
+              "Bitmap of the transceivers that have already released the transfer."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "deferredCapacity",
+            "docs": [
+              "The outbound capacity that releasing the transfer consumes under",
+              "[`Config::capacity_aware_release`]. See [`OutboxItem::deferred_capacity`]."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyConfig",
+      "docs": [
+        "The layout of [`crate::config::Config`] before the admin action timelock."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyInboxItem",
+      "docs": [
+        "The layout of [`crate::queue::inbox::InboxItem`] before the source chain",
+        "of the transfer was recorded."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "init",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "votes",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "releaseStatus",
+            "type": {
+              "defined": "ReleaseStatus"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyInboxRateLimit",
+      "docs": [
+        "The layout of [`crate::queue::inbox::InboxRateLimit`] before limit ramps."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "LegacyRateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyNttManagerPeer",
+      "docs": [
+        "The layout of [`crate::peer::NttManagerPeer`] before settlement delays."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyOutboxItem",
+      "docs": [
+        "The layout of [`crate::queue::outbox::OutboxItem`] before deferred",
+        "capacity."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyOutboxRateLimit",
+      "docs": [
+        "The layout of [`crate::queue::outbox::OutboxRateLimit`] before the queued",
+        "amount was tracked."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "LegacyRateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyRateLimitState",
+      "docs": [
+        "The layout of [`RateLimitState`] before limit ramps."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "capacityAtLastTx",
+            "type": "u64"
+          },
+          {
+            "name": "lastTxTimestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyTransceiverPeer",
+      "docs": [
+        "The layout of [`crate::transceivers::accounts::peer::TransceiverPeer`]",
+        "before per-peer finality."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintExtensions",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "extensions",
+            "docs": [
+              "The [`ExtensionType`]s of the mint. Empty for legacy token mints."
+            ],
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "warned",
+            "docs": [
+              "Whether any of the extensions is [`Compatibility::Warned`]."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SettlementDelayTier",
+      "docs": [
+        "Inbound transfers of at least `min_amount` (in local token units) have to",
+        "wait at least `delay` seconds before they can be released."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minAmount",
+            "type": "u64"
+          },
+          {
+            "name": "delay",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseStatus",
+      "docs": [
+        "The status of an InboxItem. This determines whether the tokens are minted/unlocked to the recipient. As",
+        "such, this must be used as a state machine that moves forward in a linear manner. A state",
+        "should never \"move backward\" to a previous state (e.g. should never move from `Released` to",
+        "`ReleaseAfter`)."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NotApproved"
+          },
+          {
+            "name": "ReleaseAfter",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "Released"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LimitRamp",
+      "docs": [
+        "A linear change of the limit from `start_limit` to `end_limit` between",
+        "`start_timestamp` and `end_timestamp`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startLimit",
+            "type": "u64"
+          },
+          {
+            "name": "endLimit",
+            "type": "u64"
+          },
+          {
+            "name": "startTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "endTimestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "docs": [
+              "The maximum capacity of the rate limiter."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capacityAtLastTx",
+            "docs": [
+              "The capacity of the rate limiter at `last_tx_timestamp`.",
+              "The actual current capacity is calculated in `capacity_at`, by",
+              "accounting for the time that has passed since `last_tx_timestamp` and",
+              "the refill rate."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastTxTimestamp",
+            "docs": [
+              "The timestamp of the last transaction that counted towards the current",
+              "capacity. Transactions that exceeded the capacity do not count, they are",
+              "just delayed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "ramp",
+            "docs": [
+              "A scheduled change of the limit, in progress (or not yet applied).",
+              "While it is set, the effective limit is interpolated (see",
+              "[`RateLimitState::limit_at`]) and `limit` holds the limit the ramp",
+              "started from. Once the ramp is over, the next transaction moves its",
+              "end limit into `limit`."
+            ],
+            "type": {
+              "option": {
+                "defined": "LimitRamp"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReceiveNttTransferArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fromChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The sender on the source chain."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount delivered to the receiver's token account."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "additionalPayload",
+            "docs": [
+              "The additional payload of the transfer. Empty if it has none."
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AdminAction",
+      "docs": [
+        "An admin action that is subject to the timelock. The fields mirror the",
+        "arguments (and relevant accounts) of the corresponding instruction, so a",
+        "proposal commits to exactly what is going to be executed."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "TransferOwnership",
+            "fields": [
+              {
+                "name": "newOwner",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "TransferOwnershipOneStepUnchecked",
+            "fields": [
+              {
+                "name": "newOwner",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetPeer",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "address",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "tokenDecimals",
+                "type": "u8"
+              },
+              {
+                "name": "tokenAddress",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            ]
+          },
+          {
+            "name": "RegisterTransceiver",
+            "fields": [
+              {
+                "name": "transceiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundLimit",
+            "fields": [
+              {
+                "name": "limit",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetInboundLimit",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetWormholePeer",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "address",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetWormholePeerFinality",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "finality",
+                "type": {
+                  "option": {
+                    "defined": "WormholeFinality"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetTimelockDelay",
+            "fields": [
+              {
+                "name": "delay",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetOwnerSet",
+            "fields": [
+              {
+                "name": "ownerSet",
+                "type": {
+                  "defined": "OwnerSet"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetMaxQueueAge",
+            "fields": [
+              {
+                "name": "maxQueueAge",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetCapacityAwareRelease",
+            "fields": [
+              {
+                "name": "enabled",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "SetOperator",
+            "fields": [
+              {
+                "name": "operator",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetSettlementDelays",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "tiers",
+                "type": {
+                  "vec": {
+                    "defined": "SettlementDelayTier"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "AcceptPendingPeer",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "transceiverAddress",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "allowMismatch",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "RegisterReceiver",
+            "fields": [
+              {
+                "name": "receiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "DeregisterReceiver",
+            "fields": [
+              {
+                "name": "receiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "Upgrade",
+            "fields": [
+              {
+                "name": "buffer",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RenounceOwnership"
+          },
+          {
+            "name": "SetMaxQueuedOutbound",
+            "fields": [
+              {
+                "name": "maxQueuedAmount",
+                "type": {
+                  "option": "u64"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundLimitRamp",
+            "fields": [
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "duration",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetInboundLimitRamp",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "duration",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "ApproveRelayer",
+            "fields": [
+              {
+                "name": "relayer",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetWormholeFinality",
+            "fields": [
+              {
+                "name": "finality",
+                "type": {
+                  "defined": "WormholeFinality"
+                }
+              }
+            ]
+          },
+          {
+            "name": "InitializeCustodyLedger"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingPeerInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "managerAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "managerMode",
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "tokenAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PostMessageUnreliableData",
+      "docs": [
+        "Instruction data of the core bridge's `PostMessageUnreliable` instruction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "batchId",
+            "type": "u32"
+          },
+          {
+            "name": "payload",
+            "type": "bytes"
+          },
+          {
+            "name": "finality",
+            "type": {
+              "defined": "Finality"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "WormholeFinality",
+      "docs": [
+        "The consistency level that messages are posted with.",
+        "Mirrors [`wormhole::Finality`], which can't be stored in accounts as is."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Confirmed"
+          },
+          {
+            "name": "Finalized"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AcceptPendingPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "transceiverAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "allowMismatch",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ApproveRelayerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "relayer",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerFinalityArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "finality",
+            "docs": [
+              "`None` reverts to the default finality."
+            ],
+            "type": {
+              "option": {
+                "defined": "WormholeFinality"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetWormholeFinalityArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "finality",
+            "type": {
+              "defined": "WormholeFinality"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BroadcastPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReceivePauseSignalArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "docs": [
+              "The emitter chain of the VAA."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SendPauseSignalArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReceiveAndRedeemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "release",
+            "docs": [
+              "Whether to release the transfer once the vote is recorded. The release",
+              "is skipped (without reverting) if the transfer can't be released yet."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The emitter chain of the VAA."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "messageHash",
+            "docs": [
+              "The hash of the NTT manager message in the VAA, which keys the inbox",
+              "item. See [`ntt_messages::ntt_manager::NttManagerMessage::keccak256`]."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "additionalPayloadLen",
+            "docs": [
+              "The length of the additional payload of the transfer, which the inbox",
+              "item is allocated with room for."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReceiveMessageArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "docs": [
+              "The emitter chain of the VAA."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "messageId",
+            "docs": [
+              "The id of the NTT manager message in the VAA."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "additionalPayloadLen",
+            "docs": [
+              "The length of the additional payload of the transfer, which the",
+              "message account is allocated with room for."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundReusableArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          },
+          {
+            "name": "messageIndex",
+            "docs": [
+              "Selects which of the payer's reusable message accounts to post into.",
+              "Relayers that release several transfers before the guardians observe",
+              "them should rotate through different indices."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferHookAccount",
+      "docs": [
+        "An account that has to be passed to the token program for the transfer",
+        "hook to be invoked."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "publicKey"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
+          },
+          {
+            "name": "Burning"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RawPayload",
+      "docs": [
+        "An additional payload of arbitrary bytes, which is passed on as is.",
+        "",
+        "`data` is `None` when the transfer doesn't carry an additional payload (in",
+        "which case it's omitted on the wire, see [`AdditionalPayload`]), as opposed",
+        "to an empty payload, which is written with a zero length."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "data",
+            "type": {
+              "option": "bytes"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TrimmedAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Finality",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Confirmed"
+          },
+          {
+            "name": "Finalized"
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "CantReleaseYet",
+      "msg": "CantReleaseYet"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidPendingOwner",
+      "msg": "InvalidPendingOwner"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidChainId",
+      "msg": "InvalidChainId"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidRecipientAddress",
+      "msg": "InvalidRecipientAddress"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidTransceiverPeer",
+      "msg": "InvalidTransceiverPeer"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidNttManagerPeer",
+      "msg": "InvalidNttManagerPeer"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidRecipientNttManager",
+      "msg": "InvalidRecipientNttManager"
+    },
+    {
+      "code": 6007,
+      "name": "TransferAlreadyRedeemed",
+      "msg": "TransferAlreadyRedeemed"
+    },
+    {
+      "code": 6008,
+      "name": "TransferCannotBeRedeemed",
+      "msg": "TransferCannotBeRedeemed"
+    },
+    {
+      "code": 6009,
+      "name": "TransferNotApproved",
+      "msg": "TransferNotApproved"
+    },
+    {
+      "code": 6010,
+      "name": "MessageAlreadySent",
+      "msg": "MessageAlreadySent"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidMode",
+      "msg": "InvalidMode"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidMintAuthority",
+      "msg": "InvalidMintAuthority"
+    },
+    {
+      "code": 6013,
+      "name": "TransferExceedsRateLimit",
+      "msg": "TransferExceedsRateLimit"
+    },
+    {
+      "code": 6014,
+      "name": "Paused",
+      "msg": "Paused"
+    },
+    {
+      "code": 6015,
+      "name": "DisabledTransceiver",
+      "msg": "DisabledTransceiver"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidDeployer",
+      "msg": "InvalidDeployer"
+    },
+    {
+      "code": 6017,
+      "name": "BadAmountAfterTransfer",
+      "msg": "BadAmountAfterTransfer"
+    },
+    {
+      "code": 6018,
+      "name": "BadAmountAfterBurn",
+      "msg": "BadAmountAfterBurn"
+    },
+    {
+      "code": 6019,
+      "name": "ZeroThreshold",
+      "msg": "ZeroThreshold"
+    },
+    {
+      "code": 6020,
+      "name": "OverflowExponent",
+      "msg": "OverflowExponent"
+    },
+    {
+      "code": 6021,
+      "name": "OverflowScaledAmount",
+      "msg": "OverflowScaledAmount"
+    },
+    {
+      "code": 6022,
+      "name": "BitmapIndexOutOfBounds",
+      "msg": "BitmapIndexOutOfBounds"
+    },
+    {
+      "code": 6023,
+      "name": "NoRegisteredTransceivers",
+      "msg": "NoRegisteredTransceivers"
+    },
+    {
+      "code": 6024,
+      "name": "TimelockDisabled",
+      "msg": "TimelockDisabled"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidTimelockDelay",
+      "msg": "InvalidTimelockDelay"
+    },
+    {
+      "code": 6026,
+      "name": "AdminActionNotProposed",
+      "msg": "AdminActionNotProposed"
+    },
+    {
+      "code": 6027,
+      "name": "AdminActionMismatch",
+      "msg": "AdminActionMismatch"
+    },
+    {
+      "code": 6028,
+      "name": "TimelockNotExpired",
+      "msg": "TimelockNotExpired"
+    },
+    {
+      "code": 6029,
+      "name": "AccountAlreadyMigrated",
+      "msg": "AccountAlreadyMigrated"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidOwner",
+      "msg": "InvalidOwner"
+    },
+    {
+      "code": 6031,
+      "name": "InsufficientOwnerSignatures",
+      "msg": "InsufficientOwnerSignatures"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidOwnerSet",
+      "msg": "InvalidOwnerSet"
+    },
+    {
+      "code": 6033,
+      "name": "OutboxItemNotExpired",
+      "msg": "OutboxItemNotExpired"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidMaxQueueAge",
+      "msg": "InvalidMaxQueueAge"
+    },
+    {
+      "code": 6035,
+      "name": "InboxItemFrozen",
+      "msg": "InboxItemFrozen"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidSettlementDelays",
+      "msg": "InvalidSettlementDelays"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidVaaAccount",
+      "msg": "InvalidVaaAccount"
+    },
+    {
+      "code": 6038,
+      "name": "PendingPeerIncomplete",
+      "msg": "PendingPeerIncomplete"
+    },
+    {
+      "code": 6039,
+      "name": "PendingPeerMismatch",
+      "msg": "PendingPeerMismatch"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidSourceToken",
+      "msg": "InvalidSourceToken"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidFromAuthority",
+      "msg": "InvalidFromAuthority"
+    },
+    {
+      "code": 6042,
+      "name": "ReleaseRequiresCall",
+      "msg": "ReleaseRequiresCall"
+    },
+    {
+      "code": 6043,
+      "name": "ReceiverNotRegistered",
+      "msg": "ReceiverNotRegistered"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidReceiverProgram",
+      "msg": "InvalidReceiverProgram"
+    },
+    {
+      "code": 6045,
+      "name": "NonTransferableMint",
+      "msg": "NonTransferableMint"
+    },
+    {
+      "code": 6046,
+      "name": "ConfidentialTransferMint",
+      "msg": "ConfidentialTransferMint"
+    },
+    {
+      "code": 6047,
+      "name": "PermanentDelegateMint",
+      "msg": "PermanentDelegateMint"
+    },
+    {
+      "code": 6048,
+      "name": "FrozenByDefaultMint",
+      "msg": "FrozenByDefaultMint"
+    },
+    {
+      "code": 6049,
+      "name": "UnsupportedMintExtension",
+      "msg": "UnsupportedMintExtension"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidTransferHookAccounts",
+      "msg": "InvalidTransferHookAccounts"
+    },
+    {
+      "code": 6051,
+      "name": "CurrentLUT",
+      "msg": "CurrentLUT"
+    },
+    {
+      "code": 6052,
+      "name": "NotPaused",
+      "msg": "NotPaused"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidUpgradeAuthority",
+      "msg": "InvalidUpgradeAuthority"
+    },
+    {
+      "code": 6054,
+      "name": "ConfigLayoutMismatch",
+      "msg": "ConfigLayoutMismatch"
+    },
+    {
+      "code": 6055,
+      "name": "NoPendingOwner",
+      "msg": "NoPendingOwner"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidRenounceConfirmation",
+      "msg": "InvalidRenounceConfirmation"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidMint",
+      "msg": "InvalidMint"
+    },
+    {
+      "code": 6058,
+      "name": "MultiTokenConfig",
+      "msg": "MultiTokenConfig"
+    },
+    {
+      "code": 6059,
+      "name": "PeerOutboundPaused",
+      "msg": "PeerOutboundPaused"
+    },
+    {
+      "code": 6060,
+      "name": "StalePauseSignal",
+      "msg": "StalePauseSignal"
+    },
+    {
+      "code": 6061,
+      "name": "DuplicateInboxItem",
+      "msg": "DuplicateInboxItem"
+    },
+    {
+      "code": 6062,
+      "name": "QueuedAmountExceedsCap",
+      "msg": "QueuedAmountExceedsCap"
+    },
+    {
+      "code": 6063,
+      "name": "InvalidRampDuration",
+      "msg": "InvalidRampDuration"
+    },
+    {
+      "code": 6064,
+      "name": "UnapprovedRelayer",
+      "msg": "UnapprovedRelayer"
+    },
+    {
+      "code": 6065,
+      "name": "MixedTokenModes",
+      "msg": "MixedTokenModes"
+    },
+    {
+      "code": 6066,
+      "name": "TransferFeeMint",
+      "msg": "TransferFeeMint"
+    }
+  ]
+}
+export const IDL: ExampleNativeTokenTransfers = {
+  "version": "4.0.0",
+  "name": "example_native_token_transfers",
+  "instructions": [
+    {
+      "name": "initialize",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "deployer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMode",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "singleTokenConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "mode. Deployments predating [`TokenMode`] only have this to go by.",
+            "Required in multi-token mode."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "In any case, this function is used to set the Config and initialize the program so we",
+            "assume the caller of this function will have total control over the program.",
+            "",
+            "TODO: Using `UncheckedAccount` here leads to \"Access violation in stack frame ...\".",
+            "Could refactor code to use `Box<_>` to reduce stack size."
+          ]
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The custody account that holds tokens in locking mode and temporarily",
+            "holds tokens in burning mode.",
+            "function if  the token account has already been created."
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "associated token account for the given mint."
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitializeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initializeLut",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "entries",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormhole",
+              "accounts": [
+                {
+                  "name": "bridge",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "feeCollector",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "sequence",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "program",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "systemProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "clock",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "rent",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            }
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lutAddress",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lut",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lutProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "recentSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "extendLutWithPeer",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "authority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lut",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lutAddress",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lutProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverPeer",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "extendLutWithTransceiver",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "authority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lut",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lutAddress",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lutProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "extendLutWithTransferHook",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "authority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lut",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "lutAddress",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lutProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "deactivateLut",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lut",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lutAddress",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "`authority`."
+          ]
+        },
+        {
+          "name": "lutProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeLut",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lut",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lutAddress",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "`authority`."
+          ]
+        },
+        {
+          "name": "lutProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "version",
+      "accounts": [],
+      "args": [],
+      "returns": "string"
+    },
+    {
+      "name": "transferBurn",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "See [`crate::SESSION_AUTHORITY_SEED`] for an explanation of the flow."
+          ]
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "which may not be initialized. See [`CustodyLedger::record_if_initialized`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferBurnDirect",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "fromAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferLockDirect",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "from",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "account can spend these tokens."
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Tokens are always transferred to the custody account first regardless of",
+                "the mode.",
+                "For an explanation, see the note in [`transfer_burn`]."
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "fromAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "which may not be initialized. See [`CustodyLedger::record_if_initialized`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "TransferArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "redeem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "`Account<T>` and `owner` constraints are mutually-exclusive"
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: This account is content-addressed (PDA seeded by the message hash).",
+            "This is because in a multi-transceiver configuration, the different",
+            "transceivers \"vote\" on messages (by delivering them). By making the inbox",
+            "items content-addressed, we can ensure that disagreeing votes don't",
+            "interfere with each other.",
+            "On the first call to [`redeem()`], [`InboxItem`] will be allocated and initialized with",
+            "default values.",
+            "On subsequent calls, we want to modify the `InboxItem` by \"voting\" on it. Therefore the",
+            "program should not fail which would occur when using the `init` constraint.",
+            "The [`InboxItem::init`] field is used to guard against malicious or accidental modification",
+            "InboxItem fields that should remain constant."
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RedeemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundMint",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "inboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Refilled (backflow) when a delayed transfer consumes its deferred",
+                "capacity. See [`InboxItem::try_release`]."
+              ]
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "registeredReceiver",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "This account only exists if the recipient is a registered receiver, in",
+                "which case the transfer has to be released with one of the `_and_call`",
+                "instructions. See [`crate::registered_receiver`]."
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "CHECK The seeds constraint ensures that this is the correct address"
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundUnlock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "inboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "inboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Refilled (backflow) when a delayed transfer consumes its deferred",
+                "capacity. See [`InboxItem::try_release`]."
+              ]
+            },
+            {
+              "name": "recipient",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "registeredReceiver",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "This account only exists if the recipient is a registered receiver, in",
+                "which case the transfer has to be released with one of the `_and_call`",
+                "instructions. See [`crate::registered_receiver`]."
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "CHECK The seeds constraint ensures that this is the correct address"
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "which may not be initialized. See [`CustodyLedger::record_if_initialized`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundAndCallMint",
+      "accounts": [
+        {
+          "name": "call",
+          "accounts": [
+            {
+              "name": "common",
+              "accounts": [
+                {
+                  "name": "payer",
+                  "isMut": true,
+                  "isSigner": true
+                },
+                {
+                  "name": "config",
+                  "accounts": [
+                    {
+                      "name": "config",
+                      "isMut": false,
+                      "isSigner": false
+                    }
+                  ]
+                },
+                {
+                  "name": "inboxItem",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "inboxRateLimit",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "outboxRateLimit",
+                  "isMut": true,
+                  "isSigner": false,
+                  "docs": [
+                    "Refilled (backflow) when a delayed transfer consumes its deferred",
+                    "capacity. See [`InboxItem::try_release`]."
+                  ]
+                },
+                {
+                  "name": "recipient",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "registeredReceiver",
+                  "isMut": false,
+                  "isSigner": false,
+                  "docs": [
+                    "This account only exists if the recipient is a registered receiver, in",
+                    "which case the transfer has to be released with one of the `_and_call`",
+                    "instructions. See [`crate::registered_receiver`]."
+                  ]
+                },
+                {
+                  "name": "tokenAuthority",
+                  "isMut": false,
+                  "isSigner": false,
+                  "docs": [
+                    "CHECK The seeds constraint ensures that this is the correct address"
+                  ]
+                },
+                {
+                  "name": "mint",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "tokenProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "custody",
+                  "isMut": true,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "receiverProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "receiverCaller",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseInboundAndCallUnlock",
+      "accounts": [
+        {
+          "name": "call",
+          "accounts": [
+            {
+              "name": "common",
+              "accounts": [
+                {
+                  "name": "payer",
+                  "isMut": true,
+                  "isSigner": true
+                },
+                {
+                  "name": "config",
+                  "accounts": [
+                    {
+                      "name": "config",
+                      "isMut": false,
+                      "isSigner": false
+                    }
+                  ]
+                },
+                {
+                  "name": "inboxItem",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "inboxRateLimit",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "outboxRateLimit",
+                  "isMut": true,
+                  "isSigner": false,
+                  "docs": [
+                    "Refilled (backflow) when a delayed transfer consumes its deferred",
+                    "capacity. See [`InboxItem::try_release`]."
+                  ]
+                },
+                {
+                  "name": "recipient",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "registeredReceiver",
+                  "isMut": false,
+                  "isSigner": false,
+                  "docs": [
+                    "This account only exists if the recipient is a registered receiver, in",
+                    "which case the transfer has to be released with one of the `_and_call`",
+                    "instructions. See [`crate::registered_receiver`]."
+                  ]
+                },
+                {
+                  "name": "tokenAuthority",
+                  "isMut": false,
+                  "isSigner": false,
+                  "docs": [
+                    "CHECK The seeds constraint ensures that this is the correct address"
+                  ]
+                },
+                {
+                  "name": "mint",
+                  "isMut": true,
+                  "isSigner": false
+                },
+                {
+                  "name": "tokenProgram",
+                  "isMut": false,
+                  "isSigner": false
+                },
+                {
+                  "name": "custody",
+                  "isMut": true,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "receiverProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "receiverCaller",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "which may not be initialized. See [`CustodyLedger::record_if_initialized`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseInboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "transferOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferOwnershipOneStepUnchecked",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelOwnershipTransfer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "renounceOwnership",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "RenounceOwnershipArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "pause",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "upgrade",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "upgradeLock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "program",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buffer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "`upgrade_lock`."
+          ]
+        },
+        {
+          "name": "spill",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bpfLoaderUpgradeableProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateConfig",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "[`Config`]. Checked by [`legacy::read`]."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateOutboxRateLimit",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateInboxRateLimit",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "migratePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "migrateWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "migrateInboxItem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MigrateInboxItemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateOutboxItem",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeCustodyLedger",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "custody",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "checkCustodyInvariant",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CheckCustodyInvariantArgs"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "CustodyInvariant"
+      }
+    },
+    {
+      "name": "setPeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setSettlementDelays",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetSettlementDelaysArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "registerTransceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "used here that wraps the Transceiver account type."
+          ]
+        },
+        {
+          "name": "registeredTransceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "registerReceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "receiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "`receive_ntt_transfer` handler. See [`crate::registered_receiver`]."
+          ]
+        },
+        {
+          "name": "registeredReceiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "deregisterReceiver",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "registeredReceiver",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setOutboundLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMaxQueuedOutbound",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetMaxQueuedOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setInboundLimit",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundLimitArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOutboundLimitRamp",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOutboundLimitRampArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setInboundLimitRamp",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetInboundLimitRampArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelOutboundLimitRamp",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelInboundLimitRamp",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rateLimit",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CancelInboundLimitRampArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setOwnerSet",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetOwnerSetArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAdminAction",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "action",
+          "type": {
+            "defined": "AdminAction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelAdminAction",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeAdminAction",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "action",
+          "type": {
+            "defined": "AdminAction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setTimelockDelay",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTimelockDelayArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMaxQueueAge",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetMaxQueueAgeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCapacityAwareRelease",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setOperator",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "operator",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "expediteInboxItem",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner or the operator."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setInboxItemFrozen",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "The owner or the operator."
+          ]
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "frozen",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "reclaimOutboxItemMint",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sender",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "outbox item is returned to this account."
+              ]
+            },
+            {
+              "name": "senderTokenAccount",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The tokens are always refunded to the sender's associated token account,",
+                "so that anyone can crank the reclaim on behalf of the sender."
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "CHECK The seeds constraint ensures that this is the correct address"
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "reclaimOutboxItemUnlock",
+      "accounts": [
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "config",
+              "accounts": [
+                {
+                  "name": "config",
+                  "isMut": false,
+                  "isSigner": false
+                }
+              ]
+            },
+            {
+              "name": "outboxItem",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sender",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "outbox item is returned to this account."
+              ]
+            },
+            {
+              "name": "senderTokenAccount",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "The tokens are always refunded to the sender's associated token account,",
+                "so that anyone can crank the reclaim on behalf of the sender."
+              ]
+            },
+            {
+              "name": "tokenAuthority",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "CHECK The seeds constraint ensures that this is the correct address"
+              ]
+            },
+            {
+              "name": "mint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "outboxRateLimit",
+              "isMut": true,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "which may not be initialized. See [`CustodyLedger::record_if_initialized`]."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "markOutboxItemAsReleased",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Refilled (backflow) when a queued transfer consumes its deferred",
+            "capacity. See [`OutboxItem::try_release`]."
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "bool"
+    },
+    {
+      "name": "getOutboundCapacity",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": "u64"
+    },
+    {
+      "name": "getInboundCapacity",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "GetInboundCapacityArgs"
+          }
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "getOutboxItemStatus",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItem",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "OutboxItemStatus"
+      }
+    },
+    {
+      "name": "getInboxItemStatus",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "InboxItemStatus"
+      }
+    },
+    {
+      "name": "getTransferHookAccounts",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "source",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "GetTransferHookAccountsArgs"
+          }
+        }
+      ],
+      "returns": {
+        "vec": {
+          "defined": "TransferHookAccount"
+        }
+      }
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setWormholePeerFinality",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerFinalityArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setWormholeFinality",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetWormholeFinalityArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(the core bridge) and the layout are checked by [`VaaAccount::load`],",
+            "and the emitter by [`VaaAccount::transceiver_message`]."
+          ]
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReceiveMessageArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveAndRedeemWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(the core bridge) and the layout are checked by [`VaaAccount::load`],",
+            "and the emitter by [`VaaAccount::transceiver_message`]."
+          ]
+        },
+        {
+          "name": "transceiverPeer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxItem",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: the same inbox item as [`crate::instructions::Redeem::inbox_item`],",
+            "so votes recorded by either instruction are counted together."
+          ]
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "recipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "NOTE: checked against the recipient of the inbox item before releasing,",
+            "as the inbox item may be initialised by this very instruction."
+          ]
+        },
+        {
+          "name": "registeredReceiver",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "releasing. See [`crate::instructions::ReleaseInbound::registered_receiver`]."
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "CHECK The seeds constraint ensures that this is the correct address"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custodyLedger",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "which may not be initialized. See [`CustodyLedger::record_if_initialized`]."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReceiveAndRedeemArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeTransceiverInfo",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(the core bridge) and the layout are checked by [`VaaAccount::load`]."
+          ]
+        },
+        {
+          "name": "pendingPeer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "receiveWormholeTransceiverRegistration",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(the core bridge) and the layout are checked by [`VaaAccount::load`]."
+          ]
+        },
+        {
+          "name": "pendingPeer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptWormholePendingPeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "pendingPeer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverPeer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "AcceptPendingPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Refilled (backflow) when a queued transfer consumes its deferred",
+            "capacity. See [`OutboxItem::try_release`]."
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveWormholeRelayer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See [`crate::timelock`]."
+          ]
+        },
+        {
+          "name": "approvedRelayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ApproveRelayerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "revokeWormholeRelayer",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "approvedRelayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "relayer",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "releaseWormholeOutboundReusable",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Refilled (backflow) when a queued transfer consumes its deferred",
+            "capacity. See [`OutboxItem::try_release`]."
+          ]
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "approvedRelayer",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required unless the payer is the sender of the outbox item."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundReusableArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "sendWormholePauseSignal",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "transceiverPeer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SendPauseSignalArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholePauseSignal",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(the core bridge) and the layout are checked by [`VaaAccount::load`],",
+            "and the emitter by [`VaaAccount::transceiver_message`]."
+          ]
+        },
+        {
+          "name": "transceiverPeer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReceivePauseSignalArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Pause the program. This is useful for upgrades and other maintenance."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "timelockDelay",
+            "docs": [
+              "Minimum number of seconds between proposing an admin action and",
+              "executing it. Zero disables the timelock.",
+              "See [`crate::timelock`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "ownerSet",
+            "docs": [
+              "Optional m-of-n owner set. When empty, [`Config::owner`] alone",
+              "authorizes owner instructions."
+            ],
+            "type": {
+              "defined": "OwnerSet"
+            }
+          },
+          {
+            "name": "maxQueueAge",
+            "docs": [
+              "Number of seconds after its release timestamp after which an",
+              "unreleased outbound transfer can be reclaimed by the sender.",
+              "Zero means outbound transfers never expire."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "capacityAwareRelease",
+            "docs": [
+              "When enabled, releasing a queued transfer (in either direction) also",
+              "consumes rate limit capacity, and the transfer is delayed again if there",
+              "isn't enough. Like an immediate transfer, it then refills the rate limit",
+              "of the opposite direction (backflow). This bounds the total throughput",
+              "in any 24 hour window, at the cost of transfers larger than the limit",
+              "never being released."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "operator",
+            "docs": [
+              "Optional operator that, in addition to the owner, can expedite or",
+              "freeze queued inbound transfers.",
+              "See [`crate::instructions::expedite_inbox_item`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mintExtensions",
+            "docs": [
+              "The token-2022 extensions of the mint, detected at initialize.",
+              "See [`crate::mint_extensions`]."
+            ],
+            "type": {
+              "defined": "MintExtensions"
+            }
+          },
+          {
+            "name": "layoutVersion",
+            "docs": [
+              "The version of this account's layout. Set to [`Config::LAYOUT_VERSION`]",
+              "at initialize (or by [`crate::instructions::migrate_config`]), and",
+              "checked when unpausing after an upgrade.",
+              "See [`crate::instructions::upgrade`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "multiToken",
+            "docs": [
+              "Whether this config is one of many managed by this program. The",
+              "accounts of a multi-token config (including the config itself) are",
+              "keyed by its mint. See [`Config::mint_seed`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "wormholeFinality",
+            "docs": [
+              "The finality of Wormhole messages sent to peers that don't override it",
+              "(see [`crate::transceivers::accounts::peer::TransceiverPeer::finality`]),",
+              "and of messages that aren't sent to a specific chain (e.g. broadcasts).",
+              "Initialized to the network's",
+              "[`crate::transceivers::wormhole::accounts::DEFAULT_FINALITY`]."
+            ],
+            "type": {
+              "defined": "WormholeFinality"
+            }
+          },
+          {
+            "name": "upgradeAuthorityEscrowed",
+            "docs": [
+              "Whether the `upgrade_lock` only holds the program's upgrade authority",
+              "for the duration of an ownership transfer, after which it's handed to",
+              "the new owner (or back to the owner). Otherwise, an authority held by",
+              "the `upgrade_lock` stays there, see [`crate::instructions::upgrade`]."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tokenMode",
+      "docs": [
+        "Whether the program manages a single token or multiple tokens (see",
+        "[`mint_seed`]). Set by the first [`crate::instructions::initialize`] and",
+        "checked by later ones, so that the two modes can't be mixed in one program."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "initialized",
+            "docs": [
+              "Whether [`TokenMode::multi_token`] has been set. False only while the",
+              "account is being created by the first initialize."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "multiToken",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "custodyLedger",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "totalOutbound",
+            "docs": [
+              "Total amount locked into custody by outbound transfers."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "totalInbound",
+            "docs": [
+              "Total amount unlocked from custody."
+            ],
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LUT",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "nttManagerPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          },
+          {
+            "name": "tokenAddress",
+            "docs": [
+              "The address of the token on the peer chain. Inbound transfers have to",
+              "carry this as their `source_token`."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "settlementDelays",
+            "docs": [
+              "Minimum delay before inbound transfers from this peer can be released,",
+              "regardless of the rate limit. See [`NttManagerPeer::settlement_delay`]."
+            ],
+            "type": {
+              "vec": {
+                "defined": "SettlementDelayTier"
+              }
+            }
+          },
+          {
+            "name": "outboundPaused",
+            "docs": [
+              "Set by the peer with a [`ntt_messages::pause::PauseSignal`]. Outbound",
+              "transfers to the peer are rejected while this is set."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "init",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "votes",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "releaseStatus",
+            "type": {
+              "defined": "ReleaseStatus"
+            }
+          },
+          {
+            "name": "fromChain",
+            "docs": [
+              "The chain the transfer was sent from."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "deferredCapacity",
+            "docs": [
+              "The inbound rate limit capacity that the transfer did not consume when",
+              "it was delayed. Zero once the transfer has been released.",
+              "See [`crate::config::Config::capacity_aware_release`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "frozen",
+            "docs": [
+              "Frozen transfers can't be released until they are unfrozen.",
+              "See [`crate::instructions::set_inbox_item_frozen`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The sender on the source chain. Passed to registered receivers.",
+              "See [`crate::registered_receiver`]."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the transferred token. Checked against the config by",
+              "instructions that take the inbox item."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "additionalPayload",
+            "docs": [
+              "The additional payload of the transfer. Passed to registered receivers.",
+              "NOTE: the inbox item is allocated with room for it, see",
+              "[`InboxItem::space`]."
+            ],
+            "type": {
+              "defined": "RawPayload"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxRateLimit",
+      "docs": [
+        "Inbound rate limit per chain.",
+        "SECURITY: must check the PDA (since there are multiple PDAs, namely one for each chain.)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "deferredCapacity",
+            "docs": [
+              "The outbound rate limit capacity that the transfer did not consume when",
+              "it was queued. Zero once the transfer has been released.",
+              "Like the rate limit, this is in the mint's native (untrimmed) units,",
+              "and it's also what the transfer adds to [`OutboxRateLimit::queued_amount`].",
+              "See [`crate::config::Config::capacity_aware_release`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the transferred token. Checked against the config by",
+              "instructions that take the outbox item, as it is not keyed by the mint."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxRateLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          },
+          {
+            "name": "queuedAmount",
+            "docs": [
+              "The total amount of the outbound transfers that are queued, i.e. that",
+              "were delayed by the rate limit and haven't been released (or",
+              "reclaimed) yet. Their tokens have already left the sender.",
+              "NOTE: like the rate limit, this is in the mint's native (untrimmed)",
+              "units, which (unlike trimmed amounts) don't depend on the recipient",
+              "chain."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxQueuedAmount",
+            "docs": [
+              "The maximum of [`OutboxRateLimit::queued_amount`], in the same units.",
+              "Transfers that would be queued beyond it are rejected. `None` means no",
+              "cap."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredReceiver",
+      "docs": [
+        "Stored in a PDA seeded by the receiver's authority, since that's what",
+        "inbound transfers name as their recipient."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "program",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingAdminAction",
+      "docs": [
+        "A proposed admin action. Stored in a PDA seeded by the hash of the action."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": "AdminAction"
+            }
+          },
+          {
+            "name": "earliestExecution",
+            "docs": [
+              "The timestamp from which the action may be executed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the config the action was proposed for. See",
+              "[`crate::config::mint_seed`]."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "approvedRelayer",
+      "docs": [
+        "A relayer that the owner allows to release other senders' outbound",
+        "transfers into its reusable message accounts. Stored in a PDA seeded by the",
+        "relayer (and the mint in multi-token mode).",
+        "",
+        "Reusable message accounts can be overwritten by the relayer before the",
+        "guardians observe them, so releasing into one means trusting the relayer",
+        "not to lose the release.",
+        "See [`crate::transceivers::wormhole::instructions::release_outbound_reusable`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "finality",
+            "docs": [
+              "The finality of messages sent to this chain. When not set,",
+              "[`crate::config::Config::wormhole_finality`] is used."
+            ],
+            "type": {
+              "option": {
+                "defined": "WormholeFinality"
+              }
+            }
+          },
+          {
+            "name": "pauseSignalSequence",
+            "docs": [
+              "The sequence of the last pause signal received from this peer.",
+              "Sequences are per emitter, so this is reset when the address changes."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingPeer",
+      "docs": [
+        "A peer transceiver, as claimed by its own broadcasts. Stored in a PDA seeded",
+        "by the chain id and the address of the transceiver (the emitter of the",
+        "broadcasts).",
+        "",
+        "Anyone can post broadcasts from any address, so nothing in here is trusted",
+        "until the owner accepts it with",
+        "[`crate::transceivers::wormhole::instructions::accept_pending_peer`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "transceiverAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "info",
+            "docs": [
+              "Set by a `WormholeTransceiverInfo` broadcast."
+            ],
+            "type": {
+              "option": {
+                "defined": "PendingPeerInfo"
+              }
+            }
+          },
+          {
+            "name": "registered",
+            "docs": [
+              "Whether the peer has broadcast a `WormholeTransceiverRegistration` of",
+              "this transceiver."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OwnerSet",
+      "docs": [
+        "A set of owner keys, any `threshold` of which can authorize owner",
+        "instructions together."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "keys",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CancelInboundLimitRampArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RenounceOwnershipArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "confirmation",
+            "docs": [
+              "Must be [`RENOUNCE_OWNERSHIP_CONFIRMATION`]."
+            ],
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetInboundLimitRampArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "duration",
+            "docs": [
+              "The duration of the ramp, in seconds."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetMaxQueueAgeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxQueueAge",
+            "docs": [
+              "Seconds after the release timestamp after which unreleased outbound",
+              "transfers can be reclaimed. Zero disables expiry."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetMaxQueuedOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxQueuedAmount",
+            "docs": [
+              "In the mint's native (untrimmed) units, like the rate limits.",
+              "`None` removes the cap."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundLimitArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOutboundLimitRampArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "duration",
+            "docs": [
+              "The duration of the ramp, in seconds."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetOwnerSetArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "keys",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "tokenDecimals",
+            "docs": [
+              "The token decimals on the peer chain."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "tokenAddress",
+            "docs": [
+              "The token address on the peer chain."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetSettlementDelaysArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "tiers",
+            "type": {
+              "vec": {
+                "defined": "SettlementDelayTier"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTimelockDelayArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "delay",
+            "docs": [
+              "The new delay in seconds. Zero disables the timelock."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CheckCustodyInvariantArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "autoPause",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CustodyInvariant",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "custody",
+            "docs": [
+              "The balance of the custody account."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "recorded",
+            "docs": [
+              "The balance recorded by the custody ledger."
+            ],
+            "type": "i128"
+          },
+          {
+            "name": "pendingInbound",
+            "docs": [
+              "The total amount of the inbox items that were passed in."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "discrepancy",
+            "docs": [
+              "`custody` minus the larger of `recorded` and `pending_inbound`.",
+              "Negative when the invariant is broken."
+            ],
+            "type": "i128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "multiToken",
+            "docs": [
+              "Initialize one of many tokens managed by this program, keying all its",
+              "accounts by the mint. See [`crate::config::mint_seed`].",
+              "Single-token deployments can't be converted later, and a program can't",
+              "have configs of both kinds (see [`TokenMode`])."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MigrateInboxItemArgs",
+      "docs": [
+        "The message of the transfer, which the inbox item is keyed by. Provides the",
+        "fields that the legacy layout doesn't have. The message is passed as its",
+        "fields, as clients can't encode the generic message type.",
+        "",
+        "NOTE: earlier versions didn't support additional payloads, so the",
+        "message's additional payload is omitted (see",
+        "[`ntt_messages::ntt::RawPayload`])."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fromChain",
+            "docs": [
+              "The chain the transfer was sent from."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "id",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "sender",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sourceToken",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "toChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "to",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RedeemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "ReleaseInboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "shouldQueue",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GetInboundCapacityArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "GetTransferHookAccountsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InboxItemStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "releaseStatus",
+            "type": {
+              "defined": "ReleaseStatus"
+            }
+          },
+          {
+            "name": "votes",
+            "docs": [
+              "Bitmap of the transceivers that have attested to the transfer."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "approved",
+            "docs": [
+              "Whether enough enabled transceivers have attested to the transfer to",
+              "meet the threshold (`isMessageApproved` on EVM)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "executed",
+            "docs": [
+              "Whether the transfer has been released to the recipient",
+              "(`isMessageExecuted` on EVM)."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "releasable",
+            "docs": [
+              "Whether the release timestamp has been reached, i.e. the transfer can",
+              "be released right now."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "frozen",
+            "docs": [
+              "Whether the transfer has been frozen by the owner or operator."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutboxItemStatus",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "releaseTimestamp",
+            "docs": [
+              "The timestamp after which the transfer may be released."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "releasable",
+            "docs": [
+              "Whether the transfer can be released by the transceivers right now:",
+              "the program is not paused, `release_timestamp` has been reached and,",
+              "under [`Config::capacity_aware_release`], there is enough outbound",
+              "capacity for `deferred_capacity`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "released",
+            "docs": [
+              "Bitmap of the transceivers that have already released the transfer."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "deferredCapacity",
+            "docs": [
+              "The outbound capacity that releasing the transfer consumes under",
+              "[`Config::capacity_aware_release`]. See [`OutboxItem::deferred_capacity`]."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyConfig",
+      "docs": [
+        "The layout of [`crate::config::Config`] before the admin action timelock."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyInboxItem",
+      "docs": [
+        "The layout of [`crate::queue::inbox::InboxItem`] before the source chain",
+        "of the transfer was recorded."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "init",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "recipientAddress",
+            "type": "publicKey"
+          },
+          {
+            "name": "votes",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "releaseStatus",
+            "type": {
+              "defined": "ReleaseStatus"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyInboxRateLimit",
+      "docs": [
+        "The layout of [`crate::queue::inbox::InboxRateLimit`] before limit ramps."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "LegacyRateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyNttManagerPeer",
+      "docs": [
+        "The layout of [`crate::peer::NttManagerPeer`] before settlement delays."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyOutboxItem",
+      "docs": [
+        "The layout of [`crate::queue::outbox::OutboxItem`] before deferred",
+        "capacity."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyOutboxRateLimit",
+      "docs": [
+        "The layout of [`crate::queue::outbox::OutboxRateLimit`] before the queued",
+        "amount was tracked."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "LegacyRateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyRateLimitState",
+      "docs": [
+        "The layout of [`RateLimitState`] before limit ramps."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "capacityAtLastTx",
+            "type": "u64"
+          },
+          {
+            "name": "lastTxTimestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LegacyTransceiverPeer",
+      "docs": [
+        "The layout of [`crate::transceivers::accounts::peer::TransceiverPeer`]",
+        "before per-peer finality."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintExtensions",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "extensions",
+            "docs": [
+              "The [`ExtensionType`]s of the mint. Empty for legacy token mints."
+            ],
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "warned",
+            "docs": [
+              "Whether any of the extensions is [`Compatibility::Warned`]."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SettlementDelayTier",
+      "docs": [
+        "Inbound transfers of at least `min_amount` (in local token units) have to",
+        "wait at least `delay` seconds before they can be released."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minAmount",
+            "type": "u64"
+          },
+          {
+            "name": "delay",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseStatus",
+      "docs": [
+        "The status of an InboxItem. This determines whether the tokens are minted/unlocked to the recipient. As",
+        "such, this must be used as a state machine that moves forward in a linear manner. A state",
+        "should never \"move backward\" to a previous state (e.g. should never move from `Released` to",
+        "`ReleaseAfter`)."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NotApproved"
+          },
+          {
+            "name": "ReleaseAfter",
+            "fields": [
+              "i64"
+            ]
+          },
+          {
+            "name": "Released"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LimitRamp",
+      "docs": [
+        "A linear change of the limit from `start_limit` to `end_limit` between",
+        "`start_timestamp` and `end_timestamp`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startLimit",
+            "type": "u64"
+          },
+          {
+            "name": "endLimit",
+            "type": "u64"
+          },
+          {
+            "name": "startTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "endTimestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "docs": [
+              "The maximum capacity of the rate limiter."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capacityAtLastTx",
+            "docs": [
+              "The capacity of the rate limiter at `last_tx_timestamp`.",
+              "The actual current capacity is calculated in `capacity_at`, by",
+              "accounting for the time that has passed since `last_tx_timestamp` and",
+              "the refill rate."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastTxTimestamp",
+            "docs": [
+              "The timestamp of the last transaction that counted towards the current",
+              "capacity. Transactions that exceeded the capacity do not count, they are",
+              "just delayed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "ramp",
+            "docs": [
+              "A scheduled change of the limit, in progress (or not yet applied).",
+              "While it is set, the effective limit is interpolated (see",
+              "[`RateLimitState::limit_at`]) and `limit` holds the limit the ramp",
+              "started from. Once the ramp is over, the next transaction moves its",
+              "end limit into `limit`."
+            ],
+            "type": {
+              "option": {
+                "defined": "LimitRamp"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReceiveNttTransferArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fromChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The sender on the source chain."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount delivered to the receiver's token account."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "additionalPayload",
+            "docs": [
+              "The additional payload of the transfer. Empty if it has none."
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AdminAction",
+      "docs": [
+        "An admin action that is subject to the timelock. The fields mirror the",
+        "arguments (and relevant accounts) of the corresponding instruction, so a",
+        "proposal commits to exactly what is going to be executed."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "TransferOwnership",
+            "fields": [
+              {
+                "name": "newOwner",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "TransferOwnershipOneStepUnchecked",
+            "fields": [
+              {
+                "name": "newOwner",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetPeer",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "address",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "tokenDecimals",
+                "type": "u8"
+              },
+              {
+                "name": "tokenAddress",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            ]
+          },
+          {
+            "name": "RegisterTransceiver",
+            "fields": [
+              {
+                "name": "transceiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundLimit",
+            "fields": [
+              {
+                "name": "limit",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetInboundLimit",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetWormholePeer",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "address",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetWormholePeerFinality",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "finality",
+                "type": {
+                  "option": {
+                    "defined": "WormholeFinality"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetTimelockDelay",
+            "fields": [
+              {
+                "name": "delay",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetOwnerSet",
+            "fields": [
+              {
+                "name": "ownerSet",
+                "type": {
+                  "defined": "OwnerSet"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetMaxQueueAge",
+            "fields": [
+              {
+                "name": "maxQueueAge",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetCapacityAwareRelease",
+            "fields": [
+              {
+                "name": "enabled",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "SetOperator",
+            "fields": [
+              {
+                "name": "operator",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetSettlementDelays",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "tiers",
+                "type": {
+                  "vec": {
+                    "defined": "SettlementDelayTier"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "AcceptPendingPeer",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "transceiverAddress",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "allowMismatch",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "RegisterReceiver",
+            "fields": [
+              {
+                "name": "receiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "DeregisterReceiver",
+            "fields": [
+              {
+                "name": "receiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "Upgrade",
+            "fields": [
+              {
+                "name": "buffer",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RenounceOwnership"
+          },
+          {
+            "name": "SetMaxQueuedOutbound",
+            "fields": [
+              {
+                "name": "maxQueuedAmount",
+                "type": {
+                  "option": "u64"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundLimitRamp",
+            "fields": [
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "duration",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetInboundLimitRamp",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "duration",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "ApproveRelayer",
+            "fields": [
+              {
+                "name": "relayer",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetWormholeFinality",
+            "fields": [
+              {
+                "name": "finality",
+                "type": {
+                  "defined": "WormholeFinality"
+                }
+              }
+            ]
+          },
+          {
+            "name": "InitializeCustodyLedger"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingPeerInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "managerAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "managerMode",
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "tokenAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PostMessageUnreliableData",
+      "docs": [
+        "Instruction data of the core bridge's `PostMessageUnreliable` instruction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "batchId",
+            "type": "u32"
+          },
+          {
+            "name": "payload",
+            "type": "bytes"
+          },
+          {
+            "name": "finality",
+            "type": {
+              "defined": "Finality"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "WormholeFinality",
+      "docs": [
+        "The consistency level that messages are posted with.",
+        "Mirrors [`wormhole::Finality`], which can't be stored in accounts as is."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Confirmed"
+          },
+          {
+            "name": "Finalized"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AcceptPendingPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "transceiverAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "limit",
+            "type": "u64"
+          },
+          {
+            "name": "allowMismatch",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ApproveRelayerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "relayer",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerFinalityArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "finality",
+            "docs": [
+              "`None` reverts to the default finality."
+            ],
+            "type": {
+              "option": {
+                "defined": "WormholeFinality"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetWormholeFinalityArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "finality",
+            "type": {
+              "defined": "WormholeFinality"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BroadcastPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReceivePauseSignalArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "docs": [
+              "The emitter chain of the VAA."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SendPauseSignalArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReceiveAndRedeemArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "release",
+            "docs": [
+              "Whether to release the transfer once the vote is recorded. The release",
+              "is skipped (without reverting) if the transfer can't be released yet."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The emitter chain of the VAA."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "messageHash",
+            "docs": [
+              "The hash of the NTT manager message in the VAA, which keys the inbox",
+              "item. See [`ntt_messages::ntt_manager::NttManagerMessage::keccak256`]."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "additionalPayloadLen",
+            "docs": [
+              "The length of the additional payload of the transfer, which the inbox",
+              "item is allocated with room for."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReceiveMessageArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "docs": [
+              "The emitter chain of the VAA."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "messageId",
+            "docs": [
+              "The id of the NTT manager message in the VAA."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "additionalPayloadLen",
+            "docs": [
+              "The length of the additional payload of the transfer, which the",
+              "message account is allocated with room for."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundReusableArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          },
+          {
+            "name": "messageIndex",
+            "docs": [
+              "Selects which of the payer's reusable message accounts to post into.",
+              "Relayers that release several transfers before the guardians observe",
+              "them should rotate through different indices."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferHookAccount",
+      "docs": [
+        "An account that has to be passed to the token program for the transfer",
+        "hook to be invoked."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "type": "publicKey"
+          },
+          {
+            "name": "isSigner",
+            "type": "bool"
+          },
+          {
+            "name": "isWritable",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
+          },
+          {
+            "name": "Burning"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RawPayload",
+      "docs": [
+        "An additional payload of arbitrary bytes, which is passed on as is.",
+        "",
+        "`data` is `None` when the transfer doesn't carry an additional payload (in",
+        "which case it's omitted on the wire, see [`AdditionalPayload`]), as opposed",
+        "to an empty payload, which is written with a zero length."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "data",
+            "type": {
+              "option": "bytes"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TrimmedAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Finality",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Confirmed"
+          },
+          {
+            "name": "Finalized"
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "CantReleaseYet",
+      "msg": "CantReleaseYet"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidPendingOwner",
+      "msg": "InvalidPendingOwner"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidChainId",
+      "msg": "InvalidChainId"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidRecipientAddress",
+      "msg": "InvalidRecipientAddress"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidTransceiverPeer",
+      "msg": "InvalidTransceiverPeer"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidNttManagerPeer",
+      "msg": "InvalidNttManagerPeer"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidRecipientNttManager",
+      "msg": "InvalidRecipientNttManager"
+    },
+    {
+      "code": 6007,
+      "name": "TransferAlreadyRedeemed",
+      "msg": "TransferAlreadyRedeemed"
+    },
+    {
+      "code": 6008,
+      "name": "TransferCannotBeRedeemed",
+      "msg": "TransferCannotBeRedeemed"
+    },
+    {
+      "code": 6009,
+      "name": "TransferNotApproved",
+      "msg": "TransferNotApproved"
+    },
+    {
+      "code": 6010,
+      "name": "MessageAlreadySent",
+      "msg": "MessageAlreadySent"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidMode",
+      "msg": "InvalidMode"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidMintAuthority",
+      "msg": "InvalidMintAuthority"
+    },
+    {
+      "code": 6013,
+      "name": "TransferExceedsRateLimit",
+      "msg": "TransferExceedsRateLimit"
+    },
+    {
+      "code": 6014,
+      "name": "Paused",
+      "msg": "Paused"
+    },
+    {
+      "code": 6015,
+      "name": "DisabledTransceiver",
+      "msg": "DisabledTransceiver"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidDeployer",
+      "msg": "InvalidDeployer"
+    },
+    {
+      "code": 6017,
+      "name": "BadAmountAfterTransfer",
+      "msg": "BadAmountAfterTransfer"
+    },
+    {
+      "code": 6018,
+      "name": "BadAmountAfterBurn",
+      "msg": "BadAmountAfterBurn"
+    },
+    {
+      "code": 6019,
+      "name": "ZeroThreshold",
+      "msg": "ZeroThreshold"
+    },
+    {
+      "code": 6020,
+      "name": "OverflowExponent",
+      "msg": "OverflowExponent"
+    },
+    {
+      "code": 6021,
+      "name": "OverflowScaledAmount",
+      "msg": "OverflowScaledAmount"
+    },
+    {
+      "code": 6022,
+      "name": "BitmapIndexOutOfBounds",
+      "msg": "BitmapIndexOutOfBounds"
+    },
+    {
+      "code": 6023,
+      "name": "NoRegisteredTransceivers",
+      "msg": "NoRegisteredTransceivers"
+    },
+    {
+      "code": 6024,
+      "name": "TimelockDisabled",
+      "msg": "TimelockDisabled"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidTimelockDelay",
+      "msg": "InvalidTimelockDelay"
+    },
+    {
+      "code": 6026,
+      "name": "AdminActionNotProposed",
+      "msg": "AdminActionNotProposed"
+    },
+    {
+      "code": 6027,
+      "name": "AdminActionMismatch",
+      "msg": "AdminActionMismatch"
+    },
+    {
+      "code": 6028,
+      "name": "TimelockNotExpired",
+      "msg": "TimelockNotExpired"
+    },
+    {
+      "code": 6029,
+      "name": "AccountAlreadyMigrated",
+      "msg": "AccountAlreadyMigrated"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidOwner",
+      "msg": "InvalidOwner"
+    },
+    {
+      "code": 6031,
+      "name": "InsufficientOwnerSignatures",
+      "msg": "InsufficientOwnerSignatures"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidOwnerSet",
+      "msg": "InvalidOwnerSet"
+    },
+    {
+      "code": 6033,
+      "name": "OutboxItemNotExpired",
+      "msg": "OutboxItemNotExpired"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidMaxQueueAge",
+      "msg": "InvalidMaxQueueAge"
+    },
+    {
+      "code": 6035,
+      "name": "InboxItemFrozen",
+      "msg": "InboxItemFrozen"
+    },
+    {
+      "code": 6036,
+      "name": "InvalidSettlementDelays",
+      "msg": "InvalidSettlementDelays"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidVaaAccount",
+      "msg": "InvalidVaaAccount"
+    },
+    {
+      "code": 6038,
+      "name": "PendingPeerIncomplete",
+      "msg": "PendingPeerIncomplete"
+    },
+    {
+      "code": 6039,
+      "name": "PendingPeerMismatch",
+      "msg": "PendingPeerMismatch"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidSourceToken",
+      "msg": "InvalidSourceToken"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidFromAuthority",
+      "msg": "InvalidFromAuthority"
+    },
+    {
+      "code": 6042,
+      "name": "ReleaseRequiresCall",
+      "msg": "ReleaseRequiresCall"
+    },
+    {
+      "code": 6043,
+      "name": "ReceiverNotRegistered",
+      "msg": "ReceiverNotRegistered"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidReceiverProgram",
+      "msg": "InvalidReceiverProgram"
+    },
+    {
+      "code": 6045,
+      "name": "NonTransferableMint",
+      "msg": "NonTransferableMint"
+    },
+    {
+      "code": 6046,
+      "name": "ConfidentialTransferMint",
+      "msg": "ConfidentialTransferMint"
+    },
+    {
+      "code": 6047,
+      "name": "PermanentDelegateMint",
+      "msg": "PermanentDelegateMint"
+    },
+    {
+      "code": 6048,
+      "name": "FrozenByDefaultMint",
+      "msg": "FrozenByDefaultMint"
+    },
+    {
+      "code": 6049,
+      "name": "UnsupportedMintExtension",
+      "msg": "UnsupportedMintExtension"
+    },
+    {
+      "code": 6050,
+      "name": "InvalidTransferHookAccounts",
+      "msg": "InvalidTransferHookAccounts"
+    },
+    {
+      "code": 6051,
+      "name": "CurrentLUT",
+      "msg": "CurrentLUT"
+    },
+    {
+      "code": 6052,
+      "name": "NotPaused",
+      "msg": "NotPaused"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidUpgradeAuthority",
+      "msg": "InvalidUpgradeAuthority"
+    },
+    {
+      "code": 6054,
+      "name": "ConfigLayoutMismatch",
+      "msg": "ConfigLayoutMismatch"
+    },
+    {
+      "code": 6055,
+      "name": "NoPendingOwner",
+      "msg": "NoPendingOwner"
+    },
+    {
+      "code": 6056,
+      "name": "InvalidRenounceConfirmation",
+      "msg": "InvalidRenounceConfirmation"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidMint",
+      "msg": "InvalidMint"
+    },
+    {
+      "code": 6058,
+      "name": "MultiTokenConfig",
+      "msg": "MultiTokenConfig"
+    },
+    {
+      "code": 6059,
+      "name": "PeerOutboundPaused",
+      "msg": "PeerOutboundPaused"
+    },
+    {
+      "code": 6060,
+      "name": "StalePauseSignal",
+      "msg": "StalePauseSignal"
+    },
+    {
+      "code": 6061,
+      "name": "DuplicateInboxItem",
+      "msg": "DuplicateInboxItem"
+    },
+    {
+      "code": 6062,
+      "name": "QueuedAmountExceedsCap",
+      "msg": "QueuedAmountExceedsCap"
+    },
+    {
+      "code": 6063,
+      "name": "InvalidRampDuration",
+      "msg": "InvalidRampDuration"
+    },
+    {
+      "code": 6064,
+      "name": "UnapprovedRelayer",
+      "msg": "UnapprovedRelayer"
+    },
+    {
+      "code": 6065,
+      "name": "MixedTokenModes",
+      "msg": "MixedTokenModes"
+    },
+    {
+      "code": 6066,
+      "name": "TransferFeeMint",
+      "msg": "TransferFeeMint"
+    }
+  ]
+}
+
@@ -0,0 +1,3599 @@
+export type NttTransceiver = {
+  "version": "4.0.0",
+  "name": "ntt_transceiver",
+  "instructions": [
+    {
+      "name": "transceiverType",
+      "accounts": [],
+      "args": [],
+      "returns": "string"
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See",
+            "[`example_native_token_transfers::timelock`]."
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setWormholePeerFinality",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See",
+            "[`example_native_token_transfers::timelock`]."
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerFinalityArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(the core bridge) and the layout are checked by [`VaaAccount::load`],",
+            "and the emitter by [`VaaAccount::transceiver_message`]."
+          ]
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReceiveMessageArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItemSigner",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseWormholeOutboundReusable",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItemSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "approvedRelayer",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required unless the payer is the sender of the outbox item."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundReusableArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Pause the program. This is useful for upgrades and other maintenance."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "timelockDelay",
+            "docs": [
+              "Minimum number of seconds between proposing an admin action and",
+              "executing it. Zero disables the timelock.",
+              "See [`crate::timelock`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "ownerSet",
+            "docs": [
+              "Optional m-of-n owner set. When empty, [`Config::owner`] alone",
+              "authorizes owner instructions."
+            ],
+            "type": {
+              "defined": "OwnerSet"
+            }
+          },
+          {
+            "name": "maxQueueAge",
+            "docs": [
+              "Number of seconds after its release timestamp after which an",
+              "unreleased outbound transfer can be reclaimed by the sender.",
+              "Zero means outbound transfers never expire."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "capacityAwareRelease",
+            "docs": [
+              "When enabled, releasing a queued transfer (in either direction) also",
+              "consumes rate limit capacity, and the transfer is delayed again if there",
+              "isn't enough. Like an immediate transfer, it then refills the rate limit",
+              "of the opposite direction (backflow). This bounds the total throughput",
+              "in any 24 hour window, at the cost of transfers larger than the limit",
+              "never being released."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "operator",
+            "docs": [
+              "Optional operator that, in addition to the owner, can expedite or",
+              "freeze queued inbound transfers.",
+              "See [`crate::instructions::expedite_inbox_item`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mintExtensions",
+            "docs": [
+              "The token-2022 extensions of the mint, detected at initialize.",
+              "See [`crate::mint_extensions`]."
+            ],
+            "type": {
+              "defined": "MintExtensions"
+            }
+          },
+          {
+            "name": "layoutVersion",
+            "docs": [
+              "The version of this account's layout. Set to [`Config::LAYOUT_VERSION`]",
+              "at initialize (or by [`crate::instructions::migrate_config`]), and",
+              "checked when unpausing after an upgrade.",
+              "See [`crate::instructions::upgrade`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "multiToken",
+            "docs": [
+              "Whether this config is one of many managed by this program. The",
+              "accounts of a multi-token config (including the config itself) are",
+              "keyed by its mint. See [`Config::mint_seed`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "wormholeFinality",
+            "docs": [
+              "The finality of Wormhole messages sent to peers that don't override it",
+              "(see [`crate::transceivers::accounts::peer::TransceiverPeer::finality`]),",
+              "and of messages that aren't sent to a specific chain (e.g. broadcasts).",
+              "Initialized to the network's",
+              "[`crate::transceivers::wormhole::accounts::DEFAULT_FINALITY`]."
+            ],
+            "type": {
+              "defined": "WormholeFinality"
+            }
+          },
+          {
+            "name": "upgradeAuthorityEscrowed",
+            "docs": [
+              "Whether the `upgrade_lock` only holds the program's upgrade authority",
+              "for the duration of an ownership transfer, after which it's handed to",
+              "the new owner (or back to the owner). Otherwise, an authority held by",
+              "the `upgrade_lock` stays there, see [`crate::instructions::upgrade`]."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxRateLimit",
+      "docs": [
+        "Inbound rate limit per chain.",
+        "SECURITY: must check the PDA (since there are multiple PDAs, namely one for each chain.)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "deferredCapacity",
+            "docs": [
+              "The outbound rate limit capacity that the transfer did not consume when",
+              "it was queued. Zero once the transfer has been released.",
+              "Like the rate limit, this is in the mint's native (untrimmed) units,",
+              "and it's also what the transfer adds to [`OutboxRateLimit::queued_amount`].",
+              "See [`crate::config::Config::capacity_aware_release`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the transferred token. Checked against the config by",
+              "instructions that take the outbox item, as it is not keyed by the mint."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxRateLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          },
+          {
+            "name": "queuedAmount",
+            "docs": [
+              "The total amount of the outbound transfers that are queued, i.e. that",
+              "were delayed by the rate limit and haven't been released (or",
+              "reclaimed) yet. Their tokens have already left the sender.",
+              "NOTE: like the rate limit, this is in the mint's native (untrimmed)",
+              "units, which (unlike trimmed amounts) don't depend on the recipient",
+              "chain."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxQueuedAmount",
+            "docs": [
+              "The maximum of [`OutboxRateLimit::queued_amount`], in the same units.",
+              "Transfers that would be queued beyond it are rejected. `None` means no",
+              "cap."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingAdminAction",
+      "docs": [
+        "A proposed admin action. Stored in a PDA seeded by the hash of the action."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": "AdminAction"
+            }
+          },
+          {
+            "name": "earliestExecution",
+            "docs": [
+              "The timestamp from which the action may be executed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the config the action was proposed for. See",
+              "[`crate::config::mint_seed`]."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "approvedRelayer",
+      "docs": [
+        "A relayer that the owner allows to release other senders' outbound",
+        "transfers into its reusable message accounts. Stored in a PDA seeded by the",
+        "relayer (and the mint in multi-token mode).",
+        "",
+        "Reusable message accounts can be overwritten by the relayer before the",
+        "guardians observe them, so releasing into one means trusting the relayer",
+        "not to lose the release.",
+        "See [`crate::transceivers::wormhole::instructions::release_outbound_reusable`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id (and the",
+        "mint in multi-token mode, see [`example_native_token_transfers::config::mint_seed`])."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "finality",
+            "docs": [
+              "The finality of messages sent to this chain. When not set, the",
+              "manager's",
+              "[`example_native_token_transfers::config::Config::wormhole_finality`]",
+              "is used."
+            ],
+            "type": {
+              "option": {
+                "defined": "WormholeFinality"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OwnerSet",
+      "docs": [
+        "A set of owner keys, any `threshold` of which can authorize owner",
+        "instructions together."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "keys",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintExtensions",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "extensions",
+            "docs": [
+              "The [`ExtensionType`]s of the mint. Empty for legacy token mints."
+            ],
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "warned",
+            "docs": [
+              "Whether any of the extensions is [`Compatibility::Warned`]."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SettlementDelayTier",
+      "docs": [
+        "Inbound transfers of at least `min_amount` (in local token units) have to",
+        "wait at least `delay` seconds before they can be released."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minAmount",
+            "type": "u64"
+          },
+          {
+            "name": "delay",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LimitRamp",
+      "docs": [
+        "A linear change of the limit from `start_limit` to `end_limit` between",
+        "`start_timestamp` and `end_timestamp`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startLimit",
+            "type": "u64"
+          },
+          {
+            "name": "endLimit",
+            "type": "u64"
+          },
+          {
+            "name": "startTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "endTimestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "docs": [
+              "The maximum capacity of the rate limiter."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capacityAtLastTx",
+            "docs": [
+              "The capacity of the rate limiter at `last_tx_timestamp`.",
+              "The actual current capacity is calculated in `capacity_at`, by",
+              "accounting for the time that has passed since `last_tx_timestamp` and",
+              "the refill rate."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastTxTimestamp",
+            "docs": [
+              "The timestamp of the last transaction that counted towards the current",
+              "capacity. Transactions that exceeded the capacity do not count, they are",
+              "just delayed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "ramp",
+            "docs": [
+              "A scheduled change of the limit, in progress (or not yet applied).",
+              "While it is set, the effective limit is interpolated (see",
+              "[`RateLimitState::limit_at`]) and `limit` holds the limit the ramp",
+              "started from. Once the ramp is over, the next transaction moves its",
+              "end limit into `limit`."
+            ],
+            "type": {
+              "option": {
+                "defined": "LimitRamp"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AdminAction",
+      "docs": [
+        "An admin action that is subject to the timelock. The fields mirror the",
+        "arguments (and relevant accounts) of the corresponding instruction, so a",
+        "proposal commits to exactly what is going to be executed."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "TransferOwnership",
+            "fields": [
+              {
+                "name": "newOwner",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "TransferOwnershipOneStepUnchecked",
+            "fields": [
+              {
+                "name": "newOwner",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetPeer",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "address",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "tokenDecimals",
+                "type": "u8"
+              },
+              {
+                "name": "tokenAddress",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            ]
+          },
+          {
+            "name": "RegisterTransceiver",
+            "fields": [
+              {
+                "name": "transceiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundLimit",
+            "fields": [
+              {
+                "name": "limit",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetInboundLimit",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetWormholePeer",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "address",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetWormholePeerFinality",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "finality",
+                "type": {
+                  "option": {
+                    "defined": "WormholeFinality"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetTimelockDelay",
+            "fields": [
+              {
+                "name": "delay",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetOwnerSet",
+            "fields": [
+              {
+                "name": "ownerSet",
+                "type": {
+                  "defined": "OwnerSet"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetMaxQueueAge",
+            "fields": [
+              {
+                "name": "maxQueueAge",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetCapacityAwareRelease",
+            "fields": [
+              {
+                "name": "enabled",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "SetOperator",
+            "fields": [
+              {
+                "name": "operator",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetSettlementDelays",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "tiers",
+                "type": {
+                  "vec": {
+                    "defined": "SettlementDelayTier"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "AcceptPendingPeer",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "transceiverAddress",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "allowMismatch",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "RegisterReceiver",
+            "fields": [
+              {
+                "name": "receiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "DeregisterReceiver",
+            "fields": [
+              {
+                "name": "receiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "Upgrade",
+            "fields": [
+              {
+                "name": "buffer",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RenounceOwnership"
+          },
+          {
+            "name": "SetMaxQueuedOutbound",
+            "fields": [
+              {
+                "name": "maxQueuedAmount",
+                "type": {
+                  "option": "u64"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundLimitRamp",
+            "fields": [
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "duration",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetInboundLimitRamp",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "duration",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "ApproveRelayer",
+            "fields": [
+              {
+                "name": "relayer",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetWormholeFinality",
+            "fields": [
+              {
+                "name": "finality",
+                "type": {
+                  "defined": "WormholeFinality"
+                }
+              }
+            ]
+          },
+          {
+            "name": "InitializeCustodyLedger"
+          }
+        ]
+      }
+    },
+    {
+      "name": "WormholeFinality",
+      "docs": [
+        "The consistency level that messages are posted with.",
+        "Mirrors [`wormhole::Finality`], which can't be stored in accounts as is."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Confirmed"
+          },
+          {
+            "name": "Finalized"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
+          },
+          {
+            "name": "Burning"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TrimmedAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PostMessageUnreliableData",
+      "docs": [
+        "Instruction data of the core bridge's `PostMessageUnreliable` instruction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "batchId",
+            "type": "u32"
+          },
+          {
+            "name": "payload",
+            "type": "bytes"
+          },
+          {
+            "name": "finality",
+            "type": {
+              "defined": "Finality"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerFinalityArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "finality",
+            "docs": [
+              "`None` reverts to the default finality."
+            ],
+            "type": {
+              "option": {
+                "defined": "WormholeFinality"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BroadcastPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReceiveMessageArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "docs": [
+              "The emitter chain of the VAA."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "messageId",
+            "docs": [
+              "The id of the NTT manager message in the VAA."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "additionalPayloadLen",
+            "docs": [
+              "The length of the additional payload of the transfer, which the",
+              "message account is allocated with room for."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundReusableArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          },
+          {
+            "name": "messageIndex",
+            "docs": [
+              "Selects which of the payer's reusable message accounts to post into.",
+              "Relayers that release several transfers before the guardians observe",
+              "them should rotate through different indices."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Finality",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Confirmed"
+          },
+          {
+            "name": "Finalized"
+          }
+        ]
+      }
+    }
+  ]
+}
+export const IDL: NttTransceiver = {
+  "version": "4.0.0",
+  "name": "ntt_transceiver",
+  "instructions": [
+    {
+      "name": "transceiverType",
+      "accounts": [],
+      "args": [],
+      "returns": "string"
+    },
+    {
+      "name": "setWormholePeer",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See",
+            "[`example_native_token_transfers::timelock`]."
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setWormholePeerFinality",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingAction",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required when the timelock is enabled. See",
+            "[`example_native_token_transfers::timelock`]."
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetTransceiverPeerFinalityArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "peer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chainId",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "receiveWormholeMessage",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vaa",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(the core bridge) and the layout are checked by [`VaaAccount::load`],",
+            "and the emitter by [`VaaAccount::transceiver_message`]."
+          ]
+        },
+        {
+          "name": "transceiverMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReceiveMessageArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseWormholeOutbound",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItemSigner",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseWormholeOutboundReusable",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "accounts": [
+            {
+              "name": "config",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "outboxItem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "outboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "inboxRateLimit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "transceiver",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "outboxItemSigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "approvedRelayer",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Required unless the payer is the sender of the outbox item."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ReleaseOutboundReusableArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "broadcastWormholeId",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "broadcastWormholePeer",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "emitter",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "accounts": [
+            {
+              "name": "bridge",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "feeCollector",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "sequence",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "program",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "BroadcastPeerArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "docs": [
+              "Pending next owner (before claiming ownership)."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint address of the token managed by this program."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenProgram",
+            "docs": [
+              "Address of the token program (token or token22). This could always be queried",
+              "from the [`mint`] account's owner, but storing it here avoids an indirection",
+              "on the client side."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mode",
+            "docs": [
+              "The mode that this program is running in. This is used to determine",
+              "whether the program is burning tokens or locking tokens."
+            ],
+            "type": {
+              "defined": "Mode"
+            }
+          },
+          {
+            "name": "chainId",
+            "docs": [
+              "The chain id of the chain that this program is running on. We don't",
+              "hardcode this so that the program is deployable on any potential SVM",
+              "forks."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "nextTransceiverId",
+            "docs": [
+              "The next transceiver id to use when registering an transceiver."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The number of transceivers that must attest to a transfer before it is",
+              "accepted."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "enabledTransceivers",
+            "docs": [
+              "Bitmap of enabled transceivers.",
+              "The maximum number of transceivers is equal to [`Bitmap::BITS`]."
+            ],
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Pause the program. This is useful for upgrades and other maintenance."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "The custody account that holds tokens in locking mode."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "timelockDelay",
+            "docs": [
+              "Minimum number of seconds between proposing an admin action and",
+              "executing it. Zero disables the timelock.",
+              "See [`crate::timelock`]."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "ownerSet",
+            "docs": [
+              "Optional m-of-n owner set. When empty, [`Config::owner`] alone",
+              "authorizes owner instructions."
+            ],
+            "type": {
+              "defined": "OwnerSet"
+            }
+          },
+          {
+            "name": "maxQueueAge",
+            "docs": [
+              "Number of seconds after its release timestamp after which an",
+              "unreleased outbound transfer can be reclaimed by the sender.",
+              "Zero means outbound transfers never expire."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "capacityAwareRelease",
+            "docs": [
+              "When enabled, releasing a queued transfer (in either direction) also",
+              "consumes rate limit capacity, and the transfer is delayed again if there",
+              "isn't enough. Like an immediate transfer, it then refills the rate limit",
+              "of the opposite direction (backflow). This bounds the total throughput",
+              "in any 24 hour window, at the cost of transfers larger than the limit",
+              "never being released."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "operator",
+            "docs": [
+              "Optional operator that, in addition to the owner, can expedite or",
+              "freeze queued inbound transfers.",
+              "See [`crate::instructions::expedite_inbox_item`]."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "mintExtensions",
+            "docs": [
+              "The token-2022 extensions of the mint, detected at initialize.",
+              "See [`crate::mint_extensions`]."
+            ],
+            "type": {
+              "defined": "MintExtensions"
+            }
+          },
+          {
+            "name": "layoutVersion",
+            "docs": [
+              "The version of this account's layout. Set to [`Config::LAYOUT_VERSION`]",
+              "at initialize (or by [`crate::instructions::migrate_config`]), and",
+              "checked when unpausing after an upgrade.",
+              "See [`crate::instructions::upgrade`]."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "multiToken",
+            "docs": [
+              "Whether this config is one of many managed by this program. The",
+              "accounts of a multi-token config (including the config itself) are",
+              "keyed by its mint. See [`Config::mint_seed`]."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "wormholeFinality",
+            "docs": [
+              "The finality of Wormhole messages sent to peers that don't override it",
+              "(see [`crate::transceivers::accounts::peer::TransceiverPeer::finality`]),",
+              "and of messages that aren't sent to a specific chain (e.g. broadcasts).",
+              "Initialized to the network's",
+              "[`crate::transceivers::wormhole::accounts::DEFAULT_FINALITY`]."
+            ],
+            "type": {
+              "defined": "WormholeFinality"
+            }
+          },
+          {
+            "name": "upgradeAuthorityEscrowed",
+            "docs": [
+              "Whether the `upgrade_lock` only holds the program's upgrade authority",
+              "for the duration of an ownership transfer, after which it's handed to",
+              "the new owner (or back to the owner). Otherwise, an authority held by",
+              "the `upgrade_lock` stays there, see [`crate::instructions::upgrade`]."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "inboxRateLimit",
+      "docs": [
+        "Inbound rate limit per chain.",
+        "SECURITY: must check the PDA (since there are multiple PDAs, namely one for each chain.)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxItem",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": {
+              "defined": "TrimmedAmount"
+            }
+          },
+          {
+            "name": "sender",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipientChain",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "recipientNttManager",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "recipientAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "releaseTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "released",
+            "type": {
+              "defined": "Bitmap"
+            }
+          },
+          {
+            "name": "deferredCapacity",
+            "docs": [
+              "The outbound rate limit capacity that the transfer did not consume when",
+              "it was queued. Zero once the transfer has been released.",
+              "Like the rate limit, this is in the mint's native (untrimmed) units,",
+              "and it's also what the transfer adds to [`OutboxRateLimit::queued_amount`].",
+              "See [`crate::config::Config::capacity_aware_release`]."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the transferred token. Checked against the config by",
+              "instructions that take the outbox item, as it is not keyed by the mint."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "outboxRateLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rateLimit",
+            "type": {
+              "defined": "RateLimitState"
+            }
+          },
+          {
+            "name": "queuedAmount",
+            "docs": [
+              "The total amount of the outbound transfers that are queued, i.e. that",
+              "were delayed by the rate limit and haven't been released (or",
+              "reclaimed) yet. Their tokens have already left the sender.",
+              "NOTE: like the rate limit, this is in the mint's native (untrimmed)",
+              "units, which (unlike trimmed amounts) don't depend on the recipient",
+              "chain."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxQueuedAmount",
+            "docs": [
+              "The maximum of [`OutboxRateLimit::queued_amount`], in the same units.",
+              "Transfers that would be queued beyond it are rejected. `None` means no",
+              "cap."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "registeredTransceiver",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "type": "u8"
+          },
+          {
+            "name": "transceiverAddress",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingAdminAction",
+      "docs": [
+        "A proposed admin action. Stored in a PDA seeded by the hash of the action."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": "AdminAction"
+            }
+          },
+          {
+            "name": "earliestExecution",
+            "docs": [
+              "The timestamp from which the action may be executed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The mint of the config the action was proposed for. See",
+              "[`crate::config::mint_seed`]."
+            ],
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "approvedRelayer",
+      "docs": [
+        "A relayer that the owner allows to release other senders' outbound",
+        "transfers into its reusable message accounts. Stored in a PDA seeded by the",
+        "relayer (and the mint in multi-token mode).",
+        "",
+        "Reusable message accounts can be overwritten by the relayer before the",
+        "guardians observe them, so releasing into one means trusting the relayer",
+        "not to lose the release.",
+        "See [`crate::transceivers::wormhole::instructions::release_outbound_reusable`]."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "transceiverPeer",
+      "docs": [
+        "A peer on another chain. Stored in a PDA seeded by the chain id (and the",
+        "mint in multi-token mode, see [`example_native_token_transfers::config::mint_seed`])."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "finality",
+            "docs": [
+              "The finality of messages sent to this chain. When not set, the",
+              "manager's",
+              "[`example_native_token_transfers::config::Config::wormhole_finality`]",
+              "is used."
+            ],
+            "type": {
+              "option": {
+                "defined": "WormholeFinality"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Bitmap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "map",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OwnerSet",
+      "docs": [
+        "A set of owner keys, any `threshold` of which can authorize owner",
+        "instructions together."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "keys",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintExtensions",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "extensions",
+            "docs": [
+              "The [`ExtensionType`]s of the mint. Empty for legacy token mints."
+            ],
+            "type": {
+              "vec": "u16"
+            }
+          },
+          {
+            "name": "warned",
+            "docs": [
+              "Whether any of the extensions is [`Compatibility::Warned`]."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SettlementDelayTier",
+      "docs": [
+        "Inbound transfers of at least `min_amount` (in local token units) have to",
+        "wait at least `delay` seconds before they can be released."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minAmount",
+            "type": "u64"
+          },
+          {
+            "name": "delay",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LimitRamp",
+      "docs": [
+        "A linear change of the limit from `start_limit` to `end_limit` between",
+        "`start_timestamp` and `end_timestamp`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startLimit",
+            "type": "u64"
+          },
+          {
+            "name": "endLimit",
+            "type": "u64"
+          },
+          {
+            "name": "startTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "endTimestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "limit",
+            "docs": [
+              "The maximum capacity of the rate limiter."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capacityAtLastTx",
+            "docs": [
+              "The capacity of the rate limiter at `last_tx_timestamp`.",
+              "The actual current capacity is calculated in `capacity_at`, by",
+              "accounting for the time that has passed since `last_tx_timestamp` and",
+              "the refill rate."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastTxTimestamp",
+            "docs": [
+              "The timestamp of the last transaction that counted towards the current",
+              "capacity. Transactions that exceeded the capacity do not count, they are",
+              "just delayed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "ramp",
+            "docs": [
+              "A scheduled change of the limit, in progress (or not yet applied).",
+              "While it is set, the effective limit is interpolated (see",
+              "[`RateLimitState::limit_at`]) and `limit` holds the limit the ramp",
+              "started from. Once the ramp is over, the next transaction moves its",
+              "end limit into `limit`."
+            ],
+            "type": {
+              "option": {
+                "defined": "LimitRamp"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AdminAction",
+      "docs": [
+        "An admin action that is subject to the timelock. The fields mirror the",
+        "arguments (and relevant accounts) of the corresponding instruction, so a",
+        "proposal commits to exactly what is going to be executed."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "TransferOwnership",
+            "fields": [
+              {
+                "name": "newOwner",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "TransferOwnershipOneStepUnchecked",
+            "fields": [
+              {
+                "name": "newOwner",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetPeer",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "address",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "tokenDecimals",
+                "type": "u8"
+              },
+              {
+                "name": "tokenAddress",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            ]
+          },
+          {
+            "name": "RegisterTransceiver",
+            "fields": [
+              {
+                "name": "transceiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundLimit",
+            "fields": [
+              {
+                "name": "limit",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetInboundLimit",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetWormholePeer",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "address",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetWormholePeerFinality",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "finality",
+                "type": {
+                  "option": {
+                    "defined": "WormholeFinality"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetTimelockDelay",
+            "fields": [
+              {
+                "name": "delay",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetOwnerSet",
+            "fields": [
+              {
+                "name": "ownerSet",
+                "type": {
+                  "defined": "OwnerSet"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetMaxQueueAge",
+            "fields": [
+              {
+                "name": "maxQueueAge",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetCapacityAwareRelease",
+            "fields": [
+              {
+                "name": "enabled",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "SetOperator",
+            "fields": [
+              {
+                "name": "operator",
+                "type": {
+                  "option": "publicKey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetSettlementDelays",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "tiers",
+                "type": {
+                  "vec": {
+                    "defined": "SettlementDelayTier"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "AcceptPendingPeer",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "transceiverAddress",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "allowMismatch",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "RegisterReceiver",
+            "fields": [
+              {
+                "name": "receiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "DeregisterReceiver",
+            "fields": [
+              {
+                "name": "receiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "Upgrade",
+            "fields": [
+              {
+                "name": "buffer",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RenounceOwnership"
+          },
+          {
+            "name": "SetMaxQueuedOutbound",
+            "fields": [
+              {
+                "name": "maxQueuedAmount",
+                "type": {
+                  "option": "u64"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetOutboundLimitRamp",
+            "fields": [
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "duration",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "SetInboundLimitRamp",
+            "fields": [
+              {
+                "name": "chainId",
+                "type": {
+                  "defined": "ChainId"
+                }
+              },
+              {
+                "name": "limit",
+                "type": "u64"
+              },
+              {
+                "name": "duration",
+                "type": "i64"
+              }
+            ]
+          },
+          {
+            "name": "ApproveRelayer",
+            "fields": [
+              {
+                "name": "relayer",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetWormholeFinality",
+            "fields": [
+              {
+                "name": "finality",
+                "type": {
+                  "defined": "WormholeFinality"
+                }
+              }
+            ]
+          },
+          {
+            "name": "InitializeCustodyLedger"
+          }
+        ]
+      }
+    },
+    {
+      "name": "WormholeFinality",
+      "docs": [
+        "The consistency level that messages are posted with.",
+        "Mirrors [`wormhole::Finality`], which can't be stored in accounts as is."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Confirmed"
+          },
+          {
+            "name": "Finalized"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChainId",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Mode",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Locking"
+          },
+          {
+            "name": "Burning"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TrimmedAmount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PostMessageUnreliableData",
+      "docs": [
+        "Instruction data of the core bridge's `PostMessageUnreliable` instruction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "batchId",
+            "type": "u32"
+          },
+          {
+            "name": "payload",
+            "type": "bytes"
+          },
+          {
+            "name": "finality",
+            "type": {
+              "defined": "Finality"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "address",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetTransceiverPeerFinalityArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "finality",
+            "docs": [
+              "`None` reverts to the default finality."
+            ],
+            "type": {
+              "option": {
+                "defined": "WormholeFinality"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BroadcastPeerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReceiveMessageArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "chainId",
+            "docs": [
+              "The emitter chain of the VAA."
+            ],
+            "type": {
+              "defined": "ChainId"
+            }
+          },
+          {
+            "name": "messageId",
+            "docs": [
+              "The id of the NTT manager message in the VAA."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "additionalPayloadLen",
+            "docs": [
+              "The length of the additional payload of the transfer, which the",
+              "message account is allocated with room for."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReleaseOutboundReusableArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "revertOnDelay",
+            "type": "bool"
+          },
+          {
+            "name": "messageIndex",
+            "docs": [
+              "Selects which of the payer's reusable message accounts to post into.",
+              "Relayers that release several transfers before the guardians observe",
+              "them should rotate through different indices."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Finality",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Confirmed"
+          },
+          {
+            "name": "Finalized"
+          }
+        ]
+      }
+    }
+  ]
+}
+
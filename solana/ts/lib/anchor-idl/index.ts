@@ -1,3 +1,4 @@
 export * from "./1_0_0.js";
 export * from "./2_0_0.js";
 export * from "./3_0_0.js";
+export * from "./4_0_0.js";
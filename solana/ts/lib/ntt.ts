@@ -80,29 +80,52 @@ export namespace NTT {
 
   /** Type of object containing methods to compute program addresses */
   export type Pdas = ReturnType<typeof pdas>;
-  /** pdas returns an object containing all functions to compute program addresses */
-  export const pdas = (programId: PublicKeyInitData) => {
-    const configAccount = (): PublicKey => derivePda("config", programId);
+  /**
+   * pdas returns an object containing all functions to compute program addresses
+   *
+   * @param mint Only for programs that manage multiple tokens (see the
+   *             `multiToken` argument of the initialize instruction), whose
+   *             accounts are keyed by the mint of the token.
+   */
+  export const pdas = (programId: PublicKeyInitData, mint?: PublicKey) => {
+    // NOTE: empty for single-token programs, which derives the same addresses
+    // as omitting it
+    const mintSeed = mint?.toBytes() ?? new Uint8Array();
+    const configAccount = (): PublicKey =>
+      derivePda(["config", mintSeed], programId);
+    const tokenModeAccount = (): PublicKey =>
+      derivePda("token_mode", programId);
     const inboxRateLimitAccount = (chain: Chain): PublicKey =>
-      derivePda(["inbox_rate_limit", chainToBytes(chain)], programId);
+      derivePda(["inbox_rate_limit", chainToBytes(chain), mintSeed], programId);
     const inboxItemAccount = (
       chain: Chain,
       nttMessage: Ntt.Message
     ): PublicKey =>
       derivePda(
-        ["inbox_item", Ntt.messageDigest(chain, nttMessage)],
+        ["inbox_item", Ntt.messageDigest(chain, nttMessage), mintSeed],
         programId
       );
     const outboxRateLimitAccount = (): PublicKey =>
-      derivePda("outbox_rate_limit", programId);
+      derivePda(["outbox_rate_limit", mintSeed], programId);
+    const custodyLedgerAccount = (): PublicKey =>
+      derivePda(["custody_ledger", mintSeed], programId);
     const tokenAuthority = (): PublicKey =>
-      derivePda("token_authority", programId);
+      derivePda(["token_authority", mintSeed], programId);
     const peerAccount = (chain: Chain): PublicKey =>
-      derivePda(["peer", chainToBytes(chain)], programId);
+      derivePda(["peer", chainToBytes(chain), mintSeed], programId);
     const registeredTransceiver = (transceiver: PublicKey): PublicKey =>
-      derivePda(["registered_transceiver", transceiver.toBytes()], programId);
-    const lutAccount = (): PublicKey => derivePda("lut", programId);
-    const lutAuthority = (): PublicKey => derivePda("lut_authority", programId);
+      derivePda(
+        ["registered_transceiver", transceiver.toBytes(), mintSeed],
+        programId
+      );
+    const registeredReceiver = (recipient: PublicKey): PublicKey =>
+      derivePda(
+        ["registered_receiver", recipient.toBytes(), mintSeed],
+        programId
+      );
+    const lutAccount = (): PublicKey => derivePda(["lut", mintSeed], programId);
+    const lutAuthority = (): PublicKey =>
+      derivePda(["lut_authority", mintSeed], programId);
     const sessionAuthority = (
       sender: PublicKey,
       args: TransferArgs
@@ -126,13 +149,16 @@ export namespace NTT {
     // TODO: memoize?
     return {
       configAccount,
+      tokenModeAccount,
       outboxRateLimitAccount,
       inboxRateLimitAccount,
       inboxItemAccount,
+      custodyLedgerAccount,
       sessionAuthority,
       tokenAuthority,
       peerAccount,
       registeredTransceiver,
+      registeredReceiver,
       lutAccount,
       lutAuthority,
     };
@@ -140,17 +166,28 @@ export namespace NTT {
 
   /** Type of object containing methods to compute program addresses */
   export type TransceiverPdas = ReturnType<typeof transceiverPdas>;
-  /** pdas returns an object containing all functions to compute program addresses */
-  export const transceiverPdas = (programId: PublicKeyInitData) => {
+  /**
+   * pdas returns an object containing all functions to compute program addresses
+   *
+   * @param mint See {@link pdas}.
+   */
+  export const transceiverPdas = (
+    programId: PublicKeyInitData,
+    mint?: PublicKey
+  ) => {
+    const mintSeed = mint?.toBytes() ?? new Uint8Array();
     const emitterAccount = (): PublicKey => derivePda("emitter", programId);
     const outboxItemSigner = () => derivePda(["outbox_item_signer"], programId);
     const transceiverPeerAccount = (chain: Chain): PublicKey =>
-      derivePda(["transceiver_peer", chainToBytes(chain)], programId);
+      derivePda(["transceiver_peer", chainToBytes(chain), mintSeed], programId);
     const transceiverMessageAccount = (
       chain: Chain,
       id: Uint8Array
     ): PublicKey =>
-      derivePda(["transceiver_message", chainToBytes(chain), id], programId);
+      derivePda(
+        ["transceiver_message", chainToBytes(chain), id, mintSeed],
+        programId
+      );
     const wormholeMessageAccount = (outboxItem: PublicKey): PublicKey =>
       derivePda(["message", outboxItem.toBytes()], programId);
 
@@ -241,25 +278,35 @@ export namespace NTT {
       outboundLimit: bigint;
       tokenProgram: PublicKey;
       mode: "burning" | "locking";
+      // See the `mint` parameter of `pdas`, which has to be passed in
+      // `pdas` too. Requires version 4.0.0.
+      multiToken?: boolean;
     },
     pdas?: Pdas
   ) {
     const mode: any =
       args.mode === "burning" ? { burning: {} } : { locking: {} };
     const chainId = toChainId(args.chain);
+    const multiToken = args.multiToken ?? false;
 
     pdas = pdas ?? NTT.pdas(program.programId);
 
     const limit = new BN(args.outboundLimit.toString());
     return await program.methods
-      .initialize({ chainId, limit: limit, mode })
+      .initialize({ chainId, limit: limit, mode, multiToken })
       .accountsStrict({
         payer: args.payer,
         deployer: args.owner,
         programData: programDataAddress(program.programId),
         config: pdas.configAccount(),
+        tokenMode: pdas.tokenModeAccount(),
+        // the single-token config, which must not exist in multi-token mode
+        singleTokenConfig: multiToken
+          ? NTT.pdas(program.programId).configAccount()
+          : null,
         mint: args.mint,
         rateLimit: pdas.outboxRateLimitAccount(),
+        custodyLedger: pdas.custodyLedgerAccount(),
         tokenProgram: args.tokenProgram,
         tokenAuthority: pdas.tokenAuthority(),
         custody: await NTT.custodyAccountAddress(
@@ -491,6 +538,7 @@ export namespace NTT {
           args.transferArgs
         ),
         custody,
+        custodyLedger: pdas.custodyLedgerAccount(),
       })
       .instruction();
 
@@ -559,12 +607,15 @@ export namespace NTT {
           payer: args.payer,
           config: { config: pdas.configAccount() },
           inboxItem: pdas.inboxItemAccount(args.chain, args.nttMessage),
+          inboxRateLimit: pdas.inboxRateLimitAccount(args.chain),
+          outboxRateLimit: pdas.outboxRateLimitAccount(),
           recipient: getAssociatedTokenAddressSync(
             config.mint,
             recipientAddress,
             true,
             config.tokenProgram
           ),
+          registeredReceiver: pdas.registeredReceiver(recipientAddress),
           mint: config.mint,
           tokenAuthority: pdas.tokenAuthority(),
           tokenProgram: config.tokenProgram,
@@ -640,18 +691,22 @@ export namespace NTT {
           payer: args.payer,
           config: { config: pdas.configAccount() },
           inboxItem: pdas.inboxItemAccount(args.chain, args.nttMessage),
+          inboxRateLimit: pdas.inboxRateLimitAccount(args.chain),
+          outboxRateLimit: pdas.outboxRateLimitAccount(),
           recipient: getAssociatedTokenAddressSync(
             config.mint,
             recipientAddress,
             true,
             config.tokenProgram
           ),
+          registeredReceiver: pdas.registeredReceiver(recipientAddress),
           mint: config.mint,
           tokenAuthority: pdas.tokenAuthority(),
           tokenProgram: config.tokenProgram,
           custody,
         },
         custody,
+        custodyLedger: pdas.custodyLedgerAccount(),
       })
       .instruction();
 
@@ -705,6 +760,9 @@ export namespace NTT {
       .transferOwnership()
       .accounts({
         config: pdas.configAccount(),
+        // NOTE: the owner instructions are executed directly, which fails
+        // if the timelock is enabled (see `setTimelockDelay`)
+        pendingAction: null,
         newOwner: args.newOwner,
       })
       .instruction();
@@ -719,9 +777,16 @@ export namespace NTT {
       address: ArrayLike<number>;
       limit: BN;
       tokenDecimals: number;
+      // The address of the token on the peer chain. Required by version
+      // 4.0.0, which checks it against the source token of inbound transfers.
+      tokenAddress?: ArrayLike<number>;
     },
     pdas?: Pdas
   ) {
+    const [major, , ,] = parseVersion(program.idl.version);
+    if (major >= 4 && !args.tokenAddress)
+      throw new Error("The token address of the peer is required");
+
     pdas = pdas ?? NTT.pdas(program.programId);
     return await program.methods
       .setPeer({
@@ -729,11 +794,13 @@ export namespace NTT {
         address: Array.from(args.address),
         limit: args.limit,
         tokenDecimals: args.tokenDecimals,
+        tokenAddress: Array.from(args.tokenAddress ?? new Uint8Array(32)),
       })
       .accounts({
         payer: args.payer,
         owner: args.owner,
         config: pdas.configAccount(),
+        pendingAction: null,
         peer: pdas.peerAccount(args.chain),
         inboxRateLimit: pdas.inboxRateLimitAccount(args.chain),
       })
@@ -775,6 +842,7 @@ export namespace NTT {
       .accounts({
         owner: args.owner,
         config: pdas.configAccount(),
+        pendingAction: null,
         rateLimit: pdas.outboxRateLimitAccount(),
       })
       .instruction();
@@ -798,6 +866,7 @@ export namespace NTT {
       .accounts({
         owner: args.owner,
         config: pdas.configAccount(),
+        pendingAction: null,
         rateLimit: pdas.inboxRateLimitAccount(args.chain),
       })
       .instruction();
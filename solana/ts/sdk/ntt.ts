@@ -64,7 +64,7 @@ export class SolanaNttWormholeTransceiver<
   constructor(
     readonly manager: SolanaNtt<N, C>,
     readonly program: Program<NttBindings.Transceiver<IdlVersion>>,
-    readonly version: string = "3.0.0"
+    readonly version: string = "4.0.0"
   ) {
     this.programId = program.programId;
     this.pdas = NTT.transceiverPdas(program.programId);
@@ -100,10 +100,23 @@ export class SolanaNttWormholeTransceiver<
     attestation: WormholeNttTransceiver.VAA<"WormholeTransfer">,
     payer: PublicKey
   ) {
+    const [major, , ,] = parseVersion(this.version);
     const nttMessage = attestation.payload.nttManagerPayload;
     const chain = attestation.emitterChain;
-    return this.program.methods
-      .receiveWormholeMessage()
+    // NOTE: the instruction takes arguments since version 4.0.0
+    const args =
+      major >= 4
+        ? [
+            {
+              chainId: { id: toChainId(chain) },
+              messageId: Array.from(nttMessage.id),
+              additionalPayloadLen: nttMessage.payload.additionalPayload.length,
+            },
+          ]
+        : [];
+    // @ts-ignore
+    const methods = this.program.methods.receiveWormholeMessage(...args);
+    return methods
       .accounts({
         payer,
         config: { config: this.manager.pdas.configAccount() },
@@ -193,6 +206,8 @@ export class SolanaNttWormholeTransceiver<
         payer: sender,
         owner: sender,
         config: this.manager.pdas.configAccount(),
+        pendingAction: null,
+        manager: this.manager.program.programId,
         peer: this.pdas.transceiverPeerAccount(peer.chain),
       })
       .instruction();
@@ -289,6 +304,7 @@ export class SolanaNttWormholeTransceiver<
   async createReleaseWormholeOutboundIx(
     payer: PublicKey,
     outboxItem: PublicKey,
+    recipientChain: Chain,
     revertOnDelay: boolean
   ): Promise<web3.TransactionInstruction> {
     const [major, , ,] = parseVersion(this.version);
@@ -305,6 +321,9 @@ export class SolanaNttWormholeTransceiver<
         payer,
         config: { config: this.manager.pdas.configAccount() },
         outboxItem,
+        outboxRateLimit: this.manager.pdas.outboxRateLimitAccount(),
+        inboxRateLimit: this.manager.pdas.inboxRateLimitAccount(recipientChain),
+        peer: this.pdas.transceiverPeerAccount(recipientChain),
         wormholeMessage: this.pdas.wormholeMessageAccount(outboxItem),
         emitter: whAccs.wormholeEmitter,
         transceiver: this.manager.pdas.registeredTransceiver(
@@ -352,7 +371,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     readonly chain: C,
     readonly connection: Connection,
     readonly contracts: Contracts & { ntt?: Ntt.Contracts },
-    readonly version: string = "3.0.0"
+    readonly version: string = "4.0.0"
   ) {
     if (!contracts.ntt) throw new Error("Ntt contracts not found");
 
@@ -619,7 +638,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
       );
     } catch (e) {
       // This might happen if e.g. the program is not deployed yet.
-      const version = "3.0.0";
+      const version = "4.0.0";
       return version;
     }
   }
@@ -737,6 +756,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
         payer,
         owner,
         config: this.pdas.configAccount(),
+        pendingAction: null,
         transceiver: transceiverProgramId,
         registeredTransceiver:
           this.pdas.registeredTransceiver(transceiverProgramId),
@@ -764,11 +784,15 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
     yield* transceiver.setPeer(peer, payer);
   }
 
+  /**
+   * @param token The token on the peer chain. Required since version 4.0.0.
+   */
   async *setPeer(
     peer: ChainAddress,
     tokenDecimals: number,
     inboundLimit: bigint,
-    payer: AccountAddress<C>
+    payer: AccountAddress<C>,
+    token?: ChainAddress
   ) {
     const sender = new SolanaAddress(payer).unwrap();
 
@@ -779,6 +803,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
       address: peer.address.toUniversalAddress().toUint8Array(),
       limit: new BN(inboundLimit.toString()),
       tokenDecimals,
+      tokenAddress: token?.address.toUniversalAddress().toUint8Array(),
     });
 
     const tx = new Transaction();
@@ -848,6 +873,7 @@ export class SolanaNtt<N extends Network, C extends SolanaChains>
         const releaseIx = whTransceiver.createReleaseWormholeOutboundIx(
           payerAddress,
           outboxItem.publicKey,
+          destination.chain,
           !options.queue
         );
         asyncIxs.push(releaseIx);